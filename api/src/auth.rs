@@ -1,8 +1,12 @@
 mod auth_error;
+mod claim_mapper;
 mod claims;
+mod discovery;
 mod extractor;
 mod jwks;
 mod jwt;
+mod scope;
 
 pub use auth_error::OidcAuthError;
 pub use claims::OidcUserInfo;
+pub use jwks::refresh_jwks;
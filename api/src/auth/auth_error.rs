@@ -10,4 +10,12 @@ pub enum OidcAuthError {
     Expired,
     #[error("JWKS fetch error")]
     JwksFetchError,
+    #[error("OIDC discovery fetch error")]
+    DiscoveryFetchError,
+    #[error("Personal access token lookup error")]
+    TokenLookupError,
+    #[error("Token lacks the required scope")]
+    InsufficientScope,
+    #[error("Token belongs to another tenant")]
+    TenantMismatch,
 }
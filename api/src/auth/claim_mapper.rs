@@ -0,0 +1,405 @@
+use application::user::dto::UserRoleDTO;
+use domain::{auth::capability::CapabilitySet, tenant::values::TenantId};
+use infrastructure::config::{OidcIssuerConfig, OidcProvider};
+use sha2::{Digest, Sha256};
+use uuid::{Builder, Uuid};
+
+use crate::auth::{
+    OidcAuthError,
+    claims::{OidcClaims, OidcUserInfo},
+};
+
+/// Reads a provider's claims into `OidcUserInfo`; the defaults follow the standard OIDC claims.
+pub trait ClaimMapper: Send + Sync {
+    fn default_roles_claim(&self) -> &'static str;
+
+    fn user_id(&self, claims: &OidcClaims) -> Result<Uuid, OidcAuthError>;
+
+    fn email(&self, claims: &OidcClaims) -> Option<String> {
+        claims.string("email")
+    }
+
+    fn username(&self, claims: &OidcClaims) -> Option<String> {
+        claims.string("preferred_username")
+    }
+
+    fn scopes_claim(&self) -> &'static str {
+        "scope"
+    }
+
+    /// Scopes only narrow the role's capabilities when at least one of them names a capability;
+    /// providers such as Keycloak always send scopes like `openid profile email`.
+    fn capability_scopes(&self, claims: &OidcClaims) -> Option<Vec<String>> {
+        let scopes = claims.strings_at(self.scopes_claim());
+        let names_capability = !CapabilitySet::from_scopes(&scopes).is_empty();
+
+        names_capability.then_some(scopes)
+    }
+
+    /// The tenant claim wins over the host's subdomain, which must then name the same tenant.
+    fn tenant(
+        &self,
+        claims: &OidcClaims,
+        issuer: &OidcIssuerConfig,
+        host_tenant: Option<TenantId>,
+    ) -> Result<TenantId, OidcAuthError> {
+        let Some(tenant_claim) = issuer.tenant_claim.as_deref() else {
+            return Ok(host_tenant.unwrap_or_default());
+        };
+
+        let tenant = claims
+            .strings_at(tenant_claim)
+            .into_iter()
+            .next()
+            .and_then(|value| TenantId::try_from(value).ok())
+            .ok_or(OidcAuthError::InvalidToken(format!(
+                "invalid {}",
+                tenant_claim
+            )))?;
+
+        match host_tenant {
+            Some(host_tenant) if host_tenant != tenant => Err(OidcAuthError::TenantMismatch),
+            _ => Ok(tenant),
+        }
+    }
+
+    fn map(
+        &self,
+        claims: &OidcClaims,
+        issuer: &OidcIssuerConfig,
+        host_tenant: Option<TenantId>,
+    ) -> Result<OidcUserInfo, OidcAuthError> {
+        let roles_claim = issuer
+            .roles_claim
+            .as_deref()
+            .unwrap_or(self.default_roles_claim());
+        let token_roles = claims.strings_at(roles_claim);
+        let has_any = |configured: &[String]| {
+            token_roles
+                .iter()
+                .any(|role| configured.iter().any(|c| c.eq_ignore_ascii_case(role)))
+        };
+
+        Ok(OidcUserInfo {
+            id: issuer_user_id(issuer, self.user_id(claims)?),
+            role: if has_any(&issuer.admin_roles) {
+                UserRoleDTO::Admin
+            } else if has_any(&issuer.librarian_roles) {
+                UserRoleDTO::Librarian
+            } else {
+                UserRoleDTO::Regular
+            },
+            full_name: claims.string("name"),
+            email: self.email(claims),
+            username: self.username(claims),
+            scopes: self.capability_scopes(claims),
+            tenant: self.tenant(claims, issuer, host_tenant)?,
+        })
+    }
+}
+
+pub struct KeycloakClaimMapper;
+
+/// Azure AD (Entra ID). `sub` is pairwise per application, so the stable object id is used instead.
+pub struct AzureAdClaimMapper;
+
+/// Any other provider, e.g. Auth0, whose `sub` is usually not a UUID.
+pub struct GenericClaimMapper;
+
+impl ClaimMapper for KeycloakClaimMapper {
+    fn default_roles_claim(&self) -> &'static str {
+        "realm_access.roles"
+    }
+
+    fn user_id(&self, claims: &OidcClaims) -> Result<Uuid, OidcAuthError> {
+        uuid_claim(claims, "sub")
+    }
+}
+
+impl ClaimMapper for AzureAdClaimMapper {
+    fn default_roles_claim(&self) -> &'static str {
+        "roles"
+    }
+
+    fn user_id(&self, claims: &OidcClaims) -> Result<Uuid, OidcAuthError> {
+        uuid_claim(claims, "oid")
+    }
+
+    fn email(&self, claims: &OidcClaims) -> Option<String> {
+        claims
+            .string("email")
+            .or_else(|| claims.string("preferred_username"))
+            .or_else(|| claims.string("upn"))
+    }
+
+    fn scopes_claim(&self) -> &'static str {
+        "scp"
+    }
+}
+
+impl ClaimMapper for GenericClaimMapper {
+    fn default_roles_claim(&self) -> &'static str {
+        "roles"
+    }
+
+    /// Uses `sub` when it is a UUID and otherwise derives a stable one from `iss` and `sub`.
+    fn user_id(&self, claims: &OidcClaims) -> Result<Uuid, OidcAuthError> {
+        let sub = claims
+            .string("sub")
+            .ok_or(OidcAuthError::InvalidToken("missing sub".to_string()))?;
+        if let Ok(id) = Uuid::parse_str(&sub) {
+            return Ok(id);
+        }
+
+        let iss = claims.string("iss").unwrap_or_default();
+        let digest = Sha256::digest(format!("{}\n{}", iss, sub));
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+
+        Ok(Builder::from_custom_bytes(bytes).into_uuid())
+    }
+
+    fn username(&self, claims: &OidcClaims) -> Option<String> {
+        claims
+            .string("preferred_username")
+            .or_else(|| claims.string("nickname"))
+    }
+}
+
+pub fn claim_mapper(issuer: &OidcIssuerConfig) -> &'static dyn ClaimMapper {
+    match issuer.provider {
+        OidcProvider::Keycloak => &KeycloakClaimMapper,
+        OidcProvider::AzureAd => &AzureAdClaimMapper,
+        OidcProvider::Generic => &GenericClaimMapper,
+    }
+}
+
+/// Keeps the provider's id for the primary issuer. Any other issuer gets a UUIDv5 in a namespace
+/// of its own, so that a token whose `sub` copies an employee's id does not sign in as them.
+fn issuer_user_id(issuer: &OidcIssuerConfig, id: Uuid) -> Uuid {
+    match issuer.namespace_user_ids {
+        true => {
+            let namespace = Uuid::new_v5(&Uuid::NAMESPACE_URL, issuer.authority.as_bytes());
+            Uuid::new_v5(&namespace, id.as_bytes())
+        }
+        false => id,
+    }
+}
+
+fn uuid_claim(claims: &OidcClaims, name: &str) -> Result<Uuid, OidcAuthError> {
+    claims
+        .string(name)
+        .and_then(|value| Uuid::parse_str(&value).ok())
+        .ok_or(OidcAuthError::InvalidToken(format!("invalid {}", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    const USER_ID: &str = "5d1e6c0a-3f4b-4c8e-9a1d-2b7f6e8c9d01";
+
+    fn issuer(provider: OidcProvider) -> OidcIssuerConfig {
+        OidcIssuerConfig {
+            authority: "https://idp.example.com".to_string(),
+            client_id: "library".to_string(),
+            audience: None,
+            provider,
+            roles_claim: None,
+            admin_roles: vec!["admin".to_string()],
+            librarian_roles: vec!["librarian".to_string()],
+            tenant_claim: None,
+            namespace_user_ids: false,
+            algorithms: vec!["RS256".to_string()],
+            jwks_ttl_secs: 300,
+            jwks_refetch_interval_secs: 30,
+        }
+    }
+
+    fn claims(payload: Value) -> OidcClaims {
+        serde_json::from_value(payload).unwrap()
+    }
+
+    fn map(issuer: &OidcIssuerConfig, payload: Value) -> Result<OidcUserInfo, OidcAuthError> {
+        claim_mapper(issuer).map(&claims(payload), issuer, None)
+    }
+
+    #[test]
+    fn keycloak_tokens_read_realm_roles_and_scope() {
+        let user = map(
+            &issuer(OidcProvider::Keycloak),
+            json!({
+                "sub": USER_ID,
+                "name": "Ada Lovelace",
+                "email": "ada@example.com",
+                "preferred_username": "ada",
+                "scope": "openid profile email",
+                "realm_access": { "roles": ["offline_access", "Librarian"] },
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(user.id, Uuid::parse_str(USER_ID).unwrap());
+        assert_eq!(user.role, UserRoleDTO::Librarian);
+        assert_eq!(user.email.as_deref(), Some("ada@example.com"));
+        assert_eq!(user.username.as_deref(), Some("ada"));
+        // Standard scopes do not name a capability, so the role's capabilities apply as is
+        assert_eq!(user.scopes, None);
+        assert_eq!(user.tenant, TenantId::default());
+    }
+
+    #[test]
+    fn keycloak_rejects_a_sub_that_is_not_a_uuid() {
+        let result = map(
+            &issuer(OidcProvider::Keycloak),
+            json!({ "sub": "ada", "realm_access": { "roles": ["admin"] } }),
+        );
+
+        assert!(matches!(result, Err(OidcAuthError::InvalidToken(_))));
+    }
+
+    #[test]
+    fn azure_ad_tokens_use_the_object_id_and_scp() {
+        let user = map(
+            &issuer(OidcProvider::AzureAd),
+            json!({
+                "sub": "pairwise-subject",
+                "oid": USER_ID,
+                "upn": "ada@example.com",
+                "scp": "books.read view-all-history",
+                "roles": ["Admin"],
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(user.id, Uuid::parse_str(USER_ID).unwrap());
+        assert_eq!(user.role, UserRoleDTO::Admin);
+        assert_eq!(user.email.as_deref(), Some("ada@example.com"));
+        assert_eq!(
+            user.scopes,
+            Some(vec![
+                "books.read".to_string(),
+                "view-all-history".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn missing_or_empty_role_claims_map_to_regular_users() {
+        for provider in [
+            OidcProvider::Keycloak,
+            OidcProvider::AzureAd,
+            OidcProvider::Generic,
+        ] {
+            for roles in [
+                json!({}),
+                json!({ "roles": [], "realm_access": { "roles": [] } }),
+            ] {
+                let mut payload = json!({ "sub": USER_ID, "oid": USER_ID });
+                payload
+                    .as_object_mut()
+                    .unwrap()
+                    .extend(roles.as_object().unwrap().clone());
+
+                let user = map(&issuer(provider), payload).unwrap();
+
+                assert_eq!(user.role, UserRoleDTO::Regular);
+                assert_eq!(user.scopes, None);
+            }
+        }
+    }
+
+    #[test]
+    fn generic_tokens_derive_a_stable_id_from_a_non_uuid_sub() {
+        let issuer = issuer(OidcProvider::Generic);
+        let auth0 = |iss: &str| {
+            map(
+                &issuer,
+                json!({ "iss": iss, "sub": "auth0|ada", "nickname": "ada" }),
+            )
+            .unwrap()
+        };
+
+        let user = auth0("https://tenant.auth0.com/");
+
+        assert_eq!(user.id, auth0("https://tenant.auth0.com/").id);
+        assert_ne!(user.id, auth0("https://other.auth0.com/").id);
+        assert_eq!(user.username.as_deref(), Some("ada"));
+        assert!(matches!(
+            map(&issuer, json!({ "iss": "https://tenant.auth0.com/" })),
+            Err(OidcAuthError::InvalidToken(_))
+        ));
+    }
+
+    #[test]
+    fn configured_role_claims_follow_dotted_paths() {
+        let mut issuer = issuer(OidcProvider::Keycloak);
+        issuer.roles_claim = Some("resource_access.book-manager.roles".to_string());
+
+        let user = map(
+            &issuer,
+            json!({
+                "sub": USER_ID,
+                "realm_access": { "roles": ["librarian"] },
+                "resource_access": { "book-manager": { "roles": ["ADMIN"] } },
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(user.role, UserRoleDTO::Admin);
+    }
+
+    #[test]
+    fn strings_at_reads_arrays_and_space_separated_strings() {
+        let claims = claims(json!({
+            "groups": ["a", 1, "b"],
+            "scope": "openid  email",
+            "nested": { "value": "x", "number": 3 },
+        }));
+
+        assert_eq!(claims.strings_at("groups"), vec!["a", "b"]);
+        assert_eq!(claims.strings_at("scope"), vec!["openid", "email"]);
+        assert_eq!(claims.strings_at("nested.value"), vec!["x"]);
+        assert!(claims.strings_at("nested.number").is_empty());
+        assert!(claims.strings_at("nested.missing").is_empty());
+        assert!(claims.strings_at("missing.value").is_empty());
+    }
+
+    #[test]
+    fn additional_issuers_cannot_reuse_the_primary_issuers_ids() {
+        let payload = json!({ "sub": USER_ID, "oid": USER_ID });
+        let primary = issuer(OidcProvider::Keycloak);
+        let mut partner = issuer(OidcProvider::AzureAd);
+        partner.authority = "https://login.partner.example.com/v2.0".to_string();
+        partner.namespace_user_ids = true;
+        let mut other = issuer(OidcProvider::Keycloak);
+        other.authority = "https://idp.other.example.com".to_string();
+        other.namespace_user_ids = true;
+
+        let employee = map(&primary, payload.clone()).unwrap().id;
+        let impostor = map(&partner, payload.clone()).unwrap().id;
+
+        assert_eq!(employee, Uuid::parse_str(USER_ID).unwrap());
+        assert_ne!(impostor, employee);
+        assert_eq!(impostor, map(&partner, payload.clone()).unwrap().id);
+        assert_ne!(map(&other, payload).unwrap().id, impostor);
+    }
+
+    #[test]
+    fn roles_are_mapped_with_the_issuers_own_configuration() {
+        let payload =
+            json!({ "sub": USER_ID, "oid": USER_ID, "roles": ["admin", "BookManager.Librarian"] });
+        let primary = issuer(OidcProvider::Generic);
+        let mut partner = issuer(OidcProvider::AzureAd);
+        partner.admin_roles = vec!["BookManager.Admin".to_string()];
+        partner.librarian_roles = vec!["BookManager.Librarian".to_string()];
+
+        assert_eq!(
+            map(&primary, payload.clone()).unwrap().role,
+            UserRoleDTO::Admin
+        );
+        assert_eq!(map(&partner, payload).unwrap().role, UserRoleDTO::Librarian);
+    }
+}
@@ -1,54 +1,56 @@
-use std::str::FromStr;
-
 use aide::OperationInput;
 use application::user::dto::{GetOrCreateUserRequestDTO, UserRoleDTO};
+use domain::tenant::values::TenantId;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 use uuid::Uuid;
 
 use crate::error::ApiError;
 
+/// The validated token payload, kept as raw JSON so that each provider's mapper can pick its claims.
 #[derive(Debug, Deserialize)]
-pub struct KeycloakRealmAccess {
-    pub roles: Vec<String>,
-}
+pub struct OidcClaims(Map<String, Value>);
 
-#[derive(Debug, Deserialize)]
-pub struct KeycloakClaims {
-    pub sub: Uuid,
-    #[serde(default)]
-    pub preferred_username: Option<String>,
-    #[serde(default)]
-    pub name: Option<String>,
-    #[serde(default)]
-    pub email: Option<String>,
-    #[serde(default)]
-    pub realm_access: Option<KeycloakRealmAccess>,
-    pub exp: usize,
+impl OidcClaims {
+    pub fn string(&self, name: &str) -> Option<String> {
+        self.0.get(name).and_then(Value::as_str).map(str::to_string)
+    }
+
+    /// Resolves a dotted path such as `resource_access.book-manager.roles`. A string value is
+    /// read as a space separated list, as some providers do for `scope`-like claims.
+    pub fn strings_at(&self, path: &str) -> Vec<String> {
+        let mut segments = path.split('.');
+        let value = segments
+            .next()
+            .and_then(|first| self.0.get(first))
+            .and_then(|value| segments.try_fold(value, |value, segment| value.get(segment)));
+
+        match value {
+            Some(Value::Array(values)) => values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect(),
+            Some(Value::String(value)) => value.split_whitespace().map(str::to_string).collect(),
+            _ => vec![],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct OidcUserInfo {
     pub id: Uuid,
-    pub roles: Vec<String>,
+    pub role: UserRoleDTO,
     pub full_name: Option<String>,
     pub email: Option<String>,
     pub username: Option<String>,
+    /// OAuth scopes that narrow the role's capabilities, or `None` when the role's apply as is.
+    pub scopes: Option<Vec<String>>,
+    pub tenant: TenantId,
 }
 
 impl OperationInput for OidcUserInfo {}
 
-impl From<KeycloakClaims> for OidcUserInfo {
-    fn from(claims: KeycloakClaims) -> Self {
-        OidcUserInfo {
-            id: claims.sub,
-            roles: claims.realm_access.map(|ra| ra.roles).unwrap_or_default(),
-            full_name: claims.name,
-            email: claims.email,
-            username: claims.preferred_username,
-        }
-    }
-}
-
 impl TryFrom<OidcUserInfo> for GetOrCreateUserRequestDTO {
     type Error = ApiError;
 
@@ -60,21 +62,14 @@ impl TryFrom<OidcUserInfo> for GetOrCreateUserRequestDTO {
         let email = user_info
             .email
             .ok_or(ApiError::BadRequest("missing email".to_string()))?;
-        let role = if user_info
-            .roles
-            .iter()
-            .any(|v| UserRoleDTO::from_str(v).is_ok_and(|r| r == UserRoleDTO::Admin))
-        {
-            UserRoleDTO::Admin
-        } else {
-            UserRoleDTO::Regular
-        };
 
         Ok(GetOrCreateUserRequestDTO {
             id: user_info.id.into(),
             name: full_name,
             email,
-            role,
+            role: user_info.role,
+            scopes: user_info.scopes,
+            tenant: user_info.tenant,
         })
     }
 }
@@ -0,0 +1,119 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+use tokio::sync::{OnceCell, RwLock};
+
+use crate::auth::OidcAuthError;
+
+/// The subset of the provider's `/.well-known/openid-configuration` that token validation needs.
+#[derive(Debug, Deserialize)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub jwks_uri: String,
+}
+
+/// How long a failed fetch is remembered before the provider is asked again.
+const DISCOVERY_RETRY_BACKOFF: Duration = Duration::from_secs(10);
+
+enum Discovery {
+    Fetched(Arc<OidcProviderMetadata>),
+    Failed(Instant),
+}
+
+/// One discovery document, or the time of the last failed fetch, per issuer authority.
+static PROVIDER_METADATA: OnceCell<RwLock<HashMap<String, Discovery>>> = OnceCell::const_new();
+
+/// Fetches the discovery document once per authority. After a failed fetch, calls for that
+/// authority fail fast until the backoff has passed, so an unreachable provider is not hit by
+/// every request.
+pub async fn provider_metadata(
+    authority: &str,
+) -> Result<Arc<OidcProviderMetadata>, OidcAuthError> {
+    let cache = PROVIDER_METADATA
+        .get_or_init(|| async { RwLock::new(HashMap::new()) })
+        .await;
+    match cache.read().await.get(authority) {
+        Some(Discovery::Fetched(metadata)) => return Ok(metadata.clone()),
+        Some(Discovery::Failed(at)) if at.elapsed() < DISCOVERY_RETRY_BACKOFF => {
+            return Err(OidcAuthError::DiscoveryFetchError);
+        }
+        _ => {}
+    }
+
+    let result = fetch_provider_metadata(authority).await.map(Arc::new);
+    let entry = match &result {
+        Ok(metadata) => Discovery::Fetched(metadata.clone()),
+        Err(e) => {
+            tracing::warn!(%authority, error = %e, "OIDC discovery failed");
+            Discovery::Failed(Instant::now())
+        }
+    };
+    cache.write().await.insert(authority.to_string(), entry);
+
+    result
+}
+
+/// Returns the discovery document only if it has already been fetched.
+pub async fn cached_provider_metadata(authority: &str) -> Option<Arc<OidcProviderMetadata>> {
+    let cache = PROVIDER_METADATA.get()?;
+    match cache.read().await.get(authority) {
+        Some(Discovery::Fetched(metadata)) => Some(metadata.clone()),
+        _ => None,
+    }
+}
+
+async fn fetch_provider_metadata(authority: &str) -> Result<OidcProviderMetadata, OidcAuthError> {
+    let base = authority.trim_end_matches('/');
+    let url = format!("{}/.well-known/openid-configuration", base);
+
+    let resp = reqwest::get(url)
+        .await
+        .map_err(|_| OidcAuthError::DiscoveryFetchError)?;
+
+    if !resp.status().is_success() {
+        return Err(OidcAuthError::DiscoveryFetchError);
+    }
+
+    resp.json::<OidcProviderMetadata>()
+        .await
+        .map_err(|_| OidcAuthError::DiscoveryFetchError)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn failed_discovery_is_not_refetched_during_backoff() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let authority = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = stream.read(&mut [0; 1024]);
+                let _ = stream.write_all(
+                    b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                );
+            }
+        });
+
+        for _ in 0..3 {
+            assert!(matches!(
+                provider_metadata(&authority).await,
+                Err(OidcAuthError::DiscoveryFetchError)
+            ));
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
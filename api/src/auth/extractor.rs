@@ -1,4 +1,9 @@
-use axum::{RequestPartsExt, extract::FromRequestParts, http::request::Parts};
+use application::personal_access_token::token::is_personal_access_token;
+use axum::{
+    RequestPartsExt,
+    extract::{FromRequestParts, MatchedPath},
+    http::request::Parts,
+};
 
 use axum_extra::{
     TypedHeader,
@@ -6,9 +11,13 @@ use axum_extra::{
 };
 
 use crate::{
-    auth::{OidcAuthError, claims::OidcUserInfo, jwt::decode_and_validate_token},
+    auth::{
+        OidcAuthError, claim_mapper::claim_mapper, claims::OidcUserInfo,
+        jwt::decode_and_validate_token, scope::required_scope,
+    },
     error::ApiError,
     registry::AppRegistry,
+    tenant::host_tenant,
 };
 
 impl FromRequestParts<AppRegistry> for OidcUserInfo {
@@ -48,8 +57,63 @@ async fn oidc_from_request_parts(
         .await
         .map_err(|_| OidcAuthError::MissingToken)?;
 
-    let oidc_config = &state.config().oidc;
-    let claims = decode_and_validate_token(bearer.token(), oidc_config).await?;
+    if is_personal_access_token(bearer.token()) {
+        return personal_access_token_user(parts, state, bearer.token()).await;
+    }
+
+    let config = state.config();
+    let token = decode_and_validate_token(bearer.token(), &config.oidc).await?;
+
+    let host_tenant = host_tenant(parts, &config.tenant);
+
+    claim_mapper(token.issuer).map(&token.claims, token.issuer, host_tenant)
+}
+
+async fn personal_access_token_user(
+    parts: &Parts,
+    state: &AppRegistry,
+    token: &str,
+) -> Result<OidcUserInfo, OidcAuthError> {
+    let path = parts
+        .extensions
+        .get::<MatchedPath>()
+        .map(|path| path.as_str())
+        .unwrap_or(parts.uri.path());
+    let scope = required_scope(&parts.method, path).ok_or(OidcAuthError::InvalidToken(
+        "personal access tokens are not accepted here".into(),
+    ))?;
+
+    let credential = state
+        .personal_access_token_registry()
+        .authenticate_personal_access_token()
+        .execute(token)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Personal access token lookup failed");
+            OidcAuthError::TokenLookupError
+        })?
+        .ok_or(OidcAuthError::InvalidToken(
+            "unknown or expired personal access token".into(),
+        ))?;
+
+    if !credential.scopes.contains(&scope) {
+        return Err(OidcAuthError::InsufficientScope);
+    }
+
+    if let Some(host_tenant) = host_tenant(parts, &state.config().tenant)
+        && host_tenant != credential.tenant
+    {
+        return Err(OidcAuthError::TenantMismatch);
+    }
 
-    Ok(claims.into())
+    Ok(OidcUserInfo {
+        id: credential.user.id,
+        role: credential.user.role,
+        full_name: Some(credential.user.name),
+        email: Some(credential.user.email),
+        username: None,
+        // Named permissions are never granted to personal access tokens
+        scopes: Some(vec![]),
+        tenant: credential.tenant,
+    })
 }
@@ -1,9 +1,14 @@
-use jsonwebtoken::DecodingKey;
+use infrastructure::config::OidcIssuerConfig;
+use jsonwebtoken::{Algorithm, DecodingKey};
 use serde::Deserialize;
-use std::time::{Duration, Instant};
-use tokio::sync::{OnceCell, RwLock};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::{Mutex, OnceCell, RwLock};
 
-use crate::auth::OidcAuthError;
+use crate::auth::{OidcAuthError, discovery::provider_metadata};
 
 #[derive(Debug, Deserialize)]
 struct JwkSet {
@@ -12,62 +17,151 @@ struct JwkSet {
 
 #[derive(Debug, Deserialize, Clone)]
 struct Jwk {
-    kid: String,
+    #[serde(default)]
+    kid: Option<String>,
     kty: String,
-    n: String,
-    e: String,
+    #[serde(default)]
+    alg: Option<String>,
+    #[serde(default)]
+    crv: Option<String>,
+    #[serde(default)]
+    n: Option<String>,
+    #[serde(default)]
+    e: Option<String>,
+    #[serde(default)]
+    x: Option<String>,
+    #[serde(default)]
+    y: Option<String>,
 }
 
-const JWKS_TTL: Duration = Duration::from_secs(300);
+/// Unknown kids remembered per key set; tokens with further unknown kids fall through to the
+/// rate-limited refetch path instead of growing the set without bound.
+const MAX_UNKNOWN_KIDS: usize = 64;
 
 #[derive(Debug, Default)]
 struct JwksCache {
     keys: Vec<Jwk>,
+    /// When the current key set was fetched.
     fetched_at: Option<Instant>,
+    /// When a fetch was last attempted, whether or not it succeeded.
+    last_fetch: Option<Instant>,
+    /// Kids that were missing from this key set; rejected without a fetch until the set expires.
+    unknown_kids: HashSet<String>,
 }
 
 impl JwksCache {
-    fn is_fresh(&self) -> bool {
-        match self.fetched_at {
-            Some(at) => at.elapsed() < JWKS_TTL,
-            None => false,
+    fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|k| k.kid.as_deref() == Some(kid))
+    }
+
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        self.fetched_at.is_some_and(|at| at.elapsed() < ttl)
+    }
+
+    fn fetched_within(&self, interval: Duration) -> bool {
+        self.last_fetch.is_some_and(|at| at.elapsed() < interval)
+    }
+
+    fn remember_unknown(&mut self, kid: &str) {
+        if self.unknown_kids.len() < MAX_UNKNOWN_KIDS {
+            self.unknown_kids.insert(kid.to_string());
         }
     }
+
+    fn record_fetch(
+        &mut self,
+        fetched: Result<Vec<Jwk>, OidcAuthError>,
+    ) -> Result<(), OidcAuthError> {
+        let now = Instant::now();
+        self.last_fetch = Some(now);
+        self.keys = fetched?;
+        self.fetched_at = Some(now);
+        self.unknown_kids.clear();
+        Ok(())
+    }
+}
+
+/// The key set of one issuer authority. `fetch` is held for the duration of a fetch so that
+/// concurrent requests wait for it instead of fetching the same set again.
+#[derive(Debug, Default)]
+struct AuthorityJwks {
+    cache: RwLock<JwksCache>,
+    fetch: Mutex<()>,
 }
 
-static JWKS_CACHE: OnceCell<RwLock<JwksCache>> = OnceCell::const_new();
+/// One key set per issuer authority.
+static JWKS_CACHE: OnceCell<RwLock<HashMap<String, Arc<AuthorityJwks>>>> = OnceCell::const_new();
 
-pub async fn get_decoding_key(authority: &str, kid: &str) -> Result<DecodingKey, OidcAuthError> {
-    let cache = jwks_cache().await;
+/// Looks the key up in the cached set and fetches a new set only when the cached one has
+/// expired, or when the kid is unknown and the last fetch is older than the refetch interval.
+/// A failed fetch counts as a fetch, so an unreachable provider is not hit by every request.
+pub async fn get_decoding_key(
+    issuer: &OidcIssuerConfig,
+    kid: &str,
+    alg: Algorithm,
+) -> Result<DecodingKey, OidcAuthError> {
+    let ttl = Duration::from_secs(issuer.jwks_ttl_secs);
+    let refetch_interval = Duration::from_secs(issuer.jwks_refetch_interval_secs);
+    let jwks = authority_jwks(&issuer.authority).await;
     {
-        let read_guard = cache.read().await;
-        if read_guard.is_fresh()
-            && let Some(jwk) = read_guard.keys.iter().find(|k| k.kid == kid)
-        {
-            return decoding_key_from_jwk(jwk);
+        let cached = jwks.cache.read().await;
+        if cached.is_fresh(ttl) {
+            if let Some(jwk) = cached.find(kid) {
+                return decoding_key_from_jwk(jwk, alg);
+            }
+            if cached.unknown_kids.contains(kid) {
+                return Err(unknown_kid());
+            }
         }
     }
 
-    let keys = fetch_jwks(authority).await?;
-    let jwk = keys
-        .iter()
-        .find(|k| k.kid == kid)
-        .cloned()
-        .ok_or(OidcAuthError::InvalidToken("missing key".to_string()))?;
+    let _fetching = jwks.fetch.lock().await;
+    let mut cached = jwks.cache.write().await;
+    // Another request may have fetched the set while this one waited for the lock
+    if cached.is_fresh(ttl)
+        && let Some(jwk) = cached.find(kid)
     {
-        let mut write_guard = cache.write().await;
-        write_guard.keys = keys;
-        write_guard.fetched_at = Some(Instant::now());
+        return decoding_key_from_jwk(jwk, alg);
+    }
+    if cached.fetched_within(refetch_interval.min(ttl)) {
+        if !cached.is_fresh(ttl) {
+            return Err(OidcAuthError::JwksFetchError);
+        }
+        cached.remember_unknown(kid);
+        return Err(unknown_kid());
+    }
+
+    // Readers keep using the previous set while the new one is fetched
+    drop(cached);
+    let fetched = fetch_jwks(&issuer.authority).await;
+    let mut cached = jwks.cache.write().await;
+    cached.record_fetch(fetched)?;
+
+    match cached.find(kid) {
+        Some(jwk) => decoding_key_from_jwk(jwk, alg),
+        None => {
+            cached.remember_unknown(kid);
+            Err(unknown_kid())
+        }
     }
+}
 
-    decoding_key_from_jwk(&jwk)
+/// Refreshes the cached key set ahead of expiry so that requests rarely wait on the fetch.
+pub async fn refresh_jwks(authority: &str) -> Result<usize, OidcAuthError> {
+    let jwks = authority_jwks(authority).await;
+    let _fetching = jwks.fetch.lock().await;
+    let fetched = fetch_jwks(authority).await;
+
+    let mut cached = jwks.cache.write().await;
+    cached.record_fetch(fetched)?;
+
+    Ok(cached.keys.len())
 }
 
 async fn fetch_jwks(authority: &str) -> Result<Vec<Jwk>, OidcAuthError> {
-    let base = authority.trim_end_matches('/');
-    let url = format!("{}/protocol/openid-connect/certs", base);
+    let metadata = provider_metadata(authority).await?;
 
-    let resp = reqwest::get(url)
+    let resp = reqwest::get(&metadata.jwks_uri)
         .await
         .map_err(|_| OidcAuthError::JwksFetchError)?;
 
@@ -81,17 +175,160 @@ async fn fetch_jwks(authority: &str) -> Result<Vec<Jwk>, OidcAuthError> {
         .map_err(|_| OidcAuthError::JwksFetchError)
 }
 
-async fn jwks_cache() -> &'static RwLock<JwksCache> {
-    JWKS_CACHE
-        .get_or_init(|| async { RwLock::new(JwksCache::default()) })
+async fn authority_jwks(authority: &str) -> Arc<AuthorityJwks> {
+    let authorities = JWKS_CACHE
+        .get_or_init(|| async { RwLock::new(HashMap::new()) })
+        .await;
+    if let Some(jwks) = authorities.read().await.get(authority) {
+        return jwks.clone();
+    }
+
+    authorities
+        .write()
         .await
+        .entry(authority.to_string())
+        .or_default()
+        .clone()
+}
+
+fn unknown_kid() -> OidcAuthError {
+    OidcAuthError::InvalidToken("missing key".to_string())
 }
 
-fn decoding_key_from_jwk(jwk: &Jwk) -> Result<DecodingKey, OidcAuthError> {
-    if jwk.kty != "RSA" {
-        return Err(OidcAuthError::InvalidToken("invalid key type".to_string()));
+/// Builds the key for the token's algorithm, refusing keys of another type or curve.
+fn decoding_key_from_jwk(jwk: &Jwk, alg: Algorithm) -> Result<DecodingKey, OidcAuthError> {
+    let invalid_key = || OidcAuthError::InvalidToken("invalid key type".to_string());
+
+    if let Some(jwk_alg) = &jwk.alg
+        && jwk_alg.parse::<Algorithm>().ok() != Some(alg)
+    {
+        return Err(invalid_key());
+    }
+
+    let key = match (jwk.kty.as_str(), jwk.crv.as_deref(), alg) {
+        (
+            "RSA",
+            _,
+            Algorithm::RS256
+            | Algorithm::RS384
+            | Algorithm::RS512
+            | Algorithm::PS256
+            | Algorithm::PS384
+            | Algorithm::PS512,
+        ) => DecodingKey::from_rsa_components(
+            jwk.n.as_deref().ok_or_else(invalid_key)?,
+            jwk.e.as_deref().ok_or_else(invalid_key)?,
+        ),
+        ("EC", Some("P-256"), Algorithm::ES256) | ("EC", Some("P-384"), Algorithm::ES384) => {
+            DecodingKey::from_ec_components(
+                jwk.x.as_deref().ok_or_else(invalid_key)?,
+                jwk.y.as_deref().ok_or_else(invalid_key)?,
+            )
+        }
+        ("OKP", Some("Ed25519"), Algorithm::EdDSA) => {
+            DecodingKey::from_ed_components(jwk.x.as_deref().ok_or_else(invalid_key)?)
+        }
+        _ => return Err(invalid_key()),
+    };
+
+    key.map_err(|e| OidcAuthError::InvalidToken(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::config::OidcProvider;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Serves the discovery document and answers every JWKS request with `jwks_response`,
+    /// counting the JWKS requests.
+    fn provider(jwks_response: &'static str) -> (OidcIssuerConfig, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let authority = format!("http://{}", listener.local_addr().unwrap());
+        let jwks_requests = Arc::new(AtomicUsize::new(0));
+        let counter = jwks_requests.clone();
+        let discovery = format!(r#"{{"issuer":"{authority}","jwks_uri":"{authority}/jwks"}}"#);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request = [0; 1024];
+                let read = stream.read(&mut request).unwrap_or(0);
+                let request = String::from_utf8_lossy(&request[..read]);
+                let response = match request.starts_with("GET /jwks ") {
+                    true => {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        jwks_response.to_string()
+                    }
+                    false => format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        discovery.len(),
+                        discovery
+                    ),
+                };
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let issuer = OidcIssuerConfig {
+            authority,
+            client_id: "library".to_string(),
+            audience: None,
+            provider: OidcProvider::Generic,
+            roles_claim: None,
+            admin_roles: vec![],
+            librarian_roles: vec![],
+            tenant_claim: None,
+            namespace_user_ids: false,
+            algorithms: vec!["RS256".to_string()],
+            jwks_ttl_secs: 300,
+            jwks_refetch_interval_secs: 30,
+        };
+        (issuer, jwks_requests)
     }
 
-    DecodingKey::from_rsa_components(&jwk.n, &jwk.e)
-        .map_err(|e| OidcAuthError::InvalidToken(e.to_string()))
+    const EMPTY_KEY_SET: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 11\r\nconnection: close\r\n\r\n{\"keys\":[]}";
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_unknown_kids_share_one_fetch() {
+        let (issuer, jwks_requests) = provider(EMPTY_KEY_SET);
+        let issuer = Arc::new(issuer);
+
+        let mut lookups = tokio::task::JoinSet::new();
+        for i in 0..8 {
+            let issuer = issuer.clone();
+            lookups.spawn(async move {
+                get_decoding_key(&issuer, &format!("kid-{i}"), Algorithm::RS256).await
+            });
+        }
+        for result in lookups.join_all().await {
+            assert!(matches!(result, Err(OidcAuthError::InvalidToken(_))));
+        }
+        assert_eq!(jwks_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_fetch_is_not_retried_within_refetch_interval() {
+        let (issuer, jwks_requests) = provider(UNAVAILABLE);
+
+        for _ in 0..3 {
+            assert!(matches!(
+                get_decoding_key(&issuer, "kid", Algorithm::RS256).await,
+                Err(OidcAuthError::JwksFetchError)
+            ));
+        }
+        assert_eq!(jwks_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn unknown_kids_are_capped() {
+        let mut cache = JwksCache::default();
+        for i in 0..MAX_UNKNOWN_KIDS * 2 {
+            cache.remember_unknown(&format!("kid-{i}"));
+        }
+        assert_eq!(cache.unknown_kids.len(), MAX_UNKNOWN_KIDS);
+    }
 }
@@ -1,46 +1,215 @@
-use infrastructure::config::OidcConfig;
+use infrastructure::config::{OidcConfig, OidcIssuerConfig};
 use jsonwebtoken::errors::ErrorKind;
-use jsonwebtoken::{Algorithm, Validation, decode, decode_header};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
 
 use crate::auth::OidcAuthError;
-use crate::auth::{claims::KeycloakClaims, jwks};
+use crate::auth::{
+    claims::OidcClaims,
+    discovery::{cached_provider_metadata, provider_metadata},
+    jwks,
+};
 
-pub async fn decode_and_validate_token(
+pub struct ValidatedToken<'a> {
+    pub claims: OidcClaims,
+    pub issuer: &'a OidcIssuerConfig,
+}
+
+pub async fn decode_and_validate_token<'a>(
     token: &str,
-    oidc: &OidcConfig,
-) -> Result<KeycloakClaims, OidcAuthError> {
+    oidc: &'a OidcConfig,
+) -> Result<ValidatedToken<'a>, OidcAuthError> {
     let header = decode_header(token).map_err(|e| OidcAuthError::InvalidToken(e.to_string()))?;
-    if header.alg != Algorithm::RS256 {
-        return Err(OidcAuthError::InvalidToken("invalid algorithm".to_string()));
-    }
 
     let kid = header
         .kid
         .ok_or(OidcAuthError::InvalidToken("missing kid".to_string()))?;
-    let decoding_key = jwks::get_decoding_key(&oidc.authority, &kid).await?;
+    let iss = unverified_issuer(token)?;
+    let issuer = trusted_issuer(oidc, &iss).await?;
+    if !is_allowed_algorithm(issuer, header.alg) {
+        return Err(OidcAuthError::InvalidToken("invalid algorithm".to_string()));
+    }
+    let decoding_key = jwks::get_decoding_key(issuer, &kid, header.alg).await?;
 
-    let validation = build_validation(oidc);
+    let validation = build_validation(issuer, &iss, header.alg);
 
-    let token_data = decode::<KeycloakClaims>(token, &decoding_key, &validation).map_err(|e| {
-        match e.kind() {
+    let token_data =
+        decode::<OidcClaims>(token, &decoding_key, &validation).map_err(|e| match e.kind() {
             ErrorKind::ExpiredSignature => OidcAuthError::Expired,
             _ => OidcAuthError::InvalidToken(e.to_string()),
+        })?;
+
+    Ok(ValidatedToken {
+        claims: token_data.claims,
+        issuer,
+    })
+}
+
+/// Reads `iss` before the signature is checked, only to pick the issuer whose keys verify the token.
+fn unverified_issuer(token: &str) -> Result<String, OidcAuthError> {
+    let mut validation = Validation::default();
+    validation.insecure_disable_signature_validation();
+    validation.validate_exp = false;
+    validation.validate_aud = false;
+    validation.required_spec_claims.clear();
+
+    decode::<OidcClaims>(token, &DecodingKey::from_secret(&[]), &validation)
+        .map_err(|e| OidcAuthError::InvalidToken(e.to_string()))?
+        .claims
+        .string("iss")
+        .ok_or(OidcAuthError::InvalidToken("missing iss".to_string()))
+}
+
+/// Matches `iss` against the discovered issuer of each configured authority. Authorities whose
+/// discovery document is already cached are checked first, so a token from a healthy provider
+/// never waits on one that is unreachable. An unreachable provider only fails the request when
+/// no other provider matches; its failure is cached by discovery, so it is not refetched for
+/// every request.
+async fn trusted_issuer<'a>(
+    oidc: &'a OidcConfig,
+    iss: &str,
+) -> Result<&'a OidcIssuerConfig, OidcAuthError> {
+    let mut uncached = Vec::new();
+    for issuer in &oidc.issuers {
+        match cached_provider_metadata(&issuer.authority).await {
+            Some(metadata) if metadata.issuer == iss => return Ok(issuer),
+            Some(_) => {}
+            None => uncached.push(issuer),
+        }
+    }
+
+    let mut discovery_failed = false;
+    for issuer in uncached {
+        match provider_metadata(&issuer.authority).await {
+            Ok(metadata) if metadata.issuer == iss => return Ok(issuer),
+            Ok(_) => {}
+            Err(_) => discovery_failed = true,
         }
-    })?;
+    }
+
+    match discovery_failed {
+        true => Err(OidcAuthError::DiscoveryFetchError),
+        false => Err(OidcAuthError::InvalidToken("untrusted issuer".to_string())),
+    }
+}
 
-    Ok(token_data.claims)
+/// Names in `algorithms` that jsonwebtoken does not know never match.
+fn is_allowed_algorithm(issuer: &OidcIssuerConfig, alg: Algorithm) -> bool {
+    issuer.algorithms.iter().any(|allowed| {
+        allowed
+            .parse::<Algorithm>()
+            .is_ok_and(|allowed| allowed == alg)
+    })
 }
 
-fn build_validation(oidc: &OidcConfig) -> Validation {
-    let mut validation = Validation::new(Algorithm::RS256);
+fn build_validation(issuer: &OidcIssuerConfig, iss: &str, alg: Algorithm) -> Validation {
+    let mut validation = Validation::new(alg);
 
-    if let Some(aud) = &oidc.audience {
+    if let Some(aud) = &issuer.audience {
         validation.set_audience(&[aud.as_str()]);
     } else {
         validation.validate_aud = false;
     }
 
-    validation.iss = Some(vec![oidc.authority.clone()].into_iter().collect());
+    validation.iss = Some(vec![iss.to_string()].into_iter().collect());
 
     validation
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    use infrastructure::config::OidcProvider;
+
+    use super::*;
+
+    fn issuer(authority: String) -> OidcIssuerConfig {
+        OidcIssuerConfig {
+            authority,
+            client_id: "library".to_string(),
+            audience: None,
+            provider: OidcProvider::Generic,
+            roles_claim: None,
+            admin_roles: vec![],
+            librarian_roles: vec![],
+            tenant_claim: None,
+            namespace_user_ids: false,
+            algorithms: vec!["RS256".to_string()],
+            jwks_ttl_secs: 300,
+            jwks_refetch_interval_secs: 30,
+        }
+    }
+
+    /// Serves a discovery document naming `iss` as the issuer.
+    fn provider(iss: &'static str) -> OidcIssuerConfig {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let authority = format!("http://{}", listener.local_addr().unwrap());
+        let discovery = format!(r#"{{"issuer":"{iss}","jwks_uri":"{authority}/jwks"}}"#);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let _ = stream.read(&mut [0; 1024]);
+                let _ = stream.write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        discovery.len(),
+                        discovery
+                    )
+                    .as_bytes(),
+                );
+            }
+        });
+        issuer(authority)
+    }
+
+    /// An authority that refuses every connection.
+    fn unreachable_provider() -> OidcIssuerConfig {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let authority = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        issuer(authority)
+    }
+
+    #[tokio::test]
+    async fn tokens_are_validated_by_the_issuer_they_name() {
+        let oidc = OidcConfig {
+            issuers: vec![
+                provider("https://idp.example.com/realms/books"),
+                unreachable_provider(),
+                provider("https://login.partner.example.com/v2.0"),
+            ],
+        };
+
+        let partner = trusted_issuer(&oidc, "https://login.partner.example.com/v2.0")
+            .await
+            .unwrap();
+        let primary = trusted_issuer(&oidc, "https://idp.example.com/realms/books")
+            .await
+            .unwrap();
+
+        assert_eq!(partner.authority, oidc.issuers[2].authority);
+        assert_eq!(primary.authority, oidc.primary().authority);
+    }
+
+    #[tokio::test]
+    async fn unknown_issuers_are_rejected() {
+        let healthy = OidcConfig {
+            issuers: vec![provider("https://idp.example.com/realms/library")],
+        };
+        let degraded = OidcConfig {
+            issuers: vec![
+                provider("https://idp.example.com/realms/catalog"),
+                unreachable_provider(),
+            ],
+        };
+
+        assert!(matches!(
+            trusted_issuer(&healthy, "https://evil.example.com").await,
+            Err(OidcAuthError::InvalidToken(_))
+        ));
+        assert!(matches!(
+            trusted_issuer(&degraded, "https://evil.example.com").await,
+            Err(OidcAuthError::DiscoveryFetchError)
+        ));
+    }
+}
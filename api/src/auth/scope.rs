@@ -0,0 +1,105 @@
+use application::personal_access_token::dto::TokenScopeDTO;
+use axum::http::Method;
+
+/// The scope a personal access token needs for a route, or `None` when such tokens are not
+/// accepted there at all. Token management and admin routes always require an OIDC login.
+pub fn required_scope(method: &Method, path: &str) -> Option<TokenScopeDTO> {
+    let path = path.strip_prefix("/api").unwrap_or(path);
+
+    if path.starts_with("/admin") || path.starts_with("/users/me/tokens") {
+        return None;
+    }
+
+    if *method == Method::POST
+        && (path.ends_with("/checkouts") || path.ends_with("/return"))
+        && path.starts_with("/books/")
+    {
+        return Some(TokenScopeDTO::CheckoutsWrite);
+    }
+
+    if *method == Method::GET || *method == Method::HEAD {
+        return Some(TokenScopeDTO::BooksRead);
+    }
+
+    if path.starts_with("/books") {
+        return Some(TokenScopeDTO::BooksWrite);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors the extractor: the route must accept tokens and the token must hold its scope.
+    fn accepts(scopes: &[TokenScopeDTO], method: Method, path: &str) -> bool {
+        required_scope(&method, path).is_some_and(|scope| scopes.contains(&scope))
+    }
+
+    #[test]
+    fn read_tokens_are_refused_on_write_routes() {
+        let read = [TokenScopeDTO::BooksRead];
+
+        assert!(accepts(&read, Method::GET, "/api/books/"));
+        assert!(accepts(&read, Method::GET, "/api/books/{book_id}"));
+        assert!(accepts(&read, Method::HEAD, "/api/opds/v2/new"));
+        assert!(!accepts(&read, Method::POST, "/api/books/"));
+        assert!(!accepts(&read, Method::PUT, "/api/books/{book_id}"));
+        assert!(!accepts(&read, Method::DELETE, "/api/books/{book_id}"));
+        assert!(!accepts(&read, Method::POST, "/api/books/import"));
+        assert!(!accepts(
+            &read,
+            Method::POST,
+            "/api/books/{book_id}/checkouts"
+        ));
+        assert!(!accepts(&read, Method::POST, "/api/books/{book_id}/return"));
+        assert!(!accepts(&read, Method::POST, "/api/groups/"));
+    }
+
+    #[test]
+    fn write_scopes_cover_only_their_own_routes() {
+        assert!(accepts(
+            &[TokenScopeDTO::CheckoutsWrite],
+            Method::POST,
+            "/api/books/{book_id}/checkouts"
+        ));
+        assert!(!accepts(
+            &[TokenScopeDTO::CheckoutsWrite],
+            Method::PUT,
+            "/api/books/{book_id}"
+        ));
+        assert!(accepts(
+            &[TokenScopeDTO::BooksWrite],
+            Method::PUT,
+            "/api/books/{book_id}"
+        ));
+        assert!(!accepts(
+            &[TokenScopeDTO::BooksWrite],
+            Method::POST,
+            "/api/books/{book_id}/return"
+        ));
+    }
+
+    #[test]
+    fn admin_and_token_routes_refuse_every_token() {
+        let all = [
+            TokenScopeDTO::BooksRead,
+            TokenScopeDTO::BooksWrite,
+            TokenScopeDTO::CheckoutsWrite,
+        ];
+
+        for (method, path) in [
+            (Method::GET, "/api/admin/roles"),
+            (Method::PUT, "/api/admin/roles/{role}"),
+            (Method::GET, "/api/admin/outbox"),
+            (Method::POST, "/api/admin/jobs/{job_name}/run"),
+            (Method::GET, "/api/users/me/tokens"),
+            (Method::POST, "/api/users/me/tokens"),
+            (Method::DELETE, "/api/users/me/tokens/{token_id}"),
+        ] {
+            assert_eq!(required_scope(&method, path), None, "{method} {path}");
+            assert!(!accepts(&all, method, path));
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use aide::OperationOutput;
 use application::shared::error::ApplicationError;
 use axum::response::IntoResponse;
-use domain::shared::error::DomainError;
+use domain::shared::error::{DomainError, PersistenceError};
 use reqwest::StatusCode;
 use thiserror::Error;
 
@@ -19,6 +19,12 @@ pub enum ApiError {
     Forbidden,
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("Conflict: {0}")]
+    Conflict(String),
+    #[error("Precondition failed")]
+    PreconditionFailed,
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(String),
 }
 
 impl From<ApplicationError> for ApiError {
@@ -28,8 +34,13 @@ impl From<ApplicationError> for ApiError {
                 DomainError::NotFound => ApiError::NotFound,
                 DomainError::Forbidden => ApiError::Forbidden,
                 DomainError::ValidationError(msg) => ApiError::BadRequest(msg.to_string()),
+                DomainError::VersionMismatch => ApiError::PreconditionFailed,
+                DomainError::AlreadyCheckedOut => ApiError::Conflict(domain_err.to_string()),
             },
             ApplicationError::ValidationError(err) => ApiError::BadRequest(err.to_string()),
+            ApplicationError::PersistenceError(PersistenceError::Conflict) => {
+                ApiError::Conflict("The resource was modified concurrently".to_string())
+            }
             ApplicationError::PersistenceError(_) => ApiError::InternalError(err),
             ApplicationError::NotFound => ApiError::NotFound,
             ApplicationError::Forbidden => ApiError::Forbidden,
@@ -44,9 +55,12 @@ impl From<OidcAuthError> for ApiError {
             OidcAuthError::MissingToken
             | OidcAuthError::InvalidToken(_)
             | OidcAuthError::Expired => ApiError::Unauthorized,
-            OidcAuthError::JwksFetchError => ApiError::InternalError(
-                ApplicationError::InternalError("JWKS fetch error".to_string()),
-            ),
+            OidcAuthError::InsufficientScope | OidcAuthError::TenantMismatch => ApiError::Forbidden,
+            OidcAuthError::JwksFetchError
+            | OidcAuthError::DiscoveryFetchError
+            | OidcAuthError::TokenLookupError => {
+                ApiError::InternalError(ApplicationError::InternalError(err.to_string()))
+            }
         }
     }
 }
@@ -58,6 +72,11 @@ impl IntoResponse for ApiError {
             ApiError::NotFound => (StatusCode::NOT_FOUND, None),
             ApiError::Forbidden => (StatusCode::FORBIDDEN, None),
             ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, None),
+            ApiError::Conflict(msg) => (StatusCode::CONFLICT, Some(msg.to_string())),
+            ApiError::PreconditionFailed => (StatusCode::PRECONDITION_FAILED, None),
+            ApiError::UnprocessableEntity(msg) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, Some(msg.to_string()))
+            }
             ApiError::InternalError(_) => (StatusCode::INTERNAL_SERVER_ERROR, None),
         };
 
@@ -0,0 +1,79 @@
+use std::sync::{Arc, RwLock};
+
+use domain::event::DomainEvent;
+use tokio::sync::broadcast;
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Fans the events committed on any replica out to the SSE connections held by this process.
+pub struct EventStreamHub {
+    sender: RwLock<broadcast::Sender<Arc<DomainEvent>>>,
+}
+
+impl EventStreamHub {
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<DomainEvent>> {
+        self.sender.read().unwrap().subscribe()
+    }
+
+    pub fn publish(&self, event: DomainEvent) {
+        // Sending only fails when nobody is connected, which is not an error
+        let _ = self.sender.read().unwrap().send(Arc::new(event));
+    }
+
+    /// Closes every open stream so that clients reconnect and catch up via Last-Event-ID.
+    pub fn disconnect_all(&self) {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        *self.sender.write().unwrap() = sender;
+    }
+}
+
+impl Default for EventStreamHub {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        EventStreamHub {
+            sender: RwLock::new(sender),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        audit::{Actor, AuditContext, clock::SystemClock},
+        book::values::BookId,
+        event::DomainEventKind,
+        tenant::values::TenantId,
+        user::values::UserId,
+    };
+    use tokio::sync::broadcast::error::RecvError;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn book_returned() -> DomainEvent {
+        let actor = Actor::new_system(TenantId::default());
+        DomainEvent::new(
+            &AuditContext::new(&actor, &SystemClock),
+            DomainEventKind::BookReturned {
+                book_id: BookId::from(Uuid::new_v4()),
+                owner_id: UserId::from(Uuid::new_v4()),
+                checkout_id: Uuid::new_v4(),
+                checked_out_to: UserId::from(Uuid::new_v4()),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn disconnecting_closes_open_streams_but_not_new_ones() {
+        let hub = EventStreamHub::default();
+        let mut before = hub.subscribe();
+
+        hub.disconnect_all();
+        let mut after = hub.subscribe();
+        let event = book_returned();
+        hub.publish(event.clone());
+
+        assert!(matches!(before.recv().await, Err(RecvError::Closed)));
+        assert_eq!(after.recv().await.unwrap().id(), event.id());
+    }
+}
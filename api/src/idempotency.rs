@@ -0,0 +1,120 @@
+use application::{
+    idempotency::dto::{IdempotencyOutcomeDTO, IdempotentRequestDTO, IdempotentResponseDTO},
+    shared::error::ApplicationError,
+};
+use axum::{
+    body::{Body, to_bytes},
+    extract::{FromRequestParts, Request, State},
+    http::{HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{auth::OidcUserInfo, error::ApiError, registry::AppRegistry};
+
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+pub const IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// Replays the stored response when a POST/PUT/PATCH is retried with the same `Idempotency-Key`.
+/// Requests without the header, or without valid credentials, pass straight through.
+pub async fn idempotency_layer(
+    State(registry): State<AppRegistry>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH
+    ) {
+        return Ok(next.run(request).await);
+    }
+    let Some(key) = request.headers().get(&IDEMPOTENCY_KEY) else {
+        return Ok(next.run(request).await);
+    };
+    let key = key
+        .to_str()
+        .map_err(|_| ApiError::BadRequest("Invalid Idempotency-Key header".to_string()))?
+        .to_string();
+
+    let (mut parts, body) = request.into_parts();
+    let Ok(user_info) = OidcUserInfo::from_request_parts(&mut parts, &registry).await else {
+        return Ok(next.run(Request::from_parts(parts, body)).await);
+    };
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let body = to_bytes(body, registry.config().idempotency.max_body_bytes)
+        .await
+        .map_err(|_| ApiError::BadRequest("Request body is too large".to_string()))?;
+
+    let idempotency_registry = registry.idempotency_registry();
+    let outcome = idempotency_registry
+        .begin_idempotent_request()
+        .execute(
+            &actor,
+            &IdempotentRequestDTO {
+                key: key.clone(),
+                method: parts.method.to_string(),
+                path: parts.uri.path().to_string(),
+                query: parts.uri.query().map(str::to_string),
+                body: body.to_vec(),
+            },
+        )
+        .await?;
+
+    match outcome {
+        IdempotencyOutcomeDTO::Proceed => {}
+        IdempotencyOutcomeDTO::Replay(response) => return Ok(replay(response)),
+        IdempotencyOutcomeDTO::InProgress => {
+            return Err(ApiError::Conflict(
+                "A request with this Idempotency-Key is still in progress".to_string(),
+            ));
+        }
+        IdempotencyOutcomeDTO::KeyReused => {
+            return Err(ApiError::UnprocessableEntity(
+                "Idempotency-Key was already used for a different request".to_string(),
+            ));
+        }
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+
+    let (parts, body) = response.into_parts();
+    let body = to_bytes(body, usize::MAX)
+        .await
+        .map_err(|e| ApiError::InternalError(ApplicationError::InternalError(e.to_string())))?;
+
+    let stored = IdempotentResponseDTO {
+        status: parts.status.as_u16(),
+        content_type: parts
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body: body.to_vec(),
+    };
+    // The response has already been produced; a failure here only costs the replay
+    if let Err(e) = idempotency_registry
+        .complete_idempotent_request()
+        .execute(&actor, &key, &stored)
+        .await
+    {
+        tracing::warn!(error = %e, "Failed to store idempotent response");
+    }
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+fn replay(stored: IdempotentResponseDTO) -> Response {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, stored.body).into_response();
+    let headers = response.headers_mut();
+    headers.remove(header::CONTENT_TYPE);
+    if let Some(content_type) = stored
+        .content_type
+        .and_then(|v| HeaderValue::from_str(&v).ok())
+    {
+        headers.insert(header::CONTENT_TYPE, content_type);
+    }
+    headers.insert(IDEMPOTENT_REPLAYED, HeaderValue::from_static("true"));
+    response
+}
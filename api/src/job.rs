@@ -0,0 +1,27 @@
+use application::{job::interface::Job, shared::error::ApplicationError};
+use async_trait::async_trait;
+use derive_new::new;
+
+use crate::auth::refresh_jwks;
+
+#[derive(new)]
+pub struct JwksPrewarmJob {
+    authorities: Vec<String>,
+}
+
+#[async_trait]
+impl Job for JwksPrewarmJob {
+    fn name(&self) -> &'static str {
+        "jwks_prewarm"
+    }
+
+    async fn run(&self) -> Result<(), ApplicationError> {
+        for authority in &self.authorities {
+            let count = refresh_jwks(authority)
+                .await
+                .map_err(|e| ApplicationError::InternalError(e.to_string()))?;
+            tracing::debug!(authority = %authority, keys = count, "Refreshed JWKS");
+        }
+        Ok(())
+    }
+}
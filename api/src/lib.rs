@@ -1,5 +1,11 @@
 pub mod auth;
 pub mod error;
+pub mod event_stream;
+pub mod idempotency;
+pub mod job;
 pub mod logger;
+pub mod precondition;
 pub mod registry;
+pub mod relay;
 pub mod router;
+pub mod tenant;
@@ -1,10 +1,16 @@
 use std::net::{Ipv4Addr, SocketAddr};
 
 use api::{
+    idempotency::idempotency_layer,
     logger::{build_trace_layer, init_logger},
     registry::AppRegistry,
+    relay::{
+        spawn_book_import_worker, spawn_event_stream_feed, spawn_job_scheduler, spawn_outbox_relay,
+        spawn_webhook_delivery,
+    },
     router::build_router,
 };
+use axum::middleware::from_fn_with_state;
 use tracing::info;
 #[cfg(debug_assertions)]
 use {api::router::export_openapi_schema, std::path::Path, tracing::warn};
@@ -27,7 +33,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    spawn_outbox_relay(registry.clone());
+    spawn_webhook_delivery(registry.clone());
+    spawn_book_import_worker(registry.clone());
+    spawn_job_scheduler(registry.clone());
+    spawn_event_stream_feed(registry.clone());
+
     let app = build_router(&config.oidc)
+        .layer(from_fn_with_state(registry.clone(), idempotency_layer))
         .layer(build_trace_layer())
         .with_state(registry);
 
@@ -0,0 +1,143 @@
+use aide::{
+    OperationInput, OperationOutput,
+    generate::GenContext,
+    openapi::{Operation, Response, StatusCode},
+};
+use axum::{
+    Json,
+    extract::FromRequestParts,
+    http::{HeaderValue, header, request::Parts},
+    response::IntoResponse,
+};
+use serde::Serialize;
+
+use crate::error::ApiError;
+
+/// Entity version from the `If-Match` header. `None` when the header is absent or `*`.
+pub struct IfMatch(pub Option<u32>);
+
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(header::IF_MATCH) else {
+            return Ok(IfMatch(None));
+        };
+
+        let value = value
+            .to_str()
+            .map_err(|_| ApiError::BadRequest("Invalid If-Match header".to_string()))?
+            .trim();
+
+        if value == "*" {
+            return Ok(IfMatch(None));
+        }
+
+        // Only our own strong tags can match; anything else (weak tags, lists of
+        // tags from another representation) is a failed precondition
+        value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .and_then(|v| v.parse().ok())
+            .map(|version| IfMatch(Some(version)))
+            .ok_or(ApiError::PreconditionFailed)
+    }
+}
+
+impl OperationInput for IfMatch {}
+
+/// JSON response carrying the entity version as a strong `ETag`.
+pub struct ETagged<T> {
+    version: u32,
+    body: T,
+}
+
+impl<T> ETagged<T> {
+    pub fn new(version: u32, body: T) -> Self {
+        ETagged { version, body }
+    }
+}
+
+impl<T: Serialize> IntoResponse for ETagged<T> {
+    fn into_response(self) -> axum::response::Response {
+        let mut response = Json(self.body).into_response();
+        if let Ok(etag) = HeaderValue::from_str(&format!("\"{}\"", self.version)) {
+            response.headers_mut().insert(header::ETAG, etag);
+        }
+        response
+    }
+}
+
+impl<T> OperationOutput for ETagged<T>
+where
+    Json<T>: OperationOutput,
+{
+    type Inner = T;
+
+    fn operation_response(ctx: &mut GenContext, operation: &mut Operation) -> Option<Response> {
+        Json::<T>::operation_response(ctx, operation)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<StatusCode>, Response)> {
+        Json::<T>::inferred_responses(ctx, operation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use application::shared::error::ApplicationError;
+    use axum::http::{Request, StatusCode};
+    use domain::shared::error::{DomainError, PersistenceError};
+
+    use super::*;
+
+    async fn if_match(value: Option<&str>) -> Result<Option<u32>, ApiError> {
+        let mut request = Request::builder();
+        if let Some(value) = value {
+            request = request.header(header::IF_MATCH, value);
+        }
+        let (mut parts, _) = request.body(()).unwrap().into_parts();
+
+        IfMatch::from_request_parts(&mut parts, &())
+            .await
+            .map(|IfMatch(version)| version)
+    }
+
+    #[tokio::test]
+    async fn if_match_accepts_only_our_strong_tags() {
+        assert_eq!(if_match(None).await.unwrap(), None);
+        assert_eq!(if_match(Some("*")).await.unwrap(), None);
+        assert_eq!(if_match(Some("\"3\"")).await.unwrap(), Some(3));
+
+        for value in ["W/\"3\"", "3", "\"3\", \"4\"", "\"abc\""] {
+            assert!(matches!(
+                if_match(Some(value)).await,
+                Err(ApiError::PreconditionFailed)
+            ));
+        }
+    }
+
+    #[test]
+    fn etag_carries_the_entity_version() {
+        let response = ETagged::new(7, serde_json::json!({})).into_response();
+
+        assert_eq!(response.headers()[header::ETAG], "\"7\"");
+    }
+
+    #[test]
+    fn stale_versions_map_to_412_and_concurrent_writes_to_409() {
+        let mismatch = ApiError::from(ApplicationError::DomainError(DomainError::VersionMismatch));
+        let conflict = ApiError::from(ApplicationError::PersistenceError(
+            PersistenceError::Conflict,
+        ));
+
+        assert_eq!(
+            mismatch.into_response().status(),
+            StatusCode::PRECONDITION_FAILED
+        );
+        assert_eq!(conflict.into_response().status(), StatusCode::CONFLICT);
+    }
+}
@@ -1,21 +1,68 @@
 use std::sync::Arc;
 
-use application::{book::BookRegistry, user::UserRegistry};
+use application::{
+    book::BookRegistry,
+    book_import::BookImportRegistry,
+    event::EventRegistry,
+    group::GroupRegistry,
+    idempotency::IdempotencyRegistry,
+    job::{JobRegistry, schedule::ScheduledJob},
+    notification::{NotificationRegistry, policy::NotificationPolicy},
+    outbox::OutboxRegistry,
+    personal_access_token::PersonalAccessTokenRegistry,
+    shared::{
+        event::{EventBus, EventSubscriber},
+        relay::RelayPolicy,
+    },
+    user::UserRegistry,
+    webhook::WebhookRegistry,
+};
+use chrono::Duration;
 use domain::audit::{Actor, Clock, clock::SystemClock};
 use infrastructure::{
     book::{BookQueryServiceImpl, BookRepositoryImpl},
-    config::AppConfig,
+    book_import::{BookImportQueryServiceImpl, BookImportRepositoryImpl},
+    config::{AppConfig, NotificationConfig, RelayConfig},
     database::ConnectionPool,
-    user::{UserDomainQueryServiceImpl, UserQueryServiceImpl, UserRepositoryImpl},
+    event::{EventListenerImpl, EventQueryServiceImpl},
+    group::{GroupQueryServiceImpl, GroupRepositoryImpl},
+    idempotency::IdempotencyRepositoryImpl,
+    job::{JobQueryServiceImpl, JobRepositoryImpl},
+    notification::{NotificationLogRepositoryImpl, NotificationQueryServiceImpl, SmtpNotifier},
+    outbox::{OutboxQueryServiceImpl, OutboxRepositoryImpl},
+    personal_access_token::{
+        PersonalAccessTokenQueryServiceImpl, PersonalAccessTokenRepositoryImpl,
+    },
+    user::{
+        RolePermissionRepositoryImpl, UserDomainQueryServiceImpl, UserQueryServiceImpl,
+        UserRepositoryImpl,
+    },
+    webhook::{
+        HttpWebhookSender, WebhookDeliveryRepositoryImpl, WebhookQueryServiceImpl,
+        WebhookRepositoryImpl,
+    },
 };
 
-use crate::{auth::OidcUserInfo, error::ApiError};
+use crate::{
+    auth::OidcUserInfo, error::ApiError, event_stream::EventStreamHub, job::JwksPrewarmJob,
+};
 
 #[derive(Clone)]
 pub struct AppRegistry {
     config: Arc<AppConfig>,
     book_registry: Arc<BookRegistry>,
+    book_import_registry: Arc<BookImportRegistry>,
     user_registry: Arc<UserRegistry>,
+    group_registry: Arc<GroupRegistry>,
+    personal_access_token_registry: Arc<PersonalAccessTokenRegistry>,
+    outbox_registry: Arc<OutboxRegistry>,
+    webhook_registry: Arc<WebhookRegistry>,
+    event_registry: Arc<EventRegistry>,
+    notification_registry: Arc<NotificationRegistry>,
+    job_registry: Arc<JobRegistry>,
+    idempotency_registry: Arc<IdempotencyRegistry>,
+    event_stream: Arc<EventStreamHub>,
+    event_listener: Arc<EventListenerImpl>,
 }
 
 impl AppRegistry {
@@ -30,22 +77,175 @@ impl AppRegistry {
         let book_repository = Arc::new(BookRepositoryImpl::new(db.clone()));
         let book_query_service = Arc::new(BookQueryServiceImpl::new(db.clone()));
 
+        let book_import_repository = Arc::new(BookImportRepositoryImpl::new(db.clone()));
+        let book_import_query_service = Arc::new(BookImportQueryServiceImpl::new(db.clone()));
+
         let user_repository = Arc::new(UserRepositoryImpl::new(db.clone()));
         let user_query_service = Arc::new(UserQueryServiceImpl::new(db.clone()));
         let user_domain_query_service = Arc::new(UserDomainQueryServiceImpl::new(db.clone()));
+        let role_permission_repository = Arc::new(RolePermissionRepositoryImpl::new(db.clone()));
+
+        let group_repository = Arc::new(GroupRepositoryImpl::new(db.clone()));
+        let group_query_service = Arc::new(GroupQueryServiceImpl::new(db.clone()));
+
+        let personal_access_token_repository =
+            Arc::new(PersonalAccessTokenRepositoryImpl::new(db.clone()));
+        let personal_access_token_query_service =
+            Arc::new(PersonalAccessTokenQueryServiceImpl::new(db.clone()));
+
+        let outbox_repository = Arc::new(OutboxRepositoryImpl::new(db.clone()));
+        let outbox_query_service = Arc::new(OutboxQueryServiceImpl::new(db.clone()));
+
+        let event_query_service = Arc::new(EventQueryServiceImpl::new(db.clone()));
+        let event_stream = Arc::new(EventStreamHub::default());
+        let event_listener = Arc::new(EventListenerImpl::new(db.clone()));
+
+        let webhook_repository = Arc::new(WebhookRepositoryImpl::new(db.clone()));
+        let webhook_query_service = Arc::new(WebhookQueryServiceImpl::new(db.clone()));
+        let webhook_delivery_repository = Arc::new(WebhookDeliveryRepositoryImpl::new(db.clone()));
+        let webhook_sender = Arc::new(HttpWebhookSender::new()?);
 
-        let book_registry = BookRegistry::new(book_repository, book_query_service, clock.clone());
+        let notification_query_service = Arc::new(NotificationQueryServiceImpl::new(db.clone()));
+        let notification_log_repository = Arc::new(NotificationLogRepositoryImpl::new(db.clone()));
+        let notifier = Arc::new(SmtpNotifier::new(&config.notification)?);
+
+        let idempotency_repository = Arc::new(IdempotencyRepositoryImpl::new(db.clone()));
+
+        let job_repository = Arc::new(JobRepositoryImpl::new(db.clone()));
+        let job_query_service = Arc::new(JobQueryServiceImpl::new(db.clone()));
+
+        let book_registry = BookRegistry::new(
+            book_repository.clone(),
+            book_query_service.clone(),
+            user_domain_query_service.clone(),
+            group_repository.clone(),
+            clock.clone(),
+        );
+        let book_import_registry = BookImportRegistry::new(
+            book_import_repository,
+            book_import_query_service,
+            book_repository,
+            user_domain_query_service.clone(),
+            config.book_import.batch_size,
+            Duration::seconds(config.book_import.lease_secs),
+            clock.clone(),
+        );
+        let group_registry = GroupRegistry::new(
+            group_repository,
+            group_query_service,
+            user_domain_query_service.clone(),
+            clock.clone(),
+        );
         let user_registry = UserRegistry::new(
             user_repository,
             user_query_service,
             user_domain_query_service,
+            role_permission_repository,
+            clock.clone(),
+        );
+        let personal_access_token_registry = PersonalAccessTokenRegistry::new(
+            personal_access_token_repository,
+            personal_access_token_query_service,
+            clock.clone(),
+        );
+        let webhook_registry = WebhookRegistry::new(
+            webhook_repository,
+            webhook_query_service,
+            webhook_delivery_repository,
+            webhook_sender,
+            relay_policy(&config.webhook),
+            Duration::days(config.job.retention_days),
+            clock.clone(),
+        );
+
+        let notification_registry = NotificationRegistry::new(
+            notification_query_service,
+            notification_log_repository,
+            notifier,
+            notification_policy(&config.notification),
+            clock.clone(),
+        );
+
+        let event_registry = EventRegistry::new(event_query_service, book_query_service);
+
+        let mut subscribers: Vec<Arc<dyn EventSubscriber>> =
+            vec![webhook_registry.event_subscriber()];
+        if config.notification.enabled {
+            subscribers.push(notification_registry.event_subscriber());
+        }
+
+        let event_bus = Arc::new(EventBus::new(subscribers));
+        let outbox_registry = OutboxRegistry::new(
+            outbox_repository,
+            outbox_query_service,
+            vec![event_bus],
+            relay_policy(&config.outbox),
+            Duration::days(config.job.retention_days),
+            clock.clone(),
+        );
+
+        let idempotency_registry = IdempotencyRegistry::new(
+            idempotency_repository,
+            Duration::hours(config.idempotency.ttl_hours),
+            Duration::seconds(config.idempotency.lock_timeout_secs),
+            clock.clone(),
+        );
+
+        let mut jobs = vec![
+            ScheduledJob::new(
+                Arc::new(JwksPrewarmJob::new(
+                    config
+                        .oidc
+                        .issuers
+                        .iter()
+                        .map(|issuer| issuer.authority.clone())
+                        .collect(),
+                )),
+                &config.job.jwks_prewarm_schedule,
+            )?,
+            ScheduledJob::new(
+                outbox_registry.purge_outbox(),
+                &config.job.outbox_cleanup_schedule,
+            )?,
+            ScheduledJob::new(
+                webhook_registry.purge_webhook_deliveries(),
+                &config.job.webhook_delivery_cleanup_schedule,
+            )?,
+            ScheduledJob::new(
+                idempotency_registry.purge_idempotency_keys(),
+                &config.job.idempotency_key_cleanup_schedule,
+            )?,
+        ];
+        if config.notification.enabled {
+            jobs.push(ScheduledJob::new(
+                notification_registry.send_due_reminders(),
+                &config.job.due_reminders_schedule,
+            )?);
+        }
+
+        let job_registry = JobRegistry::new(
+            job_repository,
+            job_query_service,
+            jobs,
+            Duration::seconds(config.job.lease_secs),
             clock.clone(),
         );
 
         Ok(AppRegistry {
             config,
             book_registry: Arc::new(book_registry),
+            book_import_registry: Arc::new(book_import_registry),
             user_registry: Arc::new(user_registry),
+            group_registry: Arc::new(group_registry),
+            personal_access_token_registry: Arc::new(personal_access_token_registry),
+            outbox_registry: Arc::new(outbox_registry),
+            webhook_registry: Arc::new(webhook_registry),
+            event_registry: Arc::new(event_registry),
+            notification_registry: Arc::new(notification_registry),
+            job_registry: Arc::new(job_registry),
+            idempotency_registry: Arc::new(idempotency_registry),
+            event_stream,
+            event_listener,
         })
     }
 
@@ -87,7 +287,68 @@ impl AppRegistry {
         Arc::clone(&self.book_registry)
     }
 
+    pub fn book_import_registry(&self) -> Arc<BookImportRegistry> {
+        Arc::clone(&self.book_import_registry)
+    }
+
     pub fn user_registry(&self) -> Arc<UserRegistry> {
         Arc::clone(&self.user_registry)
     }
+
+    pub fn group_registry(&self) -> Arc<GroupRegistry> {
+        Arc::clone(&self.group_registry)
+    }
+
+    pub fn personal_access_token_registry(&self) -> Arc<PersonalAccessTokenRegistry> {
+        Arc::clone(&self.personal_access_token_registry)
+    }
+
+    pub fn outbox_registry(&self) -> Arc<OutboxRegistry> {
+        Arc::clone(&self.outbox_registry)
+    }
+
+    pub fn webhook_registry(&self) -> Arc<WebhookRegistry> {
+        Arc::clone(&self.webhook_registry)
+    }
+
+    pub fn event_registry(&self) -> Arc<EventRegistry> {
+        Arc::clone(&self.event_registry)
+    }
+
+    pub fn notification_registry(&self) -> Arc<NotificationRegistry> {
+        Arc::clone(&self.notification_registry)
+    }
+
+    pub fn job_registry(&self) -> Arc<JobRegistry> {
+        Arc::clone(&self.job_registry)
+    }
+
+    pub fn idempotency_registry(&self) -> Arc<IdempotencyRegistry> {
+        Arc::clone(&self.idempotency_registry)
+    }
+
+    pub fn event_stream(&self) -> Arc<EventStreamHub> {
+        Arc::clone(&self.event_stream)
+    }
+
+    pub fn event_listener(&self) -> Arc<EventListenerImpl> {
+        Arc::clone(&self.event_listener)
+    }
+}
+
+fn relay_policy(config: &RelayConfig) -> RelayPolicy {
+    RelayPolicy::new(
+        config.batch_size,
+        config.max_attempts,
+        Duration::seconds(config.base_backoff_secs),
+        Duration::seconds(config.max_backoff_secs),
+        Duration::seconds(config.lease_secs),
+    )
+}
+
+fn notification_policy(config: &NotificationConfig) -> NotificationPolicy {
+    NotificationPolicy::new(
+        Duration::days(config.loan_period_days),
+        Duration::hours(config.due_soon_hours),
+    )
 }
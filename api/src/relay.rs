@@ -0,0 +1,124 @@
+use std::{future::Future, time::Duration};
+
+use application::shared::error::ApplicationError;
+use infrastructure::config::RelayConfig;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+
+use crate::registry::AppRegistry;
+
+const LISTEN_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+pub fn spawn_outbox_relay(registry: AppRegistry) -> JoinHandle<()> {
+    let relay = registry.outbox_registry().relay_outbox();
+    spawn_polling_worker("Outbox relay", &registry.config().outbox, move || {
+        let relay = relay.clone();
+        async move { relay.execute().await }
+    })
+}
+
+pub fn spawn_webhook_delivery(registry: AppRegistry) -> JoinHandle<()> {
+    let deliver = registry.webhook_registry().deliver_webhooks();
+    spawn_polling_worker("Webhook delivery", &registry.config().webhook, move || {
+        let deliver = deliver.clone();
+        async move { deliver.execute().await }
+    })
+}
+
+pub fn spawn_book_import_worker(registry: AppRegistry) -> JoinHandle<()> {
+    let process = registry.book_import_registry().process_book_imports();
+    spawn_polling_worker("Book import", &registry.config().book_import, move || {
+        let process = process.clone();
+        async move { process.execute().await }
+    })
+}
+
+/// Every replica polls; claiming through the jobs table keeps each run on a single replica.
+pub fn spawn_job_scheduler(registry: AppRegistry) -> JoinHandle<()> {
+    let sync_jobs = registry.job_registry().sync_jobs();
+    let run_due_jobs = registry.job_registry().run_due_jobs();
+    let poll_interval = Duration::from_millis(registry.config().job.poll_interval_ms);
+
+    tokio::spawn(async move {
+        if let Err(err) = sync_jobs.execute().await {
+            tracing::error!(error = ?err, "Failed to register jobs");
+        }
+
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            if let Err(err) = run_due_jobs.execute().await {
+                tracing::error!(error = ?err, worker = "Job scheduler", "Background worker failed");
+            }
+        }
+    })
+}
+
+/// Every replica listens, so SSE clients see events committed on any replica, whereas the
+/// outbox relay hands each event to a single one.
+pub fn spawn_event_stream_feed(registry: AppRegistry) -> JoinHandle<()> {
+    let listener = registry.event_listener();
+    let hub = registry.event_stream();
+
+    tokio::spawn(async move {
+        loop {
+            let mut subscription = match listener.listen().await {
+                Ok(subscription) => subscription,
+                Err(err) => {
+                    tracing::error!(error = ?err, worker = "Event stream feed", "Background worker failed");
+                    tokio::time::sleep(LISTEN_RETRY_DELAY).await;
+                    continue;
+                }
+            };
+
+            loop {
+                match subscription.recv().await {
+                    Ok(Some(event)) => hub.publish(event),
+                    // Events committed while disconnected were missed, so make clients catch up
+                    Ok(None) => {
+                        tracing::warn!("Event listener lost its connection; closing event streams");
+                        hub.disconnect_all();
+                    }
+                    Err(err) => {
+                        tracing::error!(error = ?err, worker = "Event stream feed", "Background worker failed");
+                        hub.disconnect_all();
+                        tokio::time::sleep(LISTEN_RETRY_DELAY).await;
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn spawn_polling_worker<F, Fut>(name: &'static str, config: &RelayConfig, run: F) -> JoinHandle<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<usize, ApplicationError>> + Send,
+{
+    let poll_interval = Duration::from_millis(config.poll_interval_ms);
+    let batch_size = config.batch_size as usize;
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            // Keep draining while full batches come back, then wait for the next tick
+            loop {
+                match run().await {
+                    Ok(processed) if processed >= batch_size => continue,
+                    Ok(_) => break,
+                    Err(err) => {
+                        tracing::error!(error = ?err, worker = name, "Background worker failed");
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
@@ -1,6 +1,9 @@
 use crate::{
     registry::AppRegistry,
-    router::{book::book_router, user::user_router},
+    router::{
+        admin::admin_router, book::book_router, event::event_router, group::group_router,
+        opds::opds_router, user::user_router,
+    },
 };
 use aide::axum::ApiRouter;
 use axum::Router;
@@ -13,7 +16,11 @@ use {
     tracing::info,
 };
 
+pub mod admin;
 pub mod book;
+pub mod event;
+pub mod group;
+pub mod opds;
 pub mod user;
 
 #[cfg(debug_assertions)]
@@ -35,6 +42,26 @@ fn build_openapi_schema(oidc_config: &OidcConfig) -> OpenApi {
             description: Some("User management endpoints".to_string()),
             ..Tag::default()
         },
+        Tag {
+            name: "Groups".to_string(),
+            description: Some("Group and team library endpoints".to_string()),
+            ..Tag::default()
+        },
+        Tag {
+            name: "Events".to_string(),
+            description: Some("Real-time event stream endpoints".to_string()),
+            ..Tag::default()
+        },
+        Tag {
+            name: "OPDS".to_string(),
+            description: Some("OPDS catalog feeds for e-reader apps".to_string()),
+            ..Tag::default()
+        },
+        Tag {
+            name: "Admin".to_string(),
+            description: Some("Administration endpoints".to_string()),
+            ..Tag::default()
+        },
     ];
 
     let mut components = Components::default();
@@ -43,7 +70,7 @@ fn build_openapi_schema(oidc_config: &OidcConfig) -> OpenApi {
         ReferenceOr::Item(SecurityScheme::OpenIdConnect {
             open_id_connect_url: format!(
                 "{}/.well-known/openid-configuration",
-                oidc_config.authority.trim_end_matches('/')
+                oidc_config.primary().authority.trim_end_matches('/')
             ),
             description: Some("OpenID Connect discovery endpoint".to_string()),
             extensions: Default::default(),
@@ -119,5 +146,13 @@ pub fn export_openapi_schema(
 }
 
 fn build_api_router() -> ApiRouter<AppRegistry> {
-    ApiRouter::new().nest("/api", book_router().merge(user_router()))
+    ApiRouter::new().nest(
+        "/api",
+        book_router()
+            .merge(user_router())
+            .merge(group_router())
+            .merge(event_router())
+            .merge(opds_router())
+            .merge(admin_router()),
+    )
 }
@@ -0,0 +1,304 @@
+use application::{
+    job::dto::*,
+    outbox::dto::*,
+    personal_access_token::dto::*,
+    shared::EntityCreationDTO,
+    user::dto::{RoleIdentity, RolePermissionsDTO, UpdateRolePermissionsRequestDTO},
+    webhook::dto::*,
+};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::NoContent,
+};
+use reqwest::StatusCode;
+
+use crate::{auth::OidcUserInfo, error::ApiError, registry::AppRegistry};
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_outbox_messages(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<OutboxListQueryDTO>,
+) -> Result<Json<OutboxMessageListDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .outbox_registry()
+        .get_outbox_messages()
+        .execute(&actor, &query)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_outbox_stats(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<OutboxStatsDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .outbox_registry()
+        .get_outbox_stats()
+        .execute(&actor)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn requeue_outbox_message(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<OutboxMessageIdentity>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .outbox_registry()
+        .requeue_outbox_message()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_webhooks(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<Vec<WebhookDTO>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .webhook_registry()
+        .get_webhooks()
+        .execute(&actor)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info, request),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn create_webhook(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Json(request): Json<CreateWebhookRequestDTO>,
+) -> Result<(StatusCode, Json<EntityCreationDTO>), ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .webhook_registry()
+        .create_webhook()
+        .execute(&actor, &request)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn delete_webhook(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<WebhookIdentity>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .webhook_registry()
+        .delete_webhook()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_webhook_deliveries(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<WebhookIdentity>,
+    Query(query): Query<WebhookDeliveryQueryDTO>,
+) -> Result<Json<WebhookDeliveryListDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .webhook_registry()
+        .get_webhook_deliveries()
+        .execute(&actor, identity, &query)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn send_test_webhook(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<WebhookIdentity>,
+) -> Result<Json<WebhookTestResultDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .webhook_registry()
+        .send_test_webhook()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_jobs(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<Vec<JobDTO>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry.job_registry().get_jobs().execute(&actor).await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn trigger_job(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<JobIdentity>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .job_registry()
+        .trigger_job()
+        .execute(&actor, &identity)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_user_tokens(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<TokenOwnerIdentity>,
+) -> Result<Json<Vec<PersonalAccessTokenDTO>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .personal_access_token_registry()
+        .get_personal_access_tokens()
+        .execute(&actor, identity.user_id)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn revoke_token(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<PersonalAccessTokenIdentity>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .personal_access_token_registry()
+        .revoke_personal_access_token()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_role_permissions(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<Vec<RolePermissionsDTO>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .user_registry()
+        .get_role_permissions()
+        .execute(&actor)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info, request),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn update_role_permissions(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<RoleIdentity>,
+    Json(request): Json<UpdateRolePermissionsRequestDTO>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .user_registry()
+        .update_role_permissions()
+        .execute(&actor, identity, &request)
+        .await?;
+
+    Ok(NoContent)
+}
@@ -0,0 +1,77 @@
+use aide::axum::{
+    ApiRouter,
+    routing::{delete_with, get_with, post_with, put_with},
+};
+use axum::{Json, response::NoContent};
+
+use application::shared::EntityCreationDTO;
+
+use crate::{registry::AppRegistry, router::admin::handlers::*};
+
+pub fn admin_router() -> ApiRouter<AppRegistry> {
+    ApiRouter::new().nest(
+        "/admin",
+        ApiRouter::new()
+            .api_route(
+                "/outbox",
+                get_with(get_outbox_messages, |op| op.tag("Admin")),
+            )
+            .api_route(
+                "/outbox/stats",
+                get_with(get_outbox_stats, |op| op.tag("Admin")),
+            )
+            .api_route(
+                "/outbox/{message_id}/retry",
+                post_with(requeue_outbox_message, |op| {
+                    op.tag("Admin").response::<204, NoContent>()
+                }),
+            )
+            .api_route(
+                "/webhooks",
+                get_with(get_webhooks, |op| op.tag("Admin")).post_with(create_webhook, |op| {
+                    op.tag("Admin").response::<201, Json<EntityCreationDTO>>()
+                }),
+            )
+            .api_route(
+                "/webhooks/{webhook_id}",
+                delete_with(delete_webhook, |op| {
+                    op.tag("Admin").response::<204, NoContent>()
+                }),
+            )
+            .api_route(
+                "/webhooks/{webhook_id}/deliveries",
+                get_with(get_webhook_deliveries, |op| op.tag("Admin")),
+            )
+            .api_route(
+                "/webhooks/{webhook_id}/test",
+                post_with(send_test_webhook, |op| op.tag("Admin")),
+            )
+            .api_route(
+                "/roles",
+                get_with(get_role_permissions, |op| op.tag("Admin")),
+            )
+            .api_route(
+                "/roles/{role}",
+                put_with(update_role_permissions, |op| {
+                    op.tag("Admin").response::<204, NoContent>()
+                }),
+            )
+            .api_route(
+                "/users/{user_id}/tokens",
+                get_with(get_user_tokens, |op| op.tag("Admin")),
+            )
+            .api_route(
+                "/tokens/{token_id}",
+                delete_with(revoke_token, |op| {
+                    op.tag("Admin").response::<204, NoContent>()
+                }),
+            )
+            .api_route("/jobs", get_with(get_jobs, |op| op.tag("Admin")))
+            .api_route(
+                "/jobs/{job_name}/run",
+                post_with(trigger_job, |op| {
+                    op.tag("Admin").response::<204, NoContent>()
+                }),
+            ),
+    )
+}
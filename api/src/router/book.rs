@@ -1,4 +1,5 @@
 pub mod handlers;
+pub mod response;
 pub mod router;
 
 pub use router::book_router;
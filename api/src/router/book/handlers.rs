@@ -1,4 +1,8 @@
-use application::{book::dto::*, shared::EntityCreationDTO};
+use application::{
+    book::dto::*,
+    book_import::dto::{BookImportDTO, BookImportIdentity, BookImportQueryDTO},
+    shared::EntityCreationDTO,
+};
 use axum::{
     Json,
     extract::{Path, Query, State},
@@ -7,7 +11,14 @@ use axum::{
 
 use reqwest::StatusCode;
 
-use crate::{auth::OidcUserInfo, error::ApiError, registry::AppRegistry};
+use crate::{
+    auth::OidcUserInfo,
+    error::ApiError,
+    precondition::{ETagged, IfMatch},
+    registry::AppRegistry,
+    router::book::response::BookExportFile,
+    tenant::RequestTenant,
+};
 
 #[tracing::instrument(
     skip(registry, user_info),
@@ -16,18 +27,19 @@ use crate::{auth::OidcUserInfo, error::ApiError, registry::AppRegistry};
 )]
 pub async fn get_book_details(
     user_info: Option<OidcUserInfo>,
+    RequestTenant(tenant): RequestTenant,
     State(registry): State<AppRegistry>,
     Path(identity): Path<BookIdentity>,
-) -> Result<Json<BookDetailsDTO>, ApiError> {
+) -> Result<ETagged<BookDetailsDTO>, ApiError> {
     let actor = registry.prepare_optional_actor(user_info.as_ref()).await?;
 
     let response = registry
         .book_registry()
         .get_book_details()
-        .execute(actor.as_ref(), identity)
+        .execute(&tenant, actor.as_ref(), identity)
         .await?;
 
-    Ok(Json(response))
+    Ok(ETagged::new(response.audit.version, response))
 }
 
 #[tracing::instrument(
@@ -37,6 +49,7 @@ pub async fn get_book_details(
 )]
 pub async fn get_book_list(
     user_info: Option<OidcUserInfo>,
+    RequestTenant(tenant): RequestTenant,
     State(registry): State<AppRegistry>,
     Query(query): Query<BookListQueryDTO>,
 ) -> Result<Json<BookListResponseDTO>, ApiError> {
@@ -45,12 +58,29 @@ pub async fn get_book_list(
     let response = registry
         .book_registry()
         .get_book_list()
-        .execute(actor.as_ref(), &query)
+        .execute(&tenant, actor.as_ref(), &query)
         .await?;
 
     Ok(Json(response))
 }
 
+#[tracing::instrument(skip(registry), err)]
+pub async fn export_books(
+    RequestTenant(tenant): RequestTenant,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<BookExportQueryDTO>,
+) -> Result<BookExportFile, ApiError> {
+    let stream = registry
+        .book_registry()
+        .export_books()
+        .execute(&tenant, &query)?;
+
+    Ok(BookExportFile {
+        format: query.format,
+        stream,
+    })
+}
+
 #[tracing::instrument(
     skip(registry, user_info),
     fields(user_id = %user_info.id),
@@ -81,6 +111,7 @@ pub async fn update_book(
     user_info: OidcUserInfo,
     State(registry): State<AppRegistry>,
     Path(identity): Path<BookIdentity>,
+    IfMatch(expected_version): IfMatch,
     Json(request): Json<UpdateBookRequestDTO>,
 ) -> Result<NoContent, ApiError> {
     let actor = registry.prepare_actor(&user_info).await?;
@@ -88,7 +119,53 @@ pub async fn update_book(
     registry
         .book_registry()
         .update_book()
-        .execute(&actor, identity, &request)
+        .execute(&actor, identity, expected_version, &request)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn update_book_co_owners(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<BookIdentity>,
+    IfMatch(expected_version): IfMatch,
+    Json(request): Json<UpdateBookCoOwnersRequestDTO>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .book_registry()
+        .update_book_co_owners()
+        .execute(&actor, identity, expected_version, &request)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn change_book_group(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<BookIdentity>,
+    IfMatch(expected_version): IfMatch,
+    Json(request): Json<ChangeBookGroupRequestDTO>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .book_registry()
+        .change_book_group()
+        .execute(&actor, identity, expected_version, &request)
         .await?;
 
     Ok(NoContent)
@@ -103,13 +180,14 @@ pub async fn delete_book(
     user_info: OidcUserInfo,
     State(registry): State<AppRegistry>,
     Path(identity): Path<BookIdentity>,
+    IfMatch(expected_version): IfMatch,
 ) -> Result<NoContent, ApiError> {
     let actor = registry.prepare_actor(&user_info).await?;
 
     registry
         .book_registry()
         .delete_book()
-        .execute(&actor, identity)
+        .execute(&actor, identity, expected_version)
         .await?;
 
     Ok(NoContent)
@@ -138,7 +216,7 @@ pub async fn get_checkout_history(
 }
 
 #[tracing::instrument(
-    skip(registry, user_info),
+    skip(registry, user_info, request),
     fields(user_id = %user_info.id),
     err
 )]
@@ -146,13 +224,16 @@ pub async fn checkout_book(
     user_info: OidcUserInfo,
     State(registry): State<AppRegistry>,
     Path(identity): Path<BookIdentity>,
+    IfMatch(expected_version): IfMatch,
+    request: Option<Json<CheckoutBookRequestDTO>>,
 ) -> Result<NoContent, ApiError> {
     let actor = registry.prepare_actor(&user_info).await?;
+    let request = request.map(|Json(request)| request).unwrap_or_default();
 
     registry
         .book_registry()
         .checkout_book()
-        .execute(&actor, identity)
+        .execute(&actor, identity, &request, expected_version)
         .await?;
 
     Ok(NoContent)
@@ -167,14 +248,62 @@ pub async fn return_book(
     user_info: OidcUserInfo,
     State(registry): State<AppRegistry>,
     Path(identity): Path<BookIdentity>,
+    IfMatch(expected_version): IfMatch,
 ) -> Result<NoContent, ApiError> {
     let actor = registry.prepare_actor(&user_info).await?;
 
     registry
         .book_registry()
         .return_book()
-        .execute(&actor, identity)
+        .execute(&actor, identity, expected_version)
         .await?;
 
     Ok(NoContent)
 }
+
+#[tracing::instrument(
+    skip(registry, user_info, body),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn import_books(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<BookImportQueryDTO>,
+    body: String,
+) -> Result<(StatusCode, Json<BookImportDTO>), ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .book_import_registry()
+        .start_book_import()
+        .execute(&actor, &query, &body)
+        .await?;
+
+    let status = match response.id {
+        Some(_) => StatusCode::ACCEPTED,
+        None => StatusCode::OK,
+    };
+    Ok((status, Json(response)))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_book_import(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<BookImportIdentity>,
+) -> Result<Json<BookImportDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .book_import_registry()
+        .get_book_import()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(Json(response))
+}
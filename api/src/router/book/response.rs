@@ -0,0 +1,67 @@
+use aide::{
+    OperationOutput,
+    generate::GenContext,
+    openapi::{MediaType, Operation, Response, StatusCode},
+};
+use application::book::{dto::BookExportFormatDTO, query::BookExportStream};
+use axum::{
+    body::Body,
+    http::header,
+    response::{IntoResponse, Response as AxumResponse},
+};
+
+/// Streamed catalog export, served as a file download.
+pub struct BookExportFile {
+    pub format: BookExportFormatDTO,
+    pub stream: BookExportStream,
+}
+
+impl IntoResponse for BookExportFile {
+    fn into_response(self) -> AxumResponse {
+        let disposition = format!(
+            "attachment; filename=\"books.{}\"",
+            self.format.file_extension()
+        );
+        (
+            [
+                (header::CONTENT_TYPE, self.format.content_type().to_string()),
+                (header::CONTENT_DISPOSITION, disposition),
+            ],
+            Body::from_stream(self.stream),
+        )
+            .into_response()
+    }
+}
+
+impl OperationOutput for BookExportFile {
+    type Inner = ();
+
+    fn operation_response(_ctx: &mut GenContext, _operation: &mut Operation) -> Option<Response> {
+        let mut response = Response {
+            description: "Catalog export".into(),
+            ..Default::default()
+        };
+        for format in [
+            BookExportFormatDTO::Csv,
+            BookExportFormatDTO::Json,
+            BookExportFormatDTO::Bibtex,
+            BookExportFormatDTO::Ris,
+            BookExportFormatDTO::Marc21,
+            BookExportFormatDTO::Marcxml,
+        ] {
+            response
+                .content
+                .insert(format.content_type().into(), MediaType::default());
+        }
+        Some(response)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<StatusCode>, Response)> {
+        Self::operation_response(ctx, operation)
+            .map(|response| vec![(Some(StatusCode::Code(200)), response)])
+            .unwrap_or_default()
+    }
+}
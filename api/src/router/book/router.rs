@@ -1,10 +1,10 @@
 use aide::axum::{
     ApiRouter,
-    routing::{get_with, post_with},
+    routing::{get_with, post_with, put_with},
 };
 use axum::{Json, response::NoContent};
 
-use application::shared::EntityCreationDTO;
+use application::{book_import::dto::BookImportDTO, shared::EntityCreationDTO};
 
 use crate::{registry::AppRegistry, router::book::handlers::*};
 
@@ -18,6 +18,19 @@ pub fn book_router() -> ApiRouter<AppRegistry> {
                     op.tag("Books").response::<201, Json<EntityCreationDTO>>()
                 }),
             )
+            .api_route("/export", get_with(export_books, |op| op.tag("Books")))
+            .api_route(
+                "/import",
+                post_with(import_books, |op| {
+                    op.tag("Books")
+                        .response::<200, Json<BookImportDTO>>()
+                        .response::<202, Json<BookImportDTO>>()
+                }),
+            )
+            .api_route(
+                "/import/{import_id}",
+                get_with(get_book_import, |op| op.tag("Books")),
+            )
             .api_route(
                 "/{book_id}",
                 get_with(get_book_details, |op| op.tag("Books"))
@@ -28,6 +41,18 @@ pub fn book_router() -> ApiRouter<AppRegistry> {
                         op.tag("Books").response::<204, NoContent>()
                     }),
             )
+            .api_route(
+                "/{book_id}/co-owners",
+                put_with(update_book_co_owners, |op| {
+                    op.tag("Books").response::<204, NoContent>()
+                }),
+            )
+            .api_route(
+                "/{book_id}/group",
+                put_with(change_book_group, |op| {
+                    op.tag("Books").response::<204, NoContent>()
+                }),
+            )
             .api_route(
                 "/{book_id}/checkouts",
                 get_with(get_checkout_history, |op| op.tag("Books"))
@@ -0,0 +1,5 @@
+pub mod handlers;
+pub mod response;
+pub mod router;
+
+pub use router::event_router;
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use application::event::dto::EventStreamQueryDTO;
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::{
+        Sse,
+        sse::{Event, KeepAlive},
+    },
+};
+use domain::event::DomainEvent;
+use futures_core::Stream;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::{
+    auth::OidcUserInfo, error::ApiError, registry::AppRegistry,
+    router::event::response::EventStream,
+};
+
+const LAST_EVENT_ID: &str = "last-event-id";
+
+#[tracing::instrument(
+    skip(registry, user_info, headers),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn stream_events(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    headers: HeaderMap,
+    Query(query): Query<EventStreamQueryDTO>,
+) -> Result<EventStream<impl Stream<Item = Result<Event, axum::Error>>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let last_event_id = headers
+        .get(LAST_EVENT_ID)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .and_then(|v| Uuid::parse_str(v).ok())
+                .ok_or_else(|| ApiError::BadRequest("Invalid Last-Event-ID header".into()))
+        })
+        .transpose()?;
+
+    // Subscribe before replaying so nothing published in between is lost
+    let mut receiver = registry.event_stream().subscribe();
+
+    let replayed = match last_event_id {
+        Some(id) => {
+            registry
+                .event_registry()
+                .replay_events()
+                .execute(&actor, id, &query)
+                .await?
+        }
+        None => vec![],
+    };
+
+    let filter_visible_events = registry.event_registry().filter_visible_events();
+
+    let stream = async_stream::stream! {
+        let mut replayed_ids: HashSet<Uuid> = replayed.iter().map(|e| e.id()).collect();
+
+        for event in &replayed {
+            yield to_sse_event(event);
+        }
+
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if replayed_ids.remove(&event.id())
+                        || event.tenant_id() != actor.tenant()
+                        || !query.matches(&event)
+                    {
+                        continue;
+                    }
+                    // Visibility is checked per event, so a book that became hidden stops streaming
+                    match filter_visible_events.execute(&actor, vec![event]).await {
+                        Ok(visible) => {
+                            for event in visible {
+                                yield to_sse_event(&event);
+                            }
+                        }
+                        Err(e) => tracing::warn!(error = %e, "Failed to check event visibility"),
+                    }
+                }
+                // Ending the stream makes the client reconnect and catch up via Last-Event-ID
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Event stream subscriber lagged behind");
+                    break;
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    };
+
+    Ok(EventStream(
+        Sse::new(stream).keep_alive(KeepAlive::default()),
+    ))
+}
+
+fn to_sse_event(event: &DomainEvent) -> Result<Event, axum::Error> {
+    Event::default()
+        .id(event.id().to_string())
+        .event(event.event_type())
+        .json_data(event)
+}
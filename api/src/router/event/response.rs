@@ -0,0 +1,43 @@
+use aide::{
+    OperationOutput,
+    generate::GenContext,
+    openapi::{MediaType, Operation, Response, StatusCode},
+};
+use axum::response::{IntoResponse, Sse, sse::Event};
+use futures_core::Stream;
+
+/// `Sse` wrapper so that the stream shows up in the OpenAPI document.
+pub struct EventStream<S>(pub Sse<S>);
+
+impl<S> IntoResponse for EventStream<S>
+where
+    S: Stream<Item = Result<Event, axum::Error>> + Send + 'static,
+{
+    fn into_response(self) -> axum::response::Response {
+        self.0.into_response()
+    }
+}
+
+impl<S> OperationOutput for EventStream<S> {
+    type Inner = ();
+
+    fn operation_response(_ctx: &mut GenContext, _operation: &mut Operation) -> Option<Response> {
+        let mut response = Response {
+            description: "Server-sent events stream".into(),
+            ..Default::default()
+        };
+        response
+            .content
+            .insert("text/event-stream".into(), MediaType::default());
+        Some(response)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<StatusCode>, Response)> {
+        Self::operation_response(ctx, operation)
+            .map(|response| vec![(Some(StatusCode::Code(200)), response)])
+            .unwrap_or_default()
+    }
+}
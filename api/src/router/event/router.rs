@@ -0,0 +1,10 @@
+use aide::axum::{ApiRouter, routing::get_with};
+
+use crate::{registry::AppRegistry, router::event::handlers::*};
+
+pub fn event_router() -> ApiRouter<AppRegistry> {
+    ApiRouter::new().nest(
+        "/events",
+        ApiRouter::new().api_route("/stream", get_with(stream_events, |op| op.tag("Events"))),
+    )
+}
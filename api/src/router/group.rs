@@ -0,0 +1,4 @@
+pub mod handlers;
+pub mod router;
+
+pub use router::group_router;
@@ -0,0 +1,165 @@
+use application::{group::dto::*, shared::EntityCreationDTO};
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::NoContent,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    auth::OidcUserInfo,
+    error::ApiError,
+    precondition::{ETagged, IfMatch},
+    registry::AppRegistry,
+};
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_group_list(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<Vec<GroupSummaryDTO>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .group_registry()
+        .get_group_list()
+        .execute(&actor)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_group_details(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<GroupIdentity>,
+) -> Result<ETagged<GroupDetailsDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .group_registry()
+        .get_group_details()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(ETagged::new(response.audit.version, response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn create_group(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Json(request): Json<CreateGroupRequestDTO>,
+) -> Result<(StatusCode, Json<EntityCreationDTO>), ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .group_registry()
+        .create_group()
+        .execute(&actor, &request)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn update_group(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<GroupIdentity>,
+    IfMatch(expected_version): IfMatch,
+    Json(request): Json<UpdateGroupRequestDTO>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .group_registry()
+        .update_group()
+        .execute(&actor, identity, expected_version, &request)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn delete_group(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<GroupIdentity>,
+    IfMatch(expected_version): IfMatch,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .group_registry()
+        .delete_group()
+        .execute(&actor, identity, expected_version)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info, request),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn update_group_member(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<GroupMemberIdentity>,
+    request: Option<Json<UpdateGroupMemberRequestDTO>>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+
+    registry
+        .group_registry()
+        .update_group_member()
+        .execute(&actor, identity, &request)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn remove_group_member(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<GroupMemberIdentity>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .group_registry()
+        .remove_group_member()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(NoContent)
+}
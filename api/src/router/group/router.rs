@@ -0,0 +1,41 @@
+use aide::axum::{
+    ApiRouter,
+    routing::{get_with, put_with},
+};
+use axum::{Json, response::NoContent};
+
+use application::shared::EntityCreationDTO;
+
+use crate::{registry::AppRegistry, router::group::handlers::*};
+
+pub fn group_router() -> ApiRouter<AppRegistry> {
+    ApiRouter::new().nest(
+        "/groups",
+        ApiRouter::new()
+            .api_route(
+                "/",
+                get_with(get_group_list, |op| op.tag("Groups")).post_with(create_group, |op| {
+                    op.tag("Groups").response::<201, Json<EntityCreationDTO>>()
+                }),
+            )
+            .api_route(
+                "/{group_id}",
+                get_with(get_group_details, |op| op.tag("Groups"))
+                    .put_with(update_group, |op| {
+                        op.tag("Groups").response::<204, NoContent>()
+                    })
+                    .delete_with(delete_group, |op| {
+                        op.tag("Groups").response::<204, NoContent>()
+                    }),
+            )
+            .api_route(
+                "/{group_id}/members/{user_id}",
+                put_with(update_group_member, |op| {
+                    op.tag("Groups").response::<204, NoContent>()
+                })
+                .delete_with(remove_group_member, |op| {
+                    op.tag("Groups").response::<204, NoContent>()
+                }),
+            ),
+    )
+}
@@ -0,0 +1,6 @@
+pub mod feed;
+pub mod handlers;
+pub mod response;
+pub mod router;
+
+pub use router::opds_router;
@@ -0,0 +1,528 @@
+use application::book::dto::{BookListItemDTO, BookListQueryDTO};
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+use crate::router::opds::response::OpdsDocument;
+
+const ATOM_NAVIGATION: &str = "application/atom+xml;profile=opds-catalog;kind=navigation";
+const ATOM_ACQUISITION: &str = "application/atom+xml;profile=opds-catalog;kind=acquisition";
+const OPDS_JSON: &str = "application/opds+json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpdsFeedKind {
+    Navigation,
+    Acquisition,
+}
+
+/// A catalog feed independent of its serialization; hrefs are relative to the catalog root.
+pub struct OpdsFeed {
+    pub id: &'static str,
+    pub title: String,
+    pub kind: OpdsFeedKind,
+    pub updated: DateTime<Utc>,
+    pub link: OpdsLink,
+    pub navigation: Vec<OpdsNavigationEntry>,
+    pub publications: Vec<BookListItemDTO>,
+    pub pagination: Option<OpdsPagination>,
+}
+
+pub struct OpdsNavigationEntry {
+    pub title: String,
+    pub summary: Option<String>,
+    pub link: OpdsLink,
+    pub kind: OpdsFeedKind,
+}
+
+pub struct OpdsPagination {
+    pub page: u64,
+    pub page_size: u64,
+    pub total_count: u64,
+}
+
+/// Path below the catalog root plus query parameters, so that paging can rebuild the href.
+#[derive(Clone)]
+pub struct OpdsLink {
+    pub path: &'static str,
+    pub params: Vec<(&'static str, String)>,
+}
+
+impl OpdsLink {
+    pub fn new(path: &'static str) -> Self {
+        Self {
+            path,
+            params: vec![],
+        }
+    }
+
+    pub fn param(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.params.push((name, value.to_string()));
+        self
+    }
+
+    /// Carries the filters of a book list over to the links of its feed.
+    pub fn with_list_query(mut self, query: &BookListQueryDTO) -> Self {
+        let filters = [
+            ("owner_id", query.filter.owner_id.map(|id| id.to_string())),
+            (
+                "checked_out",
+                query.filter.checked_out.map(|v| v.to_string()),
+            ),
+            (
+                "checked_out_to_id",
+                query.filter.checked_out_to_id.map(|id| id.to_string()),
+            ),
+            ("search", query.filter.search.clone()),
+            ("title", query.filter.title.clone()),
+            ("author_name", query.filter.author_name.clone()),
+        ];
+        self.params.extend(
+            filters
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value))),
+        );
+        self
+    }
+
+    pub fn href(&self, root: &str) -> String {
+        let path = match self.path {
+            "" => root.to_string(),
+            path => format!("{}/{}", root, path),
+        };
+        match self.params.is_empty() {
+            true => path,
+            false => {
+                let query: Vec<String> = self
+                    .params
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, encode_query_value(value)))
+                    .collect();
+                format!("{}?{}", path, query.join("&"))
+            }
+        }
+    }
+
+    fn page(&self, page: u64, page_size: u64) -> Self {
+        let mut link = self.clone();
+        link.params
+            .retain(|(name, _)| *name != "page" && *name != "page_size");
+        link.param("page", page).param("page_size", page_size)
+    }
+}
+
+impl OpdsPagination {
+    fn last_page(&self) -> u64 {
+        self.total_count.div_ceil(self.page_size).max(1)
+    }
+}
+
+/// A serialization of `OpdsFeed`, mounted at its own catalog root.
+pub trait OpdsFormat: Send + Sync + 'static {
+    const ROOT: &'static str;
+
+    fn render(feed: &OpdsFeed) -> OpdsDocument;
+}
+
+/// OPDS 1.2, an Atom profile.
+pub struct Atom;
+
+/// OPDS 2.0, a JSON-LD based format.
+pub struct Json;
+
+impl OpdsFormat for Atom {
+    const ROOT: &'static str = "/api/opds";
+
+    fn render(feed: &OpdsFeed) -> OpdsDocument {
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\"",
+            " xmlns:opds=\"http://opds-spec.org/2010/catalog\"",
+            " xmlns:opensearch=\"http://a9.com/-/spec/opensearch/1.1/\">\n"
+        ));
+        xml.push_str(&format!("  <id>urn:book-manager:opds:{}</id>\n", feed.id));
+        xml.push_str(&format!("  <title>{}</title>\n", escape(&feed.title)));
+        xml.push_str(&format!(
+            "  <updated>{}</updated>\n",
+            feed.updated.to_rfc3339()
+        ));
+        xml.push_str(&atom_link(
+            "self",
+            &feed.link.href(Self::ROOT),
+            atom_type(feed.kind),
+        ));
+        xml.push_str(&atom_link("start", Self::ROOT, ATOM_NAVIGATION));
+        xml.push_str(&atom_link(
+            "search",
+            &format!("{}/opensearch.xml", Self::ROOT),
+            "application/opensearchdescription+xml",
+        ));
+
+        if let Some(pagination) = &feed.pagination {
+            for (rel, page) in page_links(pagination) {
+                let link = feed.link.page(page, pagination.page_size);
+                xml.push_str(&atom_link(
+                    rel,
+                    &link.href(Self::ROOT),
+                    atom_type(feed.kind),
+                ));
+            }
+            xml.push_str(&format!(
+                "  <opensearch:totalResults>{}</opensearch:totalResults>\n",
+                pagination.total_count
+            ));
+            xml.push_str(&format!(
+                "  <opensearch:itemsPerPage>{}</opensearch:itemsPerPage>\n",
+                pagination.page_size
+            ));
+            xml.push_str(&format!(
+                "  <opensearch:startIndex>{}</opensearch:startIndex>\n",
+                (pagination.page - 1) * pagination.page_size + 1
+            ));
+        }
+
+        for entry in &feed.navigation {
+            xml.push_str("  <entry>\n");
+            xml.push_str(&format!("    <title>{}</title>\n", escape(&entry.title)));
+            xml.push_str(&format!(
+                "    <id>urn:book-manager:opds:{}</id>\n",
+                escape(&entry.link.href(""))
+            ));
+            xml.push_str(&format!(
+                "    <updated>{}</updated>\n",
+                feed.updated.to_rfc3339()
+            ));
+            if let Some(summary) = &entry.summary {
+                xml.push_str(&format!(
+                    "    <content type=\"text\">{}</content>\n",
+                    escape(summary)
+                ));
+            }
+            xml.push_str(&format!(
+                "  {}",
+                atom_link(
+                    "subsection",
+                    &entry.link.href(Self::ROOT),
+                    atom_type(entry.kind)
+                )
+            ));
+            xml.push_str("  </entry>\n");
+        }
+
+        for book in &feed.publications {
+            xml.push_str("  <entry>\n");
+            xml.push_str(&format!("    <title>{}</title>\n", escape(&book.title)));
+            xml.push_str(&format!("    <id>urn:uuid:{}</id>\n", book.id));
+            xml.push_str(&format!(
+                "    <updated>{}</updated>\n",
+                book.audit
+                    .updated_at
+                    .unwrap_or(book.audit.created_at)
+                    .to_rfc3339()
+            ));
+            for author in &book.authors {
+                xml.push_str(&format!(
+                    "    <author><name>{}</name></author>\n",
+                    escape(author)
+                ));
+            }
+            xml.push_str(&format!(
+                "    <content type=\"text\">{}</content>\n",
+                availability(book)
+            ));
+            xml.push_str(&format!(
+                "  {}",
+                atom_link(
+                    "alternate",
+                    &format!("/api/books/{}", book.id),
+                    "application/json"
+                )
+            ));
+            xml.push_str("  </entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+        OpdsDocument::new(atom_type(feed.kind), xml)
+    }
+}
+
+impl OpdsFormat for Json {
+    const ROOT: &'static str = "/api/opds/v2";
+
+    fn render(feed: &OpdsFeed) -> OpdsDocument {
+        let mut metadata = json!({ "title": feed.title, "modified": feed.updated });
+        let mut links = vec![
+            json_link("self", &feed.link.href(Self::ROOT)),
+            json_link("start", Self::ROOT),
+            json!({
+                "rel": "search",
+                "href": format!("{}/search{{?search}}", Self::ROOT),
+                "type": OPDS_JSON,
+                "templated": true,
+            }),
+        ];
+
+        if let Some(pagination) = &feed.pagination {
+            metadata["numberOfItems"] = json!(pagination.total_count);
+            metadata["itemsPerPage"] = json!(pagination.page_size);
+            metadata["currentPage"] = json!(pagination.page);
+            for (rel, page) in page_links(pagination) {
+                let link = feed.link.page(page, pagination.page_size);
+                links.push(json_link(rel, &link.href(Self::ROOT)));
+            }
+        }
+
+        let mut document = json!({ "metadata": metadata, "links": links });
+        match feed.kind {
+            OpdsFeedKind::Navigation => {
+                document["navigation"] = feed
+                    .navigation
+                    .iter()
+                    .map(|entry| {
+                        json!({
+                            "href": entry.link.href(Self::ROOT),
+                            "title": entry.title,
+                            "type": OPDS_JSON,
+                            "rel": "subsection",
+                        })
+                    })
+                    .collect::<Value>();
+            }
+            OpdsFeedKind::Acquisition => {
+                document["publications"] = feed
+                    .publications
+                    .iter()
+                    .map(|book| {
+                        json!({
+                            "metadata": {
+                                "@type": "http://schema.org/Book",
+                                "identifier": format!("urn:uuid:{}", book.id),
+                                "title": book.title,
+                                "author": book
+                                    .authors
+                                    .iter()
+                                    .map(|name| json!({ "name": name }))
+                                    .collect::<Value>(),
+                                "modified": book.audit.updated_at.unwrap_or(book.audit.created_at),
+                                "description": availability(book),
+                            },
+                            "links": [{
+                                "rel": "alternate",
+                                "href": format!("/api/books/{}", book.id),
+                                "type": "application/json",
+                            }],
+                        })
+                    })
+                    .collect::<Value>();
+            }
+        }
+
+        OpdsDocument::new(OPDS_JSON, document.to_string())
+    }
+}
+
+/// The OpenSearch description that OPDS 1.2 clients use to build search requests.
+pub fn opensearch_description() -> OpdsDocument {
+    let xml = format!(
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<OpenSearchDescription xmlns=\"http://a9.com/-/spec/opensearch/1.1/\">\n",
+            "  <ShortName>Book Manager</ShortName>\n",
+            "  <Description>Search the book catalog</Description>\n",
+            "  <InputEncoding>UTF-8</InputEncoding>\n",
+            "  <OutputEncoding>UTF-8</OutputEncoding>\n",
+            "  <Url type=\"{}\" template=\"{}/search?search={{searchTerms}}\"/>\n",
+            "</OpenSearchDescription>\n"
+        ),
+        ATOM_ACQUISITION,
+        Atom::ROOT
+    );
+    OpdsDocument::new("application/opensearchdescription+xml", xml)
+}
+
+fn page_links(pagination: &OpdsPagination) -> Vec<(&'static str, u64)> {
+    let last_page = pagination.last_page();
+    let mut links = vec![("first", 1), ("last", last_page)];
+    if pagination.page > 1 {
+        links.push(("previous", (pagination.page - 1).min(last_page)));
+    }
+    if pagination.page < last_page {
+        links.push(("next", pagination.page + 1));
+    }
+    links
+}
+
+fn availability(book: &BookListItemDTO) -> &'static str {
+    match book.checked_out {
+        true => "Checked out",
+        false => "Available",
+    }
+}
+
+fn atom_type(kind: OpdsFeedKind) -> &'static str {
+    match kind {
+        OpdsFeedKind::Navigation => ATOM_NAVIGATION,
+        OpdsFeedKind::Acquisition => ATOM_ACQUISITION,
+    }
+}
+
+fn atom_link(rel: &str, href: &str, link_type: &str) -> String {
+    format!(
+        "  <link rel=\"{}\" href=\"{}\" type=\"{}\"/>\n",
+        rel,
+        escape(href),
+        link_type
+    )
+}
+
+fn json_link(rel: &str, href: &str) -> Value {
+    json!({ "rel": rel, "href": href, "type": OPDS_JSON })
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use application::{
+        book::dto::BookVisibilityDTO,
+        shared::{AuditSummaryDTO, PermissionDTO, UserReferenceDTO},
+    };
+    use axum::{body::to_bytes, http::header, response::IntoResponse};
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn search_feed() -> OpdsFeed {
+        let updated = Utc.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap();
+        OpdsFeed {
+            id: "search",
+            title: "Search: <Rust & \"DDD\">".to_string(),
+            kind: OpdsFeedKind::Acquisition,
+            updated,
+            link: OpdsLink::new("search").param("search", "a&b c"),
+            navigation: vec![],
+            publications: vec![BookListItemDTO {
+                id: Uuid::nil(),
+                title: "Rust & <Friends>".to_string(),
+                authors: vec!["O'Brien".to_string()],
+                owner: UserReferenceDTO {
+                    id: Uuid::nil(),
+                    name: "owner".to_string(),
+                },
+                visibility: BookVisibilityDTO::Public,
+                checked_out: true,
+                audit: AuditSummaryDTO {
+                    created_at: updated,
+                    updated_at: None,
+                    permission: PermissionDTO {
+                        can_update: false,
+                        can_delete: false,
+                        capabilities: vec![],
+                    },
+                },
+            }],
+            pagination: Some(OpdsPagination {
+                page: 2,
+                page_size: 1,
+                total_count: 3,
+            }),
+        }
+    }
+
+    async fn rendered(document: OpdsDocument) -> (String, String) {
+        let response = document.into_response();
+        let content_type = response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (content_type, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn atom_feed_escapes_text_and_links() {
+        let (content_type, xml) = rendered(Atom::render(&search_feed())).await;
+
+        assert_eq!(content_type, ATOM_ACQUISITION);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed "));
+        assert!(xml.ends_with("</feed>\n"));
+        assert!(xml.contains("  <title>Search: &lt;Rust &amp; &quot;DDD&quot;&gt;</title>\n"));
+        assert!(xml.contains(
+            "  <link rel=\"self\" href=\"/api/opds/search?search=a%26b%20c\" type=\"application/atom+xml;profile=opds-catalog;kind=acquisition\"/>\n"
+        ));
+        assert!(
+            xml.contains("href=\"/api/opds/search?search=a%26b%20c&amp;page=3&amp;page_size=1\"")
+        );
+        assert!(xml.contains("  <opensearch:totalResults>3</opensearch:totalResults>\n"));
+        assert!(xml.contains("  <opensearch:startIndex>2</opensearch:startIndex>\n"));
+
+        assert_eq!(xml.matches("<entry>").count(), 1);
+        assert!(xml.contains("    <title>Rust &amp; &lt;Friends&gt;</title>\n"));
+        assert!(xml.contains("    <id>urn:uuid:00000000-0000-0000-0000-000000000000</id>\n"));
+        assert!(xml.contains("    <author><name>O&apos;Brien</name></author>\n"));
+        assert!(xml.contains("    <content type=\"text\">Checked out</content>\n"));
+    }
+
+    #[tokio::test]
+    async fn json_feed_has_opds2_shape() {
+        let (content_type, body) = rendered(Json::render(&search_feed())).await;
+        let document: Value = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(content_type, OPDS_JSON);
+        assert_eq!(document["metadata"]["title"], "Search: <Rust & \"DDD\">");
+        assert_eq!(document["metadata"]["numberOfItems"], 3);
+        assert_eq!(document["metadata"]["itemsPerPage"], 1);
+        assert_eq!(document["metadata"]["currentPage"], 2);
+        assert!(document.get("navigation").is_none());
+
+        let links = document["links"].as_array().unwrap();
+        let href = |rel: &str| {
+            links
+                .iter()
+                .find(|link| link["rel"] == rel)
+                .map(|link| link["href"].as_str().unwrap().to_string())
+        };
+        assert_eq!(
+            href("self").as_deref(),
+            Some("/api/opds/v2/search?search=a%26b%20c")
+        );
+        assert_eq!(
+            href("next").as_deref(),
+            Some("/api/opds/v2/search?search=a%26b%20c&page=3&page_size=1")
+        );
+        assert_eq!(
+            href("previous").as_deref(),
+            Some("/api/opds/v2/search?search=a%26b%20c&page=1&page_size=1")
+        );
+
+        let publications = document["publications"].as_array().unwrap();
+        assert_eq!(publications.len(), 1);
+        let metadata = &publications[0]["metadata"];
+        assert_eq!(metadata["@type"], "http://schema.org/Book");
+        assert_eq!(metadata["title"], "Rust & <Friends>");
+        assert_eq!(metadata["author"][0]["name"], "O'Brien");
+        assert_eq!(metadata["description"], "Checked out");
+        assert_eq!(
+            publications[0]["links"][0]["href"],
+            "/api/books/00000000-0000-0000-0000-000000000000"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use application::book::dto::{AuthorListQueryDTO, BookListQueryDTO, BookListResponseDTO};
+use axum::extract::{Query, State};
+use chrono::Utc;
+use domain::tenant::values::TenantId;
+
+use crate::{
+    auth::OidcUserInfo,
+    error::ApiError,
+    registry::AppRegistry,
+    router::opds::{
+        feed::{
+            OpdsFeed, OpdsFeedKind, OpdsFormat, OpdsLink, OpdsNavigationEntry, OpdsPagination,
+            opensearch_description,
+        },
+        response::OpdsDocument,
+    },
+    tenant::RequestTenant,
+};
+
+#[tracing::instrument(skip_all)]
+pub async fn get_root<F: OpdsFormat>() -> OpdsDocument {
+    let entry = |title: &str, summary: &str, path| OpdsNavigationEntry {
+        title: title.to_string(),
+        summary: Some(summary.to_string()),
+        link: OpdsLink::new(path),
+        kind: match path {
+            "authors" => OpdsFeedKind::Navigation,
+            _ => OpdsFeedKind::Acquisition,
+        },
+    };
+
+    F::render(&OpdsFeed {
+        id: "root",
+        title: "Book Manager".to_string(),
+        kind: OpdsFeedKind::Navigation,
+        updated: Utc::now(),
+        link: OpdsLink::new(""),
+        navigation: vec![
+            entry("New arrivals", "Most recently added books", "new"),
+            entry("Authors", "Browse books by author", "authors"),
+            entry("Available", "Books that are not checked out", "available"),
+        ],
+        publications: vec![],
+        pagination: None,
+    })
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = ?user_info.as_ref().map(|u| u.id)),
+    err
+)]
+pub async fn get_new_books<F: OpdsFormat>(
+    user_info: Option<OidcUserInfo>,
+    RequestTenant(tenant): RequestTenant,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<BookListQueryDTO>,
+) -> Result<OpdsDocument, ApiError> {
+    let response = get_books(&registry, &tenant, user_info, &query).await?;
+    let link = OpdsLink::new("new").with_list_query(&query);
+
+    Ok(F::render(&acquisition_feed(
+        "new",
+        "New arrivals",
+        link,
+        response,
+    )))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = ?user_info.as_ref().map(|u| u.id)),
+    err
+)]
+pub async fn get_available_books<F: OpdsFormat>(
+    user_info: Option<OidcUserInfo>,
+    RequestTenant(tenant): RequestTenant,
+    State(registry): State<AppRegistry>,
+    Query(mut query): Query<BookListQueryDTO>,
+) -> Result<OpdsDocument, ApiError> {
+    query.filter.checked_out = Some(false);
+    let response = get_books(&registry, &tenant, user_info, &query).await?;
+    // The checked_out filter is implied by the path, so it is not repeated in the links
+    query.filter.checked_out = None;
+    let link = OpdsLink::new("available").with_list_query(&query);
+
+    Ok(F::render(&acquisition_feed(
+        "available",
+        "Available",
+        link,
+        response,
+    )))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = ?user_info.as_ref().map(|u| u.id)),
+    err
+)]
+pub async fn search_books<F: OpdsFormat>(
+    user_info: Option<OidcUserInfo>,
+    RequestTenant(tenant): RequestTenant,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<BookListQueryDTO>,
+) -> Result<OpdsDocument, ApiError> {
+    let response = get_books(&registry, &tenant, user_info, &query).await?;
+    let title = match &query.filter.search {
+        Some(search) => format!("Search results for \"{}\"", search),
+        None => "Search results".to_string(),
+    };
+    let link = OpdsLink::new("search").with_list_query(&query);
+
+    Ok(F::render(&acquisition_feed(
+        "search", &title, link, response,
+    )))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = ?user_info.as_ref().map(|u| u.id)),
+    err
+)]
+pub async fn get_books_by_author<F: OpdsFormat>(
+    user_info: Option<OidcUserInfo>,
+    RequestTenant(tenant): RequestTenant,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<BookListQueryDTO>,
+) -> Result<OpdsDocument, ApiError> {
+    let response = get_books(&registry, &tenant, user_info, &query).await?;
+    let title = match &query.filter.author_name {
+        Some(author_name) => format!("Books by {}", author_name),
+        None => "Books".to_string(),
+    };
+    let link = OpdsLink::new("books").with_list_query(&query);
+
+    Ok(F::render(&acquisition_feed(
+        "books", &title, link, response,
+    )))
+}
+
+#[tracing::instrument(skip(registry), err)]
+pub async fn get_authors<F: OpdsFormat>(
+    RequestTenant(tenant): RequestTenant,
+    State(registry): State<AppRegistry>,
+    Query(query): Query<AuthorListQueryDTO>,
+) -> Result<OpdsDocument, ApiError> {
+    let response = registry
+        .book_registry()
+        .get_author_list()
+        .execute(&tenant, &query)
+        .await?;
+
+    Ok(F::render(&OpdsFeed {
+        id: "authors",
+        title: "Authors".to_string(),
+        kind: OpdsFeedKind::Navigation,
+        updated: Utc::now(),
+        link: OpdsLink::new("authors"),
+        navigation: response
+            .items
+            .into_iter()
+            .map(|author| OpdsNavigationEntry {
+                summary: Some(format!("{} books", author.book_count)),
+                link: OpdsLink::new("books").param("author_name", &author.name),
+                title: author.name,
+                kind: OpdsFeedKind::Acquisition,
+            })
+            .collect(),
+        publications: vec![],
+        pagination: Some(OpdsPagination {
+            page: response.page,
+            page_size: response.page_size,
+            total_count: response.total_count,
+        }),
+    }))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn get_opensearch_description() -> OpdsDocument {
+    opensearch_description()
+}
+
+async fn get_books(
+    registry: &AppRegistry,
+    tenant: &TenantId,
+    user_info: Option<OidcUserInfo>,
+    query: &BookListQueryDTO,
+) -> Result<BookListResponseDTO, ApiError> {
+    let actor = registry.prepare_optional_actor(user_info.as_ref()).await?;
+
+    Ok(registry
+        .book_registry()
+        .get_book_list()
+        .execute(tenant, actor.as_ref(), query)
+        .await?)
+}
+
+fn acquisition_feed(
+    id: &'static str,
+    title: &str,
+    link: OpdsLink,
+    response: BookListResponseDTO,
+) -> OpdsFeed {
+    OpdsFeed {
+        id,
+        title: title.to_string(),
+        kind: OpdsFeedKind::Acquisition,
+        updated: response
+            .items
+            .iter()
+            .map(|book| book.audit.updated_at.unwrap_or(book.audit.created_at))
+            .max()
+            .unwrap_or_else(Utc::now),
+        link,
+        navigation: vec![],
+        pagination: Some(OpdsPagination {
+            page: response.page,
+            page_size: response.page_size,
+            total_count: response.total_count,
+        }),
+        publications: response.items,
+    }
+}
@@ -0,0 +1,57 @@
+use aide::{
+    OperationOutput,
+    generate::GenContext,
+    openapi::{MediaType, Operation, Response, StatusCode},
+};
+use axum::{
+    http::header,
+    response::{IntoResponse, Response as AxumResponse},
+};
+
+/// A rendered OPDS document together with its profile-specific content type.
+pub struct OpdsDocument {
+    content_type: &'static str,
+    body: String,
+}
+
+impl OpdsDocument {
+    pub fn new(content_type: &'static str, body: String) -> Self {
+        Self { content_type, body }
+    }
+}
+
+impl IntoResponse for OpdsDocument {
+    fn into_response(self) -> AxumResponse {
+        ([(header::CONTENT_TYPE, self.content_type)], self.body).into_response()
+    }
+}
+
+impl OperationOutput for OpdsDocument {
+    type Inner = ();
+
+    fn operation_response(_ctx: &mut GenContext, _operation: &mut Operation) -> Option<Response> {
+        let mut response = Response {
+            description: "OPDS catalog document".into(),
+            ..Default::default()
+        };
+        for content_type in [
+            "application/atom+xml",
+            "application/opds+json",
+            "application/opensearchdescription+xml",
+        ] {
+            response
+                .content
+                .insert(content_type.into(), MediaType::default());
+        }
+        Some(response)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<StatusCode>, Response)> {
+        Self::operation_response(ctx, operation)
+            .map(|response| vec![(Some(StatusCode::Code(200)), response)])
+            .unwrap_or_default()
+    }
+}
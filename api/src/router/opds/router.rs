@@ -0,0 +1,37 @@
+use aide::axum::{ApiRouter, routing::get_with};
+
+use crate::{
+    registry::AppRegistry,
+    router::opds::{
+        feed::{Atom, Json, OpdsFormat},
+        handlers::*,
+    },
+};
+
+pub fn opds_router() -> ApiRouter<AppRegistry> {
+    ApiRouter::new().nest(
+        "/opds",
+        format_router::<Atom>()
+            .api_route(
+                "/opensearch.xml",
+                get_with(get_opensearch_description, |op| op.tag("OPDS")),
+            )
+            .nest("/v2", format_router::<Json>()),
+    )
+}
+
+fn format_router<F: OpdsFormat>() -> ApiRouter<AppRegistry> {
+    ApiRouter::new()
+        .api_route("/", get_with(get_root::<F>, |op| op.tag("OPDS")))
+        .api_route("/new", get_with(get_new_books::<F>, |op| op.tag("OPDS")))
+        .api_route(
+            "/available",
+            get_with(get_available_books::<F>, |op| op.tag("OPDS")),
+        )
+        .api_route("/authors", get_with(get_authors::<F>, |op| op.tag("OPDS")))
+        .api_route(
+            "/books",
+            get_with(get_books_by_author::<F>, |op| op.tag("OPDS")),
+        )
+        .api_route("/search", get_with(search_books::<F>, |op| op.tag("OPDS")))
+}
@@ -1,8 +1,19 @@
-use axum::{Json, extract::State};
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::NoContent,
+};
 
-use application::user::dto::UserDetailsDTO;
+use application::{
+    personal_access_token::dto::*,
+    user::dto::{
+        BookSettingsDTO, NotificationSettingsDTO, UpdateBookSettingsRequestDTO,
+        UpdateNotificationSettingsRequestDTO, UserDetailsDTO,
+    },
+};
+use reqwest::StatusCode;
 
-use crate::{auth::OidcUserInfo, error::ApiError, registry::AppRegistry};
+use crate::{auth::OidcUserInfo, error::ApiError, precondition::ETagged, registry::AppRegistry};
 
 #[tracing::instrument(
     skip(registry, user_info),
@@ -12,7 +23,7 @@ use crate::{auth::OidcUserInfo, error::ApiError, registry::AppRegistry};
 pub async fn get_me_details(
     user_info: OidcUserInfo,
     State(registry): State<AppRegistry>,
-) -> Result<Json<UserDetailsDTO>, ApiError> {
+) -> Result<ETagged<UserDetailsDTO>, ApiError> {
     let actor = registry.prepare_actor(&user_info).await?;
 
     let response = registry
@@ -21,5 +32,149 @@ pub async fn get_me_details(
         .execute(actor.id())
         .await?;
 
+    Ok(ETagged::new(response.version, response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_notification_settings(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<NotificationSettingsDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .user_registry()
+        .get_notification_settings()
+        .execute(actor.id())
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn update_notification_settings(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Json(request): Json<UpdateNotificationSettingsRequestDTO>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .user_registry()
+        .update_notification_settings()
+        .execute(&actor, &request)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_book_settings(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<BookSettingsDTO>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .user_registry()
+        .get_book_settings()
+        .execute(actor.id())
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn update_book_settings(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Json(request): Json<UpdateBookSettingsRequestDTO>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .user_registry()
+        .update_book_settings()
+        .execute(&actor, &request)
+        .await?;
+
+    Ok(NoContent)
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn get_my_tokens(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+) -> Result<Json<Vec<PersonalAccessTokenDTO>>, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .personal_access_token_registry()
+        .get_personal_access_tokens()
+        .execute(&actor, actor.id())
+        .await?;
+
     Ok(Json(response))
 }
+
+#[tracing::instrument(
+    skip(registry, user_info, request),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn create_my_token(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Json(request): Json<CreatePersonalAccessTokenRequestDTO>,
+) -> Result<(StatusCode, Json<PersonalAccessTokenCreatedDTO>), ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    let response = registry
+        .personal_access_token_registry()
+        .create_personal_access_token()
+        .execute(&actor, &request)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+#[tracing::instrument(
+    skip(registry, user_info),
+    fields(user_id = %user_info.id),
+    err
+)]
+pub async fn revoke_my_token(
+    user_info: OidcUserInfo,
+    State(registry): State<AppRegistry>,
+    Path(identity): Path<PersonalAccessTokenIdentity>,
+) -> Result<NoContent, ApiError> {
+    let actor = registry.prepare_actor(&user_info).await?;
+
+    registry
+        .personal_access_token_registry()
+        .revoke_personal_access_token()
+        .execute(&actor, identity)
+        .await?;
+
+    Ok(NoContent)
+}
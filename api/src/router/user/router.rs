@@ -1,13 +1,44 @@
-use aide::axum::{ApiRouter, routing::get_with};
+use aide::axum::{
+    ApiRouter,
+    routing::{delete_with, get_with},
+};
+use axum::{Json, response::NoContent};
+
+use application::personal_access_token::dto::PersonalAccessTokenCreatedDTO;
 
 use crate::{registry::AppRegistry, router::user::handlers::*};
 
 pub fn user_router() -> ApiRouter<AppRegistry> {
     ApiRouter::new().nest(
         "/users",
-        ApiRouter::new().api_route(
-            "/me",
-            get_with(get_me_details, |op| op.tag("Users")),
-        ),
+        ApiRouter::new()
+            .api_route("/me", get_with(get_me_details, |op| op.tag("Users")))
+            .api_route(
+                "/me/notification-settings",
+                get_with(get_notification_settings, |op| op.tag("Users"))
+                    .put_with(update_notification_settings, |op| {
+                        op.tag("Users").response::<204, NoContent>()
+                    }),
+            )
+            .api_route(
+                "/me/book-settings",
+                get_with(get_book_settings, |op| op.tag("Users"))
+                    .put_with(update_book_settings, |op| {
+                        op.tag("Users").response::<204, NoContent>()
+                    }),
+            )
+            .api_route(
+                "/me/tokens",
+                get_with(get_my_tokens, |op| op.tag("Users")).post_with(create_my_token, |op| {
+                    op.tag("Users")
+                        .response::<201, Json<PersonalAccessTokenCreatedDTO>>()
+                }),
+            )
+            .api_route(
+                "/me/tokens/{token_id}",
+                delete_with(revoke_my_token, |op| {
+                    op.tag("Users").response::<204, NoContent>()
+                }),
+            ),
     )
 }
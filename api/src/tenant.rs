@@ -0,0 +1,47 @@
+use aide::OperationInput;
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+use domain::tenant::values::TenantId;
+use infrastructure::config::TenantConfig;
+
+use crate::{error::ApiError, registry::AppRegistry};
+
+/// Tenant the request is addressed to by its host, for endpoints that also serve anonymous users.
+pub struct RequestTenant(pub TenantId);
+
+impl FromRequestParts<AppRegistry> for RequestTenant {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppRegistry,
+    ) -> Result<Self, Self::Rejection> {
+        Ok(RequestTenant(
+            host_tenant(parts, &state.config().tenant).unwrap_or_default(),
+        ))
+    }
+}
+
+impl OperationInput for RequestTenant {}
+
+/// Reads the tenant from the subdomain of the configured base domain, e.g. `acme` from
+/// `acme.books.example.com`. `None` for the base domain itself and for any other host.
+pub fn host_tenant(parts: &Parts, config: &TenantConfig) -> Option<TenantId> {
+    let base_domain = config.base_domain.as_deref()?;
+    let host = parts
+        .headers
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .or(parts.uri.host())?;
+    let host = host
+        .rsplit_once(':')
+        .map_or(host, |(name, _port)| name)
+        .to_ascii_lowercase();
+
+    host.strip_suffix(base_domain)
+        .and_then(|subdomain| subdomain.strip_suffix('.'))
+        .filter(|label| !label.contains('.'))
+        .and_then(|label| TenantId::try_from(label.to_string()).ok())
+}
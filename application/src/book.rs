@@ -1,5 +1,6 @@
 pub mod command;
 pub mod dto;
+mod export;
 pub mod interface;
 pub mod query;
 pub mod registry;
@@ -1,11 +1,15 @@
+mod change_book_group;
 mod checkout_book;
 mod create_book;
 mod delete_book;
 mod return_book;
 mod update_book;
+mod update_book_co_owners;
 
+pub use change_book_group::*;
 pub use checkout_book::*;
 pub use create_book::*;
 pub use delete_book::*;
 pub use return_book::*;
 pub use update_book::*;
+pub use update_book_co_owners::*;
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    book::interface::BookRepository,
+    group::{interface::GroupRepository, values::GroupId},
+    shared::error::DomainError,
+};
+
+use crate::{
+    book::dto::{BookIdentity, ChangeBookGroupRequestDTO},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct ChangeBookGroupService {
+    clock: Arc<dyn Clock>,
+    book_repository: Arc<dyn BookRepository>,
+    group_repository: Arc<dyn GroupRepository>,
+}
+
+impl ChangeBookGroupService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: BookIdentity,
+        expected_version: Option<u32>,
+        request: &ChangeBookGroupRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        ensure_group_exists(self.group_repository.as_ref(), actor, request.group_id).await?;
+
+        let mut book = self
+            .book_repository
+            .find_by_id(identity.book_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        book.audit().ensure_version(expected_version)?;
+
+        book.change_group(&context, request.group_id)?;
+
+        self.book_repository.save(&mut book).await?;
+
+        Ok(())
+    }
+}
+
+pub(crate) async fn ensure_group_exists(
+    group_repository: &dyn GroupRepository,
+    actor: &Actor,
+    group_id: Option<GroupId>,
+) -> Result<(), ApplicationError> {
+    let Some(group_id) = group_id else {
+        return Ok(());
+    };
+
+    match group_repository.find_by_id(group_id).await? {
+        Some(group) if actor.can_access_tenant(group.audit().tenant()) => Ok(()),
+        _ => Err(DomainError::ValidationError("Group does not exist".to_string()).into()),
+    }
+}
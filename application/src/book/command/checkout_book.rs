@@ -3,15 +3,24 @@ use std::sync::Arc;
 use derive_new::new;
 use domain::{
     audit::{Actor, AuditContext, Clock},
+    auth::capability::Capability,
     book::interface::BookRepository,
+    group::interface::GroupRepository,
+    shared::error::{DomainError, PersistenceError},
+    user::{interface::UserDomainQueryService, values::UserReference},
 };
 
-use crate::{book::dto::BookIdentity, shared::error::ApplicationError};
+use crate::{
+    book::dto::{BookIdentity, CheckoutBookRequestDTO},
+    shared::error::ApplicationError,
+};
 
 #[derive(new)]
 pub struct CheckoutBookService {
     clock: Arc<dyn Clock>,
     book_repository: Arc<dyn BookRepository>,
+    user_domain_query_service: Arc<dyn UserDomainQueryService>,
+    group_repository: Arc<dyn GroupRepository>,
 }
 
 impl CheckoutBookService {
@@ -19,19 +28,72 @@ impl CheckoutBookService {
         &self,
         actor: &Actor,
         identity: BookIdentity,
+        request: &CheckoutBookRequestDTO,
+        expected_version: Option<u32>,
     ) -> Result<(), ApplicationError> {
         let context = AuditContext::new(actor, self.clock.as_ref());
 
+        let borrower: UserReference = match request.borrower_id {
+            // Checked before the lookup so that the response does not tell whether the user exists
+            Some(borrower_id)
+                if borrower_id != actor.id()
+                    && !actor.has_capability(Capability::CheckoutOnBehalf) =>
+            {
+                return Err(DomainError::Forbidden.into());
+            }
+            Some(borrower_id) if borrower_id != actor.id() => (&self
+                .user_domain_query_service
+                .find_actor_by_id(borrower_id)
+                .await?
+                .filter(|user| user.tenant() == actor.tenant())
+                .ok_or(DomainError::ValidationError(
+                    "Borrower does not exist".to_string(),
+                ))?)
+                .into(),
+            _ => actor.into(),
+        };
+
         let mut book = self
             .book_repository
             .find_by_id(identity.book_id)
             .await?
             .ok_or(ApplicationError::NotFound)?;
 
-        book.do_checkout(&context)?;
+        // A book in a private team library is hidden from non-members as if it did not exist
+        if let Some(group_id) = book.group_id()
+            && !actor.is_admin()
+            && !actor.is_member_of(group_id)
+            && self
+                .group_repository
+                .find_by_id(group_id)
+                .await?
+                .is_some_and(|group| group.private_library())
+        {
+            return Err(ApplicationError::NotFound);
+        }
+
+        book.audit().ensure_version(expected_version)?;
+
+        book.do_checkout(&context, borrower)?;
 
-        self.book_repository.save(&book).await?;
+        match self.book_repository.save(&mut book).await {
+            Ok(()) => Ok(()),
+            // Someone else saved the book between our load and save; if that was a
+            // checkout, report it the same way as when we had seen it up front
+            Err(PersistenceError::Conflict) => {
+                let current = self
+                    .book_repository
+                    .find_by_id(identity.book_id)
+                    .await?
+                    .ok_or(ApplicationError::NotFound)?;
 
-        Ok(())
+                if current.is_checked_out() {
+                    Err(DomainError::AlreadyCheckedOut.into())
+                } else {
+                    Err(PersistenceError::Conflict.into())
+                }
+            }
+            Err(e) => Err(e.into()),
+        }
     }
 }
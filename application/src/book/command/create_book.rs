@@ -3,11 +3,16 @@ use std::sync::Arc;
 use derive_new::new;
 use domain::{
     audit::{Actor, AuditContext, Clock},
-    book::{entity::Book, interface::BookRepository},
+    book::{
+        entity::{Book, NewBook},
+        interface::BookRepository,
+    },
+    group::interface::GroupRepository,
+    user::interface::UserDomainQueryService,
 };
 
 use crate::{
-    book::dto::CreateBookRequestDTO,
+    book::{command::ensure_group_exists, dto::CreateBookRequestDTO},
     shared::{EntityCreationDTO, error::ApplicationError},
 };
 
@@ -15,6 +20,8 @@ use crate::{
 pub struct CreateBookService {
     clock: Arc<dyn Clock>,
     book_repository: Arc<dyn BookRepository>,
+    group_repository: Arc<dyn GroupRepository>,
+    user_domain_query_service: Arc<dyn UserDomainQueryService>,
 }
 
 impl CreateBookService {
@@ -25,16 +32,31 @@ impl CreateBookService {
     ) -> Result<EntityCreationDTO, ApplicationError> {
         let context = AuditContext::new(actor, self.clock.as_ref());
 
-        let book = Book::create_new(
+        ensure_group_exists(self.group_repository.as_ref(), actor, request.group_id).await?;
+
+        let visibility = match request.visibility {
+            Some(visibility) => visibility.into(),
+            None => self
+                .user_domain_query_service
+                .find_default_book_visibility(actor.id())
+                .await?
+                .unwrap_or_default(),
+        };
+
+        let mut book = Book::create_new(
             &context,
-            request.title.clone().try_into()?,
-            request.author_names.clone().try_into()?,
-            request.isbn.clone().try_into()?,
-            request.description.clone().try_into()?,
-            actor.into(),
+            NewBook {
+                title: request.title.clone().try_into()?,
+                authors: request.author_names.clone().try_into()?,
+                isbn: request.isbn.clone().try_into()?,
+                description: request.description.clone().try_into()?,
+                owner: actor.into(),
+                group_id: request.group_id,
+                visibility,
+            },
         )?;
 
-        self.book_repository.save(&book).await?;
+        self.book_repository.save(&mut book).await?;
 
         Ok(book.audit().into())
     }
@@ -19,6 +19,7 @@ impl DeleteBookService {
         &self,
         actor: &Actor,
         identity: BookIdentity,
+        expected_version: Option<u32>,
     ) -> Result<(), ApplicationError> {
         let context = AuditContext::new(actor, self.clock.as_ref());
 
@@ -28,9 +29,11 @@ impl DeleteBookService {
             .await?
             .ok_or(ApplicationError::NotFound)?;
 
+        book.audit().ensure_version(expected_version)?;
+
         book.validate_deletion(&context)?;
 
-        self.book_repository.delete(book.audit().id()).await?;
+        self.book_repository.delete(&book).await?;
 
         Ok(())
     }
@@ -19,6 +19,7 @@ impl ReturnBookService {
         &self,
         actor: &Actor,
         identity: BookIdentity,
+        expected_version: Option<u32>,
     ) -> Result<(), ApplicationError> {
         let context = AuditContext::new(actor, self.clock.as_ref());
 
@@ -28,9 +29,11 @@ impl ReturnBookService {
             .await?
             .ok_or(ApplicationError::NotFound)?;
 
+        book.audit().ensure_version(expected_version)?;
+
         book.do_return(&context)?;
 
-        self.book_repository.save(&book).await?;
+        self.book_repository.save(&mut book).await?;
 
         Ok(())
     }
@@ -22,6 +22,7 @@ impl UpdateBookService {
         &self,
         actor: &Actor,
         identity: BookIdentity,
+        expected_version: Option<u32>,
         request: &UpdateBookRequestDTO,
     ) -> Result<(), ApplicationError> {
         let context = AuditContext::new(actor, self.clock.as_ref());
@@ -32,15 +33,21 @@ impl UpdateBookService {
             .await?
             .ok_or(ApplicationError::NotFound)?;
 
+        book.audit().ensure_version(expected_version)?;
+
         book.update(
             &context,
             request.title.clone().try_into()?,
             request.author_names.clone().try_into()?,
             request.isbn.clone().try_into()?,
             request.description.clone().try_into()?,
+            // Leaving it out keeps the book's current visibility
+            request
+                .visibility
+                .map_or(book.visibility(), |visibility| visibility.into()),
         )?;
 
-        self.book_repository.save(&book).await?;
+        self.book_repository.save(&mut book).await?;
 
         Ok(())
     }
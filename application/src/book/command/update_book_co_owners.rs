@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    book::interface::BookRepository,
+    shared::error::DomainError,
+    user::{interface::UserDomainQueryService, values::UserReference},
+};
+
+use crate::{
+    book::dto::{BookIdentity, UpdateBookCoOwnersRequestDTO},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct UpdateBookCoOwnersService {
+    clock: Arc<dyn Clock>,
+    book_repository: Arc<dyn BookRepository>,
+    user_domain_query_service: Arc<dyn UserDomainQueryService>,
+}
+
+impl UpdateBookCoOwnersService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: BookIdentity,
+        expected_version: Option<u32>,
+        request: &UpdateBookCoOwnersRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut co_owners: Vec<UserReference> = Vec::with_capacity(request.user_ids.len());
+        for user_id in &request.user_ids {
+            let user = self
+                .user_domain_query_service
+                .find_actor_by_id(*user_id)
+                .await?
+                .filter(|user| user.tenant() == actor.tenant())
+                .ok_or(DomainError::ValidationError(
+                    "Co-owner does not exist".to_string(),
+                ))?;
+            co_owners.push((&user).into());
+        }
+
+        let mut book = self
+            .book_repository
+            .find_by_id(identity.book_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        book.audit().ensure_version(expected_version)?;
+
+        book.update_co_owners(&context, co_owners.try_into()?)?;
+
+        self.book_repository.save(&mut book).await?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
+mod enums;
 mod identity;
 mod query;
 mod request;
 mod response;
 
+pub use enums::*;
 pub use identity::*;
 pub use query::*;
 pub use request::*;
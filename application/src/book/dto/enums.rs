@@ -0,0 +1,65 @@
+use domain::book::enums::BookVisibility;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BookExportFormatDTO {
+    Csv,
+    Json,
+    Bibtex,
+    Ris,
+    Marc21,
+    Marcxml,
+}
+
+impl BookExportFormatDTO {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            BookExportFormatDTO::Csv => "text/csv; charset=utf-8",
+            BookExportFormatDTO::Json => "application/json",
+            BookExportFormatDTO::Bibtex => "application/x-bibtex; charset=utf-8",
+            BookExportFormatDTO::Ris => "application/x-research-info-systems; charset=utf-8",
+            BookExportFormatDTO::Marc21 => "application/marc",
+            BookExportFormatDTO::Marcxml => "application/marcxml+xml",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            BookExportFormatDTO::Csv => "csv",
+            BookExportFormatDTO::Json => "json",
+            BookExportFormatDTO::Bibtex => "bib",
+            BookExportFormatDTO::Ris => "ris",
+            BookExportFormatDTO::Marc21 => "mrc",
+            BookExportFormatDTO::Marcxml => "xml",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BookVisibilityDTO {
+    Public,
+    Internal,
+    Private,
+}
+
+impl From<BookVisibilityDTO> for BookVisibility {
+    fn from(dto: BookVisibilityDTO) -> Self {
+        match dto {
+            BookVisibilityDTO::Public => BookVisibility::Public,
+            BookVisibilityDTO::Internal => BookVisibility::Internal,
+            BookVisibilityDTO::Private => BookVisibility::Private,
+        }
+    }
+}
+
+impl From<BookVisibility> for BookVisibilityDTO {
+    fn from(visibility: BookVisibility) -> Self {
+        match visibility {
+            BookVisibility::Public => BookVisibilityDTO::Public,
+            BookVisibility::Internal => BookVisibilityDTO::Internal,
+            BookVisibility::Private => BookVisibilityDTO::Private,
+        }
+    }
+}
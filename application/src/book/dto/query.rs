@@ -2,29 +2,61 @@ use garde::Validate;
 use serde::Deserialize;
 use uuid::Uuid;
 
+use crate::{book::dto::BookExportFormatDTO, shared::query_param};
+
 #[derive(Debug, Deserialize, Validate, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct BookListQueryDTO {
     #[garde(range(min = 1))]
-    #[serde(default = "default_page_size")]
+    #[serde(
+        default = "default_page_size",
+        deserialize_with = "query_param::from_str"
+    )]
     pub page_size: u64,
     #[garde(range(min = 1))]
-    #[serde(default = "default_page")]
+    #[serde(default = "default_page", deserialize_with = "query_param::from_str")]
     pub page: u64,
     #[garde(skip)]
-    pub owner_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub filter: BookListFilterDTO,
+}
+
+#[derive(Debug, Deserialize, Validate, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BookExportQueryDTO {
     #[garde(skip)]
-    pub checked_out: Option<bool>,
+    pub format: BookExportFormatDTO,
     #[garde(skip)]
+    #[serde(flatten)]
+    pub filter: BookListFilterDTO,
+}
+
+// The filters shared by the book list and the catalog export
+#[derive(Debug, Clone, Default, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BookListFilterDTO {
+    pub owner_id: Option<Uuid>,
+    pub managed_by_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    #[serde(default, deserialize_with = "query_param::option_from_str")]
+    pub checked_out: Option<bool>,
     pub checked_out_to_id: Option<Uuid>,
-    #[garde(skip)]
     pub search: Option<String>,
-    #[garde(skip)]
     pub title: Option<String>,
-    #[garde(skip)]
     pub author_name: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Validate, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AuthorListQueryDTO {
+    #[garde(range(min = 1))]
+    #[serde(default = "default_page_size")]
+    pub page_size: u64,
+    #[garde(range(min = 1))]
+    #[serde(default = "default_page")]
+    pub page: u64,
+}
+
 #[derive(Debug, Deserialize, Validate, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct CheckoutHistoryQueryDTO {
@@ -43,3 +75,50 @@ const fn default_page_size() -> u64 {
 const fn default_page() -> u64 {
     1
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_query_parses_flattened_filters_from_a_query_string() {
+        let owner_id = Uuid::new_v4();
+        let query: BookListQueryDTO = serde_urlencoded::from_str(&format!(
+            "page_size=5&page=2&checked_out=true&owner_id={owner_id}&title=Rust"
+        ))
+        .unwrap();
+
+        assert_eq!(query.page_size, 5);
+        assert_eq!(query.page, 2);
+        assert_eq!(query.filter.checked_out, Some(true));
+        assert_eq!(query.filter.owner_id, Some(owner_id));
+        assert_eq!(query.filter.title.as_deref(), Some("Rust"));
+    }
+
+    #[test]
+    fn list_query_defaults_paging_and_leaves_filters_empty() {
+        let query: BookListQueryDTO = serde_urlencoded::from_str("").unwrap();
+
+        assert_eq!(query.page_size, default_page_size());
+        assert_eq!(query.page, default_page());
+        assert_eq!(query.filter.checked_out, None);
+        assert_eq!(query.filter.search, None);
+    }
+
+    #[test]
+    fn export_query_shares_the_list_filters() {
+        let query: BookExportQueryDTO =
+            serde_urlencoded::from_str("format=csv&checked_out=false&search=ddd").unwrap();
+
+        assert_eq!(query.format, BookExportFormatDTO::Csv);
+        assert_eq!(query.filter.checked_out, Some(false));
+        assert_eq!(query.filter.search.as_deref(), Some("ddd"));
+    }
+
+    #[test]
+    fn invalid_boolean_is_rejected() {
+        let result = serde_urlencoded::from_str::<BookListQueryDTO>("checked_out=maybe");
+
+        assert!(result.is_err());
+    }
+}
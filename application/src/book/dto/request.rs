@@ -1,6 +1,8 @@
-use domain::user::values::UserId;
+use domain::{group::values::GroupId, user::values::UserId};
 use serde::Deserialize;
 
+use crate::book::dto::BookVisibilityDTO;
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateBookRequestDTO {
@@ -8,6 +10,8 @@ pub struct CreateBookRequestDTO {
     pub author_names: Vec<String>,
     pub isbn: Option<String>,
     pub description: Option<String>,
+    pub group_id: Option<GroupId>,
+    pub visibility: Option<BookVisibilityDTO>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -17,6 +21,19 @@ pub struct UpdateBookRequestDTO {
     pub author_names: Vec<String>,
     pub isbn: Option<String>,
     pub description: Option<String>,
+    pub visibility: Option<BookVisibilityDTO>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBookCoOwnersRequestDTO {
+    pub user_ids: Vec<UserId>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeBookGroupRequestDTO {
+    pub group_id: Option<GroupId>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -24,3 +41,9 @@ pub struct UpdateBookRequestDTO {
 pub struct ChangeBookOwnerRequestDTO {
     pub new_owner_id: UserId,
 }
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutBookRequestDTO {
+    pub borrower_id: Option<UserId>,
+}
@@ -2,7 +2,11 @@ use chrono::{DateTime, Utc};
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::shared::{AuditDTO, AuditSummaryDTO, PaginationDTO, UserReferenceDTO};
+use crate::{
+    book::dto::BookVisibilityDTO,
+    group::dto::GroupReferenceDTO,
+    shared::{AuditDTO, AuditSummaryDTO, PaginationDTO, UserReferenceDTO},
+};
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -13,6 +17,9 @@ pub struct BookDetailsDTO {
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub owner: UserReferenceDTO,
+    pub co_owners: Vec<UserReferenceDTO>,
+    pub group: Option<GroupReferenceDTO>,
+    pub visibility: BookVisibilityDTO,
     pub checkout: Option<BookCheckoutDTO>,
     pub audit: AuditDTO,
 }
@@ -23,6 +30,7 @@ pub struct BookCheckoutDTO {
     pub checkout_id: Uuid,
     pub checked_out_at: DateTime<Utc>,
     pub checked_out_to: UserReferenceDTO,
+    pub checked_out_by: UserReferenceDTO,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -32,19 +40,43 @@ pub struct BookListItemDTO {
     pub title: String,
     pub authors: Vec<String>,
     pub owner: UserReferenceDTO,
+    pub visibility: BookVisibilityDTO,
     pub checked_out: bool,
     pub audit: AuditSummaryDTO,
 }
 
 pub type BookListResponseDTO = PaginationDTO<BookListItemDTO>;
 
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorSummaryDTO {
+    pub name: String,
+    pub book_count: u64,
+}
+
+pub type AuthorListResponseDTO = PaginationDTO<AuthorSummaryDTO>;
+
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BookCheckoutWithReturnDTO {
     pub checkout_id: Uuid,
     pub checked_out_at: DateTime<Utc>,
     pub checked_out_to: UserReferenceDTO,
+    pub checked_out_by: UserReferenceDTO,
     pub returned_at: Option<DateTime<Utc>>,
+    pub returned_by: Option<UserReferenceDTO>,
 }
 
 pub type CheckoutHistoryListDTO = PaginationDTO<BookCheckoutWithReturnDTO>;
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BookExportItemDTO {
+    pub id: Uuid,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub isbn: Option<String>,
+    pub description: Option<String>,
+    pub owner: UserReferenceDTO,
+    pub checked_out: bool,
+}
@@ -0,0 +1,179 @@
+use crate::{
+    book::dto::{BookExportFormatDTO, BookExportItemDTO},
+    shared::{
+        error::ApplicationError,
+        marc::{
+            MARCXML_FOOTER, MARCXML_HEADER, MarcBibliographic, MarcRecord, write_iso2709,
+            write_marcxml_record,
+        },
+    },
+};
+
+/// Renders the catalog export piece by piece so that it can be streamed.
+#[derive(Debug, Clone, Copy)]
+pub struct BookExportWriter {
+    format: BookExportFormatDTO,
+}
+
+impl BookExportWriter {
+    pub fn new(format: BookExportFormatDTO) -> Self {
+        Self { format }
+    }
+
+    pub fn header(&self) -> String {
+        match self.format {
+            BookExportFormatDTO::Csv => {
+                "id,title,authors,isbn,description,owner_id,owner_name,available\r\n".to_string()
+            }
+            BookExportFormatDTO::Json => "[".to_string(),
+            BookExportFormatDTO::Marcxml => MARCXML_HEADER.to_string(),
+            BookExportFormatDTO::Bibtex
+            | BookExportFormatDTO::Ris
+            | BookExportFormatDTO::Marc21 => String::new(),
+        }
+    }
+
+    /// `written` is the number of books already rendered, which JSON needs for its separators.
+    pub fn items(
+        &self,
+        items: &[BookExportItemDTO],
+        written: usize,
+    ) -> Result<String, ApplicationError> {
+        let mut output = String::new();
+        for (index, item) in items.iter().enumerate() {
+            match self.format {
+                BookExportFormatDTO::Csv => write_csv(&mut output, item)?,
+                BookExportFormatDTO::Json => {
+                    output.push_str(if written + index == 0 { "\n" } else { ",\n" });
+                    output.push_str(
+                        &serde_json::to_string(item)
+                            .map_err(|e| ApplicationError::InternalError(e.to_string()))?,
+                    );
+                }
+                BookExportFormatDTO::Bibtex => write_bibtex(&mut output, item),
+                BookExportFormatDTO::Ris => write_ris(&mut output, item),
+                BookExportFormatDTO::Marc21 => output.push_str(
+                    &write_iso2709(&to_marc(item))
+                        .map_err(|e| ApplicationError::InternalError(e.to_string()))?,
+                ),
+                BookExportFormatDTO::Marcxml => {
+                    output.push_str(&write_marcxml_record(&to_marc(item)))
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    pub fn footer(&self) -> String {
+        match self.format {
+            BookExportFormatDTO::Json => "\n]\n".to_string(),
+            BookExportFormatDTO::Marcxml => MARCXML_FOOTER.to_string(),
+            BookExportFormatDTO::Csv
+            | BookExportFormatDTO::Bibtex
+            | BookExportFormatDTO::Ris
+            | BookExportFormatDTO::Marc21 => String::new(),
+        }
+    }
+}
+
+fn availability(item: &BookExportItemDTO) -> &'static str {
+    match item.checked_out {
+        true => "checked out",
+        false => "available",
+    }
+}
+
+/// Owner and availability have no place in the bibliographic fields and are left out.
+fn to_marc(item: &BookExportItemDTO) -> MarcRecord {
+    MarcBibliographic {
+        control_number: Some(item.id.to_string()),
+        title: item.title.clone(),
+        authors: item.authors.clone(),
+        isbn: item.isbn.clone(),
+        description: item.description.clone(),
+    }
+    .to_record()
+}
+
+fn write_csv(output: &mut String, item: &BookExportItemDTO) -> Result<(), ApplicationError> {
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::CRLF)
+        .from_writer(vec![]);
+    writer
+        .write_record([
+            item.id.to_string().as_str(),
+            &item.title,
+            &item.authors.join("; "),
+            item.isbn.as_deref().unwrap_or_default(),
+            item.description.as_deref().unwrap_or_default(),
+            item.owner.id.to_string().as_str(),
+            &item.owner.name,
+            if item.checked_out { "false" } else { "true" },
+        ])
+        .map_err(|e| ApplicationError::InternalError(e.to_string()))?;
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| ApplicationError::InternalError(e.to_string()))?;
+
+    output.push_str(&String::from_utf8_lossy(&bytes));
+    Ok(())
+}
+
+fn write_bibtex(output: &mut String, item: &BookExportItemDTO) {
+    output.push_str(&format!("@book{{{},\n", item.id));
+
+    let mut field = |name: &str, value: &str| {
+        output.push_str(&format!("  {} = {{{}}},\n", name, escape_bibtex(value)));
+    };
+    field("title", &item.title);
+    field("author", &item.authors.join(" and "));
+    if let Some(isbn) = &item.isbn {
+        field("isbn", isbn);
+    }
+    if let Some(description) = &item.description {
+        field("abstract", description);
+    }
+    field("owner", &item.owner.name);
+    field("availability", availability(item));
+    output.push_str("}\n\n");
+}
+
+fn escape_bibtex(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '&' | '%' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn write_ris(output: &mut String, item: &BookExportItemDTO) {
+    // RIS is line based, so values must not span lines
+    let mut tag = |name: &str, value: &str| {
+        let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        output.push_str(&format!("{}  - {}\r\n", name, value));
+    };
+
+    tag("TY", "BOOK");
+    tag("ID", &item.id.to_string());
+    tag("TI", &item.title);
+    for author in &item.authors {
+        tag("AU", author);
+    }
+    if let Some(isbn) = &item.isbn {
+        tag("SN", isbn);
+    }
+    if let Some(description) = &item.description {
+        tag("AB", description);
+    }
+    tag("N1", &format!("Owner: {}", item.owner.name));
+    tag("N1", &format!("Availability: {}", availability(item)));
+    tag("ER", "");
+    output.push_str("\r\n");
+}
@@ -1,5 +1,10 @@
+use std::collections::HashSet;
+
 use async_trait::async_trait;
-use domain::{audit::Actor, shared::error::PersistenceError};
+use domain::{
+    audit::Actor, book::values::BookId, shared::error::PersistenceError, tenant::values::TenantId,
+};
+use uuid::Uuid;
 
 use crate::book::dto::*;
 
@@ -7,18 +12,45 @@ use crate::book::dto::*;
 pub trait BookQueryService: Send + Sync {
     async fn get_book_details(
         &self,
+        tenant: &TenantId,
         actor: Option<&Actor>,
         identity: BookIdentity,
     ) -> Result<Option<BookDetailsDTO>, PersistenceError>;
 
     async fn get_book_list(
         &self,
+        tenant: &TenantId,
         actor: Option<&Actor>,
         query: &BookListQueryDTO,
     ) -> Result<BookListResponseDTO, PersistenceError>;
 
+    /// Author names with the number of books each appears on, in name order.
+    async fn get_author_list(
+        &self,
+        tenant: &TenantId,
+        query: &AuthorListQueryDTO,
+    ) -> Result<AuthorListResponseDTO, PersistenceError>;
+
+    /// Returns up to `limit` books matching `filter`, ordered by id and starting after `after`.
+    async fn get_export_chunk(
+        &self,
+        tenant: &TenantId,
+        filter: &BookListFilterDTO,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<BookExportItemDTO>, PersistenceError>;
+
+    /// Returns those of `book_ids` in the actor's tenant that the actor may see, by the same
+    /// rules as the book list.
+    async fn get_visible_book_ids(
+        &self,
+        actor: &Actor,
+        book_ids: &[BookId],
+    ) -> Result<HashSet<BookId>, PersistenceError>;
+
     async fn get_checkout_history(
         &self,
+        tenant: &TenantId,
         identity: BookIdentity,
         query: &CheckoutHistoryQueryDTO,
     ) -> Result<CheckoutHistoryListDTO, PersistenceError>;
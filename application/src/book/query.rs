@@ -1,7 +1,11 @@
+mod export_books;
+mod get_author_list;
 mod get_book_details;
 mod get_book_list;
 mod get_checkout_history;
 
+pub use export_books::*;
+pub use get_author_list::*;
 pub use get_book_details::*;
 pub use get_book_list::*;
 pub use get_checkout_history::*;
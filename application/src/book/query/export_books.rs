@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::tenant::values::TenantId;
+use futures::{
+    StreamExt, TryStreamExt,
+    stream::{self, BoxStream},
+};
+use garde::Validate;
+use uuid::Uuid;
+
+use crate::{
+    book::{dto::BookExportQueryDTO, export::BookExportWriter, interface::BookQueryService},
+    shared::error::ApplicationError,
+};
+
+const EXPORT_CHUNK_SIZE: u64 = 500;
+
+pub type BookExportStream = BoxStream<'static, Result<String, ApplicationError>>;
+
+#[derive(new)]
+pub struct ExportBooksService {
+    book_query_service: Arc<dyn BookQueryService>,
+}
+
+struct ExportCursor {
+    after: Option<Uuid>,
+    written: usize,
+}
+
+impl ExportBooksService {
+    /// Streams the catalog in chunks so that only one chunk is held in memory at a time.
+    pub fn execute(
+        &self,
+        tenant: &TenantId,
+        query: &BookExportQueryDTO,
+    ) -> Result<BookExportStream, ApplicationError> {
+        query.validate()?;
+
+        let writer = BookExportWriter::new(query.format);
+        let filter = query.filter.clone();
+        let tenant = tenant.clone();
+        let book_query_service = self.book_query_service.clone();
+
+        let initial = Some(ExportCursor {
+            after: None,
+            written: 0,
+        });
+        let body = stream::try_unfold(initial, move |cursor| {
+            let filter = filter.clone();
+            let tenant = tenant.clone();
+            let book_query_service = book_query_service.clone();
+            async move {
+                let Some(cursor) = cursor else {
+                    return Ok(None);
+                };
+
+                let items = book_query_service
+                    .get_export_chunk(&tenant, &filter, cursor.after, EXPORT_CHUNK_SIZE)
+                    .await?;
+                if items.is_empty() {
+                    return Ok(None);
+                }
+
+                let chunk = writer.items(&items, cursor.written)?;
+                let next = (items.len() as u64 == EXPORT_CHUNK_SIZE).then(|| ExportCursor {
+                    after: items.last().map(|item| item.id),
+                    written: cursor.written + items.len(),
+                });
+
+                Ok::<_, ApplicationError>(Some((chunk, next)))
+            }
+        });
+
+        Ok(stream::once(async move { Ok(writer.header()) })
+            .chain(body)
+            .chain(stream::once(async move { Ok(writer.footer()) }))
+            .inspect_err(|err| tracing::error!(error = ?err, "Book export failed"))
+            .boxed())
+    }
+}
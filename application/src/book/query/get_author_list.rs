@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::tenant::values::TenantId;
+use garde::Validate;
+
+use crate::{
+    book::{
+        dto::{AuthorListQueryDTO, AuthorListResponseDTO},
+        interface::BookQueryService,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetAuthorListService {
+    book_query_service: Arc<dyn BookQueryService>,
+}
+
+impl GetAuthorListService {
+    pub async fn execute(
+        &self,
+        tenant: &TenantId,
+        query: &AuthorListQueryDTO,
+    ) -> Result<AuthorListResponseDTO, ApplicationError> {
+        query.validate()?;
+
+        self.book_query_service
+            .get_author_list(tenant, query)
+            .await
+            .map_err(|e| e.into())
+    }
+}
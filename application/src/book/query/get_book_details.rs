@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use derive_new::new;
-use domain::audit::Actor;
+use domain::{audit::Actor, tenant::values::TenantId};
 
 use crate::{
     book::{
@@ -17,13 +17,17 @@ pub struct GetBookDetailsService {
 }
 
 impl GetBookDetailsService {
+    /// `tenant` is the one the request was addressed to; signed-in users always see their own.
     pub async fn execute(
         &self,
+        tenant: &TenantId,
         actor: Option<&Actor>,
         identity: BookIdentity,
     ) -> Result<BookDetailsDTO, ApplicationError> {
+        let tenant = actor.map_or(tenant, Actor::tenant);
+
         self.book_query_service
-            .get_book_details(actor, identity)
+            .get_book_details(tenant, actor, identity)
             .await
             .map_err(|e| e.into())
             .and_then(|opt| opt.ok_or(ApplicationError::NotFound))
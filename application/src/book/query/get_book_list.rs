@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use derive_new::new;
-use domain::audit::Actor;
+use domain::{audit::Actor, tenant::values::TenantId};
 use garde::Validate;
 
 use crate::{
@@ -18,15 +18,19 @@ pub struct GetBookListService {
 }
 
 impl GetBookListService {
+    /// `tenant` is the one the request was addressed to; signed-in users always see their own.
     pub async fn execute(
         &self,
+        tenant: &TenantId,
         actor: Option<&Actor>,
         query: &BookListQueryDTO,
     ) -> Result<BookListResponseDTO, ApplicationError> {
         query.validate()?;
 
+        let tenant = actor.map_or(tenant, Actor::tenant);
+
         self.book_query_service
-            .get_book_list(actor, query)
+            .get_book_list(tenant, actor, query)
             .await
             .map_err(|e| e.into())
     }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use derive_new::new;
-use domain::audit::Actor;
+use domain::{audit::Actor, auth::capability::Capability};
 use garde::Validate;
 
 use crate::{
@@ -24,14 +24,14 @@ impl GetCheckoutHistoryService {
         identity: BookIdentity,
         query: &CheckoutHistoryQueryDTO,
     ) -> Result<CheckoutHistoryListDTO, ApplicationError> {
-        if !actor.is_admin() {
+        if !actor.has_capability(Capability::ViewAllHistory) {
             return Err(ApplicationError::Forbidden);
         }
 
         query.validate()?;
 
         self.book_query_service
-            .get_checkout_history(identity, query)
+            .get_checkout_history(actor.tenant(), identity, query)
             .await
             .map_err(|e| e.into())
     }
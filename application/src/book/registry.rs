@@ -1,45 +1,80 @@
 use std::sync::Arc;
 
-use domain::{audit::Clock, book::interface::BookRepository};
+use domain::{
+    audit::Clock, book::interface::BookRepository, group::interface::GroupRepository,
+    user::interface::UserDomainQueryService,
+};
 
 use crate::book::{command::*, interface::*, query::*};
 
 pub struct BookRegistry {
     create_book: Arc<CreateBookService>,
     update_book: Arc<UpdateBookService>,
+    update_book_co_owners: Arc<UpdateBookCoOwnersService>,
+    change_book_group: Arc<ChangeBookGroupService>,
     delete_book: Arc<DeleteBookService>,
     checkout_book: Arc<CheckoutBookService>,
     return_book: Arc<ReturnBookService>,
     get_book_details: Arc<GetBookDetailsService>,
     get_book_list: Arc<GetBookListService>,
+    get_author_list: Arc<GetAuthorListService>,
     get_checkout_history: Arc<GetCheckoutHistoryService>,
+    export_books: Arc<ExportBooksService>,
 }
 
 impl BookRegistry {
     pub fn new(
         repository: Arc<dyn BookRepository>,
         query_service: Arc<dyn BookQueryService>,
+        user_domain_query_service: Arc<dyn UserDomainQueryService>,
+        group_repository: Arc<dyn GroupRepository>,
         clock: Arc<dyn Clock>,
     ) -> Self {
-        let create_book = CreateBookService::new(clock.clone(), repository.clone());
+        let create_book = CreateBookService::new(
+            clock.clone(),
+            repository.clone(),
+            group_repository.clone(),
+            user_domain_query_service.clone(),
+        );
         let update_book = UpdateBookService::new(clock.clone(), repository.clone());
+        let update_book_co_owners = UpdateBookCoOwnersService::new(
+            clock.clone(),
+            repository.clone(),
+            user_domain_query_service.clone(),
+        );
+        let change_book_group = ChangeBookGroupService::new(
+            clock.clone(),
+            repository.clone(),
+            group_repository.clone(),
+        );
         let delete_book = DeleteBookService::new(clock.clone(), repository.clone());
-        let checkout_book = CheckoutBookService::new(clock.clone(), repository.clone());
+        let checkout_book = CheckoutBookService::new(
+            clock.clone(),
+            repository.clone(),
+            user_domain_query_service.clone(),
+            group_repository.clone(),
+        );
         let return_book = ReturnBookService::new(clock.clone(), repository.clone());
 
         let get_book_details = GetBookDetailsService::new(query_service.clone());
         let get_book_list = GetBookListService::new(query_service.clone());
+        let get_author_list = GetAuthorListService::new(query_service.clone());
         let get_checkout_history = GetCheckoutHistoryService::new(query_service.clone());
+        let export_books = ExportBooksService::new(query_service.clone());
 
         BookRegistry {
             create_book: Arc::new(create_book),
             update_book: Arc::new(update_book),
+            update_book_co_owners: Arc::new(update_book_co_owners),
+            change_book_group: Arc::new(change_book_group),
             delete_book: Arc::new(delete_book),
             checkout_book: Arc::new(checkout_book),
             return_book: Arc::new(return_book),
             get_book_details: Arc::new(get_book_details),
             get_book_list: Arc::new(get_book_list),
+            get_author_list: Arc::new(get_author_list),
             get_checkout_history: Arc::new(get_checkout_history),
+            export_books: Arc::new(export_books),
         }
     }
 
@@ -51,6 +86,14 @@ impl BookRegistry {
         self.update_book.clone()
     }
 
+    pub fn update_book_co_owners(&self) -> Arc<UpdateBookCoOwnersService> {
+        self.update_book_co_owners.clone()
+    }
+
+    pub fn change_book_group(&self) -> Arc<ChangeBookGroupService> {
+        self.change_book_group.clone()
+    }
+
     pub fn delete_book(&self) -> Arc<DeleteBookService> {
         self.delete_book.clone()
     }
@@ -71,7 +114,15 @@ impl BookRegistry {
         self.get_book_list.clone()
     }
 
+    pub fn get_author_list(&self) -> Arc<GetAuthorListService> {
+        self.get_author_list.clone()
+    }
+
     pub fn get_checkout_history(&self) -> Arc<GetCheckoutHistoryService> {
         self.get_checkout_history.clone()
     }
+
+    pub fn export_books(&self) -> Arc<ExportBooksService> {
+        self.export_books.clone()
+    }
 }
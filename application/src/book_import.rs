@@ -0,0 +1,8 @@
+pub mod command;
+pub mod dto;
+pub mod interface;
+mod parser;
+pub mod query;
+pub mod registry;
+
+pub use registry::BookImportRegistry;
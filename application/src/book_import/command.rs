@@ -0,0 +1,5 @@
+mod process_book_imports;
+mod start_book_import;
+
+pub use process_book_imports::*;
+pub use start_book_import::*;
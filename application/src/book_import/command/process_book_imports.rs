@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    book::{
+        entity::{Book, NewBook},
+        enums::BookVisibility,
+        interface::BookRepository,
+    },
+    user::interface::UserDomainQueryService,
+};
+
+use crate::{
+    book_import::{
+        command::start_book_import::duplicate_isbn_error,
+        dto::BookImportRowErrorDTO,
+        interface::{
+            BookImportProgress, BookImportQueryService, BookImportRepository, BookImportRow,
+        },
+        parser::to_values,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct ProcessBookImportsService {
+    clock: Arc<dyn Clock>,
+    repository: Arc<dyn BookImportRepository>,
+    query_service: Arc<dyn BookImportQueryService>,
+    book_repository: Arc<dyn BookRepository>,
+    user_domain_query_service: Arc<dyn UserDomainQueryService>,
+    batch_size: u64,
+    lease: Duration,
+}
+
+impl ProcessBookImportsService {
+    /// Creates the next batch of books of one import and returns how many rows were handled.
+    pub async fn execute(&self) -> Result<usize, ApplicationError> {
+        let now = self.clock.now();
+
+        let Some(import) = self.repository.claim_next(now, now + self.lease).await? else {
+            return Ok(0);
+        };
+
+        let mut progress = BookImportProgress {
+            processed_rows: import.processed_rows,
+            created_count: import.created_count,
+            errors: import.errors,
+        };
+
+        let Some(actor) = self
+            .user_domain_query_service
+            .find_actor_by_id(import.actor_id)
+            .await?
+        else {
+            progress.processed_rows = import.rows.len() as u32;
+            self.repository
+                .save_progress(import.id, &progress, Some(self.clock.now()))
+                .await?;
+            return Ok(0);
+        };
+
+        let batch: Vec<_> = import
+            .rows
+            .iter()
+            .skip(import.processed_rows as usize)
+            .take(self.batch_size as usize)
+            .collect();
+
+        let visibility = self
+            .user_domain_query_service
+            .find_default_book_visibility(actor.id())
+            .await?
+            .unwrap_or_default();
+
+        // Books may have been added since the file was validated
+        let isbns: Vec<String> = batch.iter().filter_map(|row| row.isbn.clone()).collect();
+        let existing = self
+            .query_service
+            .find_existing_isbns(actor.tenant(), &isbns)
+            .await?;
+
+        let mut handled = 0;
+        let mut failure = None;
+        for row in &batch {
+            match self.create_book(&actor, row, &existing, visibility).await {
+                Ok(()) => progress.created_count += 1,
+                Err(RowFailure::Rejected(error)) => progress.errors.push(error),
+                Err(RowFailure::Persistence(error)) => {
+                    failure = Some(error);
+                    break;
+                }
+            }
+            handled += 1;
+            progress.processed_rows += 1;
+
+            // Only ISBN rows are deduplicated, so a rerun of this row would create the book twice
+            self.repository
+                .record_progress(import.id, &progress)
+                .await?;
+        }
+
+        let finished = progress.processed_rows as usize >= import.rows.len();
+        self.repository
+            .save_progress(import.id, &progress, finished.then(|| self.clock.now()))
+            .await?;
+
+        match failure {
+            Some(error) => Err(error),
+            None => Ok(handled),
+        }
+    }
+
+    async fn create_book(
+        &self,
+        actor: &Actor,
+        row: &BookImportRow,
+        existing: &[String],
+        visibility: BookVisibility,
+    ) -> Result<(), RowFailure> {
+        if let Some(isbn) = row
+            .isbn
+            .as_deref()
+            .filter(|isbn| existing.iter().any(|e| e == isbn))
+        {
+            return Err(RowFailure::Rejected(duplicate_isbn_error(row.line, isbn)));
+        }
+
+        let values = to_values(row).map_err(RowFailure::Rejected)?;
+        let context = AuditContext::new(actor, self.clock.as_ref());
+        let mut book = Book::create_new(
+            &context,
+            NewBook {
+                title: values.title,
+                authors: values.authors,
+                isbn: values.isbn,
+                description: values.description,
+                owner: actor.into(),
+                group_id: None,
+                visibility,
+            },
+        )
+        .map_err(|e| {
+            RowFailure::Rejected(BookImportRowErrorDTO {
+                line: row.line,
+                message: e.to_string(),
+            })
+        })?;
+
+        self.book_repository
+            .save(&mut book)
+            .await
+            .map_err(|e| RowFailure::Persistence(e.into()))
+    }
+}
+
+enum RowFailure {
+    Rejected(BookImportRowErrorDTO),
+    Persistence(ApplicationError),
+}
@@ -0,0 +1,123 @@
+use std::{collections::HashMap, sync::Arc};
+
+use derive_new::new;
+use domain::audit::{Actor, Clock};
+use garde::Validate;
+use uuid::Uuid;
+
+use crate::{
+    book_import::{
+        dto::{BookImportDTO, BookImportQueryDTO, BookImportRowErrorDTO, BookImportStatusDTO},
+        interface::{BookImportQueryService, BookImportRepository, BookImportRow, NewBookImport},
+        parser::{parse_rows, to_values},
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct StartBookImportService {
+    clock: Arc<dyn Clock>,
+    repository: Arc<dyn BookImportRepository>,
+    query_service: Arc<dyn BookImportQueryService>,
+}
+
+impl StartBookImportService {
+    /// Validates every row up front. Unless `dry_run` is set, the accepted rows are queued
+    /// for the import worker and the returned import can be polled for progress.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        query: &BookImportQueryDTO,
+        body: &str,
+    ) -> Result<BookImportDTO, ApplicationError> {
+        query.validate()?;
+
+        let parsed = parse_rows(body, query)?;
+        let total_rows = parsed.len() as u32;
+
+        let mut errors = vec![];
+        let mut accepted: Vec<BookImportRow> = vec![];
+        let mut seen_isbns: HashMap<String, u64> = HashMap::new();
+        for row in parsed {
+            let row = match row.and_then(|row| to_values(&row).map(|_| row)) {
+                Ok(row) => row,
+                Err(error) => {
+                    errors.push(error);
+                    continue;
+                }
+            };
+
+            if let Some(isbn) = &row.isbn {
+                if let Some(first_line) = seen_isbns.get(isbn) {
+                    errors.push(BookImportRowErrorDTO {
+                        line: row.line,
+                        message: format!("ISBN {} already appears on line {}", isbn, first_line),
+                    });
+                    continue;
+                }
+                seen_isbns.insert(isbn.clone(), row.line);
+            }
+            accepted.push(row);
+        }
+
+        let isbns: Vec<String> = seen_isbns.into_keys().collect();
+        let existing = self
+            .query_service
+            .find_existing_isbns(actor.tenant(), &isbns)
+            .await?;
+        accepted.retain(|row| match &row.isbn {
+            Some(isbn) if existing.contains(isbn) => {
+                errors.push(duplicate_isbn_error(row.line, isbn));
+                false
+            }
+            _ => true,
+        });
+        errors.sort_by_key(|error| error.line);
+
+        let accepted_rows = accepted.len() as u32;
+        if query.dry_run {
+            return Ok(BookImportDTO {
+                id: None,
+                status: BookImportStatusDTO::Validated,
+                requested_by_id: actor.raw_id(),
+                total_rows,
+                accepted_rows,
+                processed_rows: 0,
+                created_count: 0,
+                errors,
+                created_at: None,
+                finished_at: None,
+            });
+        }
+
+        let import = NewBookImport {
+            id: Uuid::new_v4(),
+            actor_id: actor.id(),
+            total_rows,
+            rows: accepted,
+            errors,
+            created_at: self.clock.now(),
+        };
+        self.repository.create(&import).await?;
+
+        Ok(BookImportDTO {
+            id: Some(import.id),
+            status: BookImportStatusDTO::Pending,
+            requested_by_id: actor.raw_id(),
+            total_rows,
+            accepted_rows,
+            processed_rows: 0,
+            created_count: 0,
+            errors: import.errors,
+            created_at: Some(import.created_at),
+            finished_at: None,
+        })
+    }
+}
+
+pub(super) fn duplicate_isbn_error(line: u64, isbn: &str) -> BookImportRowErrorDTO {
+    BookImportRowErrorDTO {
+        line,
+        message: format!("A book with ISBN {} already exists", isbn),
+    }
+}
@@ -0,0 +1,9 @@
+mod enums;
+mod identity;
+mod query;
+mod response;
+
+pub use enums::*;
+pub use identity::*;
+pub use query::*;
+pub use response::*;
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumString};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BookImportFormatDTO {
+    Csv,
+    Jsonl,
+    Marc21,
+    Marcxml,
+}
+
+#[derive(
+    Debug,
+    EnumString,
+    AsRefStr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BookImportStatusDTO {
+    Validated,
+    Pending,
+    Running,
+    Completed,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct BookImportIdentity {
+    pub import_id: Uuid,
+}
@@ -0,0 +1,49 @@
+use garde::Validate;
+use serde::Deserialize;
+
+use crate::book_import::dto::BookImportFormatDTO;
+
+#[derive(Debug, Deserialize, Validate, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BookImportQueryDTO {
+    #[garde(skip)]
+    pub format: BookImportFormatDTO,
+    #[garde(skip)]
+    #[serde(default)]
+    pub dry_run: bool,
+    #[garde(length(min = 1))]
+    #[serde(default = "default_title_column")]
+    pub title_column: String,
+    #[garde(length(min = 1))]
+    #[serde(default = "default_authors_column")]
+    pub authors_column: String,
+    #[garde(length(min = 1))]
+    #[serde(default = "default_isbn_column")]
+    pub isbn_column: String,
+    #[garde(length(min = 1))]
+    #[serde(default = "default_description_column")]
+    pub description_column: String,
+    #[garde(length(min = 1))]
+    #[serde(default = "default_author_separator")]
+    pub author_separator: String,
+}
+
+fn default_title_column() -> String {
+    "title".to_string()
+}
+
+fn default_authors_column() -> String {
+    "authors".to_string()
+}
+
+fn default_isbn_column() -> String {
+    "isbn".to_string()
+}
+
+fn default_description_column() -> String {
+    "description".to_string()
+}
+
+fn default_author_separator() -> String {
+    ";".to_string()
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::book_import::dto::BookImportStatusDTO;
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BookImportDTO {
+    pub id: Option<Uuid>,
+    pub status: BookImportStatusDTO,
+    pub requested_by_id: Uuid,
+    pub total_rows: u32,
+    pub accepted_rows: u32,
+    pub processed_rows: u32,
+    pub created_count: u32,
+    pub errors: Vec<BookImportRowErrorDTO>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BookImportRowErrorDTO {
+    pub line: u64,
+    pub message: String,
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{shared::error::PersistenceError, tenant::values::TenantId, user::values::UserId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::book_import::dto::*;
+
+/// A parsed row of an import file, before validation by the value objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookImportRow {
+    pub line: u64,
+    pub title: String,
+    pub author_names: Vec<String>,
+    pub isbn: Option<String>,
+    pub description: Option<String>,
+}
+
+pub struct NewBookImport {
+    pub id: Uuid,
+    pub actor_id: UserId,
+    pub total_rows: u32,
+    pub rows: Vec<BookImportRow>,
+    pub errors: Vec<BookImportRowErrorDTO>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct ClaimedBookImport {
+    pub id: Uuid,
+    pub actor_id: UserId,
+    pub rows: Vec<BookImportRow>,
+    pub processed_rows: u32,
+    pub created_count: u32,
+    pub errors: Vec<BookImportRowErrorDTO>,
+}
+
+pub struct BookImportProgress {
+    pub processed_rows: u32,
+    pub created_count: u32,
+    pub errors: Vec<BookImportRowErrorDTO>,
+}
+
+#[async_trait]
+pub trait BookImportRepository: Send + Sync {
+    async fn create(&self, import: &NewBookImport) -> Result<(), PersistenceError>;
+
+    /// Claims the oldest unfinished import that no other worker holds, until `lease_until`.
+    async fn claim_next(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Option<ClaimedBookImport>, PersistenceError>;
+
+    /// Stores progress after a single row while keeping the claim, so that a worker that dies
+    /// mid-batch resumes after the last row it handled.
+    async fn record_progress(
+        &self,
+        id: Uuid,
+        progress: &BookImportProgress,
+    ) -> Result<(), PersistenceError>;
+
+    /// Stores progress and releases the claim; `finished_at` marks the import completed.
+    async fn save_progress(
+        &self,
+        id: Uuid,
+        progress: &BookImportProgress,
+        finished_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError>;
+}
+
+#[async_trait]
+pub trait BookImportQueryService: Send + Sync {
+    async fn get_import(
+        &self,
+        tenant: &TenantId,
+        identity: BookImportIdentity,
+    ) -> Result<Option<BookImportDTO>, PersistenceError>;
+
+    /// Returns those of `isbns` that already belong to a book of `tenant`.
+    async fn find_existing_isbns(
+        &self,
+        tenant: &TenantId,
+        isbns: &[String],
+    ) -> Result<Vec<String>, PersistenceError>;
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use domain::{
+    book::values::{BookAuthorList, BookDescription, BookIsbn, BookTitle},
+    shared::error::DomainError,
+};
+
+use crate::{
+    book::dto::CreateBookRequestDTO,
+    book_import::{
+        dto::{BookImportFormatDTO, BookImportQueryDTO, BookImportRowErrorDTO},
+        interface::BookImportRow,
+    },
+    shared::{
+        error::ApplicationError,
+        marc::{MarcBibliographic, MarcRecord, read_iso2709, read_marcxml},
+    },
+};
+
+pub type ParsedRow = Result<BookImportRow, BookImportRowErrorDTO>;
+
+/// Splits an uploaded file into rows. Errors that concern a single row are returned in
+/// place of that row; only an unusable file as a whole fails the call.
+pub fn parse_rows(
+    body: &str,
+    query: &BookImportQueryDTO,
+) -> Result<Vec<ParsedRow>, ApplicationError> {
+    match query.format {
+        BookImportFormatDTO::Csv => parse_csv(body, query),
+        BookImportFormatDTO::Jsonl => Ok(parse_jsonl(body)),
+        BookImportFormatDTO::Marc21 => Ok(parse_marc21(body)),
+        BookImportFormatDTO::Marcxml => parse_marcxml(body),
+    }
+}
+
+fn parse_csv(body: &str, query: &BookImportQueryDTO) -> Result<Vec<ParsedRow>, ApplicationError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(body.as_bytes());
+
+    let headers: HashMap<String, usize> = reader
+        .headers()
+        .map_err(|e| invalid_file(format!("Cannot read CSV header: {}", e)))?
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (name.to_string(), index))
+        .collect();
+
+    let column = |name: &str| headers.get(name).copied();
+    let title_column = column(&query.title_column)
+        .ok_or_else(|| invalid_file(format!("CSV has no '{}' column", query.title_column)))?;
+    let authors_column = column(&query.authors_column)
+        .ok_or_else(|| invalid_file(format!("CSV has no '{}' column", query.authors_column)))?;
+    let isbn_column = column(&query.isbn_column);
+    let description_column = column(&query.description_column);
+
+    Ok(reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|e| BookImportRowErrorDTO {
+                line: e.position().map(|p| p.line()).unwrap_or_default(),
+                message: e.to_string(),
+            })?;
+            let cell = |index: Option<usize>| {
+                index
+                    .and_then(|i| record.get(i))
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+            };
+
+            Ok(BookImportRow {
+                line: record.position().map(|p| p.line()).unwrap_or_default(),
+                title: cell(Some(title_column)).unwrap_or_default(),
+                author_names: cell(Some(authors_column))
+                    .map(|authors| {
+                        authors
+                            .split(query.author_separator.as_str())
+                            .map(str::trim)
+                            .filter(|name| !name.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                isbn: cell(isbn_column).map(normalize_isbn),
+                description: cell(description_column),
+            })
+        })
+        .collect())
+}
+
+fn parse_jsonl(body: &str) -> Vec<ParsedRow> {
+    body.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let line_number = index as u64 + 1;
+            let request: CreateBookRequestDTO =
+                serde_json::from_str(line).map_err(|e| BookImportRowErrorDTO {
+                    line: line_number,
+                    message: e.to_string(),
+                })?;
+
+            Ok(BookImportRow {
+                line: line_number,
+                title: request.title,
+                author_names: request.author_names,
+                isbn: request.isbn.map(|isbn| normalize_isbn(&isbn)),
+                description: request.description,
+            })
+        })
+        .collect()
+}
+
+/// MARC files have no meaningful lines, so rows are numbered by record instead.
+fn parse_marc21(body: &str) -> Vec<ParsedRow> {
+    read_iso2709(body)
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| {
+            let line = index as u64 + 1;
+            record
+                .map(|record| from_marc(line, &record))
+                .map_err(|e| BookImportRowErrorDTO {
+                    line,
+                    message: e.to_string(),
+                })
+        })
+        .collect()
+}
+
+fn parse_marcxml(body: &str) -> Result<Vec<ParsedRow>, ApplicationError> {
+    let records = read_marcxml(body).map_err(|e| invalid_file(e.to_string()))?;
+
+    Ok(records
+        .iter()
+        .enumerate()
+        .map(|(index, record)| Ok(from_marc(index as u64 + 1, record)))
+        .collect())
+}
+
+fn from_marc(line: u64, record: &MarcRecord) -> BookImportRow {
+    let book = MarcBibliographic::from_record(record);
+    BookImportRow {
+        line,
+        title: book.title,
+        author_names: book.authors,
+        isbn: book.isbn.map(normalize_isbn),
+        description: book.description,
+    }
+}
+
+/// Spreadsheets usually carry ISBNs with hyphens or spaces.
+fn normalize_isbn(isbn: impl AsRef<str>) -> String {
+    isbn.as_ref()
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .collect()
+}
+
+fn invalid_file(message: String) -> ApplicationError {
+    DomainError::ValidationError(message).into()
+}
+
+pub struct BookImportValues {
+    pub title: BookTitle,
+    pub authors: BookAuthorList,
+    pub isbn: BookIsbn,
+    pub description: BookDescription,
+}
+
+/// Runs a row through the same value objects `CreateBookService` uses.
+pub fn to_values(row: &BookImportRow) -> Result<BookImportValues, BookImportRowErrorDTO> {
+    try_to_values(row).map_err(|e| BookImportRowErrorDTO {
+        line: row.line,
+        message: e.to_string(),
+    })
+}
+
+fn try_to_values(row: &BookImportRow) -> Result<BookImportValues, DomainError> {
+    Ok(BookImportValues {
+        title: row.title.clone().try_into()?,
+        authors: row.author_names.clone().try_into()?,
+        isbn: row.isbn.clone().try_into()?,
+        description: row.description.clone().try_into()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(query_string: &str) -> BookImportQueryDTO {
+        serde_urlencoded::from_str(query_string).unwrap()
+    }
+
+    fn rows(body: &str, query_string: &str) -> Vec<ParsedRow> {
+        parse_rows(body, &query(query_string)).unwrap()
+    }
+
+    #[test]
+    fn csv_rows_follow_the_configured_columns() {
+        let body = "Name,Writers,Code\n\
+                    Dune,Frank Herbert,978-0-441-17271-9\n\
+                    Good Omens,Terry Pratchett | Neil Gaiman,\n";
+        let rows = rows(
+            body,
+            "format=csv&title_column=Name&authors_column=Writers&isbn_column=Code&author_separator=|",
+        );
+
+        let first = rows[0].as_ref().unwrap();
+        assert_eq!(first.line, 2);
+        assert_eq!(first.title, "Dune");
+        assert_eq!(first.isbn.as_deref(), Some("9780441172719"));
+        assert_eq!(first.description, None);
+
+        let second = rows[1].as_ref().unwrap();
+        assert_eq!(second.line, 3);
+        assert_eq!(second.author_names, vec!["Terry Pratchett", "Neil Gaiman"]);
+        assert_eq!(second.isbn, None);
+    }
+
+    #[test]
+    fn csv_without_the_title_column_is_rejected_as_a_whole() {
+        let result = parse_rows("name,authors\nDune,Frank Herbert\n", &query("format=csv"));
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::DomainError(DomainError::ValidationError(message)))
+                if message == "CSV has no 'title' column"
+        ));
+    }
+
+    #[test]
+    fn malformed_jsonl_lines_are_reported_in_place() {
+        let body = "{\"title\":\"Dune\",\"authorNames\":[\"Frank Herbert\"]}\n\
+                    \n\
+                    not json\n";
+        let rows = rows(body, "format=jsonl");
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].as_ref().unwrap().title, "Dune");
+        assert_eq!(rows[1].as_ref().unwrap_err().line, 3);
+    }
+
+    #[test]
+    fn rows_are_checked_by_the_book_value_objects() {
+        let row = BookImportRow {
+            line: 4,
+            title: "Dune".into(),
+            author_names: vec!["Frank Herbert".into()],
+            isbn: Some("12345".into()),
+            description: None,
+        };
+
+        let error = to_values(&row).err().unwrap();
+        assert_eq!(error.line, 4);
+        assert!(error.message.contains("ISBN"));
+
+        let valid = BookImportRow {
+            isbn: Some("9780441172719".into()),
+            ..row
+        };
+        assert!(to_values(&valid).is_ok());
+    }
+}
@@ -0,0 +1,3 @@
+mod get_book_import;
+
+pub use get_book_import::*;
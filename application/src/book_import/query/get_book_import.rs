@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    book_import::{
+        dto::{BookImportDTO, BookImportIdentity},
+        interface::BookImportQueryService,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetBookImportService {
+    query_service: Arc<dyn BookImportQueryService>,
+}
+
+impl GetBookImportService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: BookImportIdentity,
+    ) -> Result<BookImportDTO, ApplicationError> {
+        let import = self
+            .query_service
+            .get_import(actor.tenant(), identity)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        if import.requested_by_id != actor.raw_id() && !actor.is_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        Ok(import)
+    }
+}
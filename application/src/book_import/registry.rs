@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use domain::{
+    audit::Clock, book::interface::BookRepository, user::interface::UserDomainQueryService,
+};
+
+use crate::book_import::{command::*, interface::*, query::*};
+
+pub struct BookImportRegistry {
+    start_book_import: Arc<StartBookImportService>,
+    process_book_imports: Arc<ProcessBookImportsService>,
+    get_book_import: Arc<GetBookImportService>,
+}
+
+impl BookImportRegistry {
+    pub fn new(
+        repository: Arc<dyn BookImportRepository>,
+        query_service: Arc<dyn BookImportQueryService>,
+        book_repository: Arc<dyn BookRepository>,
+        user_domain_query_service: Arc<dyn UserDomainQueryService>,
+        batch_size: u64,
+        lease: Duration,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let start_book_import =
+            StartBookImportService::new(clock.clone(), repository.clone(), query_service.clone());
+        let process_book_imports = ProcessBookImportsService::new(
+            clock.clone(),
+            repository.clone(),
+            query_service.clone(),
+            book_repository,
+            user_domain_query_service,
+            batch_size,
+            lease,
+        );
+
+        let get_book_import = GetBookImportService::new(query_service.clone());
+
+        BookImportRegistry {
+            start_book_import: Arc::new(start_book_import),
+            process_book_imports: Arc::new(process_book_imports),
+            get_book_import: Arc::new(get_book_import),
+        }
+    }
+
+    pub fn start_book_import(&self) -> Arc<StartBookImportService> {
+        self.start_book_import.clone()
+    }
+
+    pub fn process_book_imports(&self) -> Arc<ProcessBookImportsService> {
+        self.process_book_imports.clone()
+    }
+
+    pub fn get_book_import(&self) -> Arc<GetBookImportService> {
+        self.get_book_import.clone()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod interface;
+pub mod query;
+pub mod registry;
+
+pub use registry::EventRegistry;
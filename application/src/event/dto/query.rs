@@ -0,0 +1,45 @@
+use domain::{
+    book::values::BookId,
+    event::{DomainEvent, DomainEventKind},
+    user::values::UserId,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct EventStreamQueryDTO {
+    pub book_id: Option<BookId>,
+    pub owner_id: Option<UserId>,
+}
+
+impl EventStreamQueryDTO {
+    /// Only book events are streamed; the filters narrow them further.
+    pub fn matches(&self, event: &DomainEvent) -> bool {
+        let (book_id, owner_ids) = match event.kind() {
+            DomainEventKind::BookCreated {
+                book_id, owner_id, ..
+            }
+            | DomainEventKind::BookUpdated {
+                book_id, owner_id, ..
+            }
+            | DomainEventKind::BookCheckedOut {
+                book_id, owner_id, ..
+            }
+            | DomainEventKind::BookReturned {
+                book_id, owner_id, ..
+            } => (*book_id, vec![*owner_id]),
+            DomainEventKind::OwnerChanged {
+                book_id,
+                previous_owner_id,
+                new_owner_id,
+            } => (*book_id, vec![*previous_owner_id, *new_owner_id]),
+            DomainEventKind::CoOwnersChanged {
+                book_id, owner_id, ..
+            } => (*book_id, vec![*owner_id]),
+            DomainEventKind::UserRoleChanged { .. } => return false,
+        };
+
+        self.book_id.is_none_or(|id| id == book_id)
+            && self.owner_id.is_none_or(|id| owner_ids.contains(&id))
+    }
+}
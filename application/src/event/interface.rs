@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use domain::{event::DomainEvent, shared::error::PersistenceError};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait EventQueryService: Send + Sync {
+    /// Returns the events recorded after `last_event_id`, or `None` if that event is unknown.
+    async fn get_events_after(
+        &self,
+        last_event_id: Uuid,
+        limit: u64,
+    ) -> Result<Option<Vec<DomainEvent>>, PersistenceError>;
+}
@@ -0,0 +1,5 @@
+mod filter_visible_events;
+mod replay_events;
+
+pub use filter_visible_events::*;
+pub use replay_events::*;
@@ -0,0 +1,43 @@
+use std::{borrow::Borrow, sync::Arc};
+
+use derive_new::new;
+use domain::{audit::Actor, event::DomainEvent};
+use itertools::Itertools;
+
+use crate::{book::interface::BookQueryService, shared::error::ApplicationError};
+
+#[derive(new)]
+pub struct FilterVisibleEventsService {
+    book_query_service: Arc<dyn BookQueryService>,
+}
+
+impl FilterVisibleEventsService {
+    /// Keeps the book events whose book the actor may currently see, by the same visibility and
+    /// team library rules as the book list. Events about deleted books are dropped.
+    pub async fn execute<E: Borrow<DomainEvent>>(
+        &self,
+        actor: &Actor,
+        events: Vec<E>,
+    ) -> Result<Vec<E>, ApplicationError> {
+        let book_ids = events
+            .iter()
+            .filter_map(|event| event.borrow().kind().book_id())
+            .unique()
+            .collect_vec();
+        let visible = self
+            .book_query_service
+            .get_visible_book_ids(actor, &book_ids)
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .filter(|event| {
+                event
+                    .borrow()
+                    .kind()
+                    .book_id()
+                    .is_some_and(|book_id| visible.contains(&book_id))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{audit::Actor, event::DomainEvent};
+use uuid::Uuid;
+
+use crate::{
+    event::{
+        dto::EventStreamQueryDTO, interface::EventQueryService, query::FilterVisibleEventsService,
+    },
+    shared::error::ApplicationError,
+};
+
+const MAX_REPLAYED_EVENTS: u64 = 1000;
+
+#[derive(new)]
+pub struct ReplayEventsService {
+    event_query_service: Arc<dyn EventQueryService>,
+    filter_visible_events: Arc<FilterVisibleEventsService>,
+}
+
+impl ReplayEventsService {
+    /// Events of the actor's tenant missed since `last_event_id`, limited to books the actor may
+    /// see; an unknown id replays nothing.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        last_event_id: Uuid,
+        query: &EventStreamQueryDTO,
+    ) -> Result<Vec<DomainEvent>, ApplicationError> {
+        let events = self
+            .event_query_service
+            .get_events_after(last_event_id, MAX_REPLAYED_EVENTS)
+            .await?
+            .unwrap_or_default();
+
+        let events = events
+            .into_iter()
+            .filter(|event| event.tenant_id() == actor.tenant() && query.matches(event))
+            .collect();
+
+        self.filter_visible_events.execute(actor, events).await
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::{
+    book::interface::BookQueryService,
+    event::{interface::*, query::*},
+};
+
+pub struct EventRegistry {
+    replay_events: Arc<ReplayEventsService>,
+    filter_visible_events: Arc<FilterVisibleEventsService>,
+}
+
+impl EventRegistry {
+    pub fn new(
+        query_service: Arc<dyn EventQueryService>,
+        book_query_service: Arc<dyn BookQueryService>,
+    ) -> Self {
+        let filter_visible_events = Arc::new(FilterVisibleEventsService::new(book_query_service));
+        let replay_events =
+            ReplayEventsService::new(query_service.clone(), filter_visible_events.clone());
+
+        EventRegistry {
+            replay_events: Arc::new(replay_events),
+            filter_visible_events,
+        }
+    }
+
+    pub fn replay_events(&self) -> Arc<ReplayEventsService> {
+        self.replay_events.clone()
+    }
+
+    pub fn filter_visible_events(&self) -> Arc<FilterVisibleEventsService> {
+        self.filter_visible_events.clone()
+    }
+}
@@ -0,0 +1,11 @@
+mod create_group;
+mod delete_group;
+mod remove_group_member;
+mod update_group;
+mod update_group_member;
+
+pub use create_group::*;
+pub use delete_group::*;
+pub use remove_group_member::*;
+pub use update_group::*;
+pub use update_group_member::*;
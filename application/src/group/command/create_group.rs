@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    group::{entity::Group, interface::GroupRepository},
+};
+
+use crate::{
+    group::dto::CreateGroupRequestDTO,
+    shared::{EntityCreationDTO, error::ApplicationError},
+};
+
+#[derive(new)]
+pub struct CreateGroupService {
+    clock: Arc<dyn Clock>,
+    group_repository: Arc<dyn GroupRepository>,
+}
+
+impl CreateGroupService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        request: &CreateGroupRequestDTO,
+    ) -> Result<EntityCreationDTO, ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut group = Group::create_new(
+            &context,
+            request.name.clone().try_into()?,
+            request.private_library,
+        )?;
+
+        self.group_repository.save(&mut group).await?;
+
+        Ok(group.audit().into())
+    }
+}
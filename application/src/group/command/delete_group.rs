@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    group::interface::GroupRepository,
+};
+
+use crate::{group::dto::GroupIdentity, shared::error::ApplicationError};
+
+#[derive(new)]
+pub struct DeleteGroupService {
+    clock: Arc<dyn Clock>,
+    group_repository: Arc<dyn GroupRepository>,
+}
+
+impl DeleteGroupService {
+    /// Books in the group's library stay with their owners.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: GroupIdentity,
+        expected_version: Option<u32>,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let group = self
+            .group_repository
+            .find_by_id(identity.group_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        group.audit().ensure_version(expected_version)?;
+
+        group.validate_deletion(&context)?;
+
+        self.group_repository.delete(&group).await?;
+
+        Ok(())
+    }
+}
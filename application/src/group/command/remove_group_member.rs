@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    group::interface::GroupRepository,
+};
+
+use crate::{group::dto::GroupMemberIdentity, shared::error::ApplicationError};
+
+#[derive(new)]
+pub struct RemoveGroupMemberService {
+    clock: Arc<dyn Clock>,
+    group_repository: Arc<dyn GroupRepository>,
+}
+
+impl RemoveGroupMemberService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: GroupMemberIdentity,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut group = self
+            .group_repository
+            .find_by_id(identity.group_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        group.remove_member(&context, identity.user_id)?;
+
+        self.group_repository.save(&mut group).await?;
+
+        Ok(())
+    }
+}
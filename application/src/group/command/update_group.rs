@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    group::interface::GroupRepository,
+};
+
+use crate::{
+    group::dto::{GroupIdentity, UpdateGroupRequestDTO},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct UpdateGroupService {
+    clock: Arc<dyn Clock>,
+    group_repository: Arc<dyn GroupRepository>,
+}
+
+impl UpdateGroupService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: GroupIdentity,
+        expected_version: Option<u32>,
+        request: &UpdateGroupRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut group = self
+            .group_repository
+            .find_by_id(identity.group_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        group.audit().ensure_version(expected_version)?;
+
+        group.update(
+            &context,
+            request.name.clone().try_into()?,
+            request.private_library,
+        )?;
+
+        self.group_repository.save(&mut group).await?;
+
+        Ok(())
+    }
+}
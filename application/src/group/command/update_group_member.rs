@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    group::{interface::GroupRepository, values::GroupMember},
+    shared::error::DomainError,
+    user::interface::UserDomainQueryService,
+};
+
+use crate::{
+    group::dto::{GroupMemberIdentity, UpdateGroupMemberRequestDTO},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct UpdateGroupMemberService {
+    clock: Arc<dyn Clock>,
+    group_repository: Arc<dyn GroupRepository>,
+    user_domain_query_service: Arc<dyn UserDomainQueryService>,
+}
+
+impl UpdateGroupMemberService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: GroupMemberIdentity,
+        request: &UpdateGroupMemberRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let user = self
+            .user_domain_query_service
+            .find_actor_by_id(identity.user_id)
+            .await?
+            .filter(|user| user.tenant() == actor.tenant())
+            .ok_or(DomainError::ValidationError(
+                "User does not exist".to_string(),
+            ))?;
+
+        let mut group = self
+            .group_repository
+            .find_by_id(identity.group_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        group.upsert_member(
+            &context,
+            GroupMember::new((&user).into(), request.role.into()),
+        )?;
+
+        self.group_repository.save(&mut group).await?;
+
+        Ok(())
+    }
+}
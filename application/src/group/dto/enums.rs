@@ -0,0 +1,32 @@
+use domain::group::enums::GroupMemberRole;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
+
+#[derive(
+    Debug,
+    EnumString,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(ascii_case_insensitive)]
+pub enum GroupMemberRoleDTO {
+    Admin,
+    #[default]
+    Member,
+}
+
+impl From<GroupMemberRoleDTO> for GroupMemberRole {
+    fn from(dto: GroupMemberRoleDTO) -> Self {
+        match dto {
+            GroupMemberRoleDTO::Admin => GroupMemberRole::Admin,
+            GroupMemberRoleDTO::Member => GroupMemberRole::Member,
+        }
+    }
+}
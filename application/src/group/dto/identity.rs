@@ -0,0 +1,15 @@
+use domain::{group::values::GroupId, user::values::UserId};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct GroupIdentity {
+    pub group_id: GroupId,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct GroupMemberIdentity {
+    pub group_id: GroupId,
+    pub user_id: UserId,
+}
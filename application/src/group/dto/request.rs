@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use crate::group::dto::GroupMemberRoleDTO;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateGroupRequestDTO {
+    pub name: String,
+    #[serde(default)]
+    pub private_library: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGroupRequestDTO {
+    pub name: String,
+    pub private_library: bool,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGroupMemberRequestDTO {
+    #[serde(default)]
+    pub role: GroupMemberRoleDTO,
+}
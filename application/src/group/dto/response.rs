@@ -0,0 +1,40 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    group::dto::GroupMemberRoleDTO,
+    shared::{AuditDTO, UserReferenceDTO},
+};
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupDetailsDTO {
+    pub id: Uuid,
+    pub name: String,
+    pub private_library: bool,
+    pub members: Vec<GroupMemberDTO>,
+    pub audit: AuditDTO,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberDTO {
+    pub user: UserReferenceDTO,
+    pub role: GroupMemberRoleDTO,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupSummaryDTO {
+    pub id: Uuid,
+    pub name: String,
+    pub private_library: bool,
+    pub member_count: u64,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupReferenceDTO {
+    pub id: Uuid,
+    pub name: String,
+}
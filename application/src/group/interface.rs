@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use domain::{audit::Actor, shared::error::PersistenceError};
+
+use crate::group::dto::*;
+
+#[async_trait]
+pub trait GroupQueryService: Send + Sync {
+    async fn get_group_details(
+        &self,
+        actor: &Actor,
+        identity: GroupIdentity,
+    ) -> Result<Option<GroupDetailsDTO>, PersistenceError>;
+
+    /// Groups the actor belongs to, or every group for admins, in name order.
+    async fn get_group_list(&self, actor: &Actor)
+    -> Result<Vec<GroupSummaryDTO>, PersistenceError>;
+}
@@ -0,0 +1,5 @@
+mod get_group_details;
+mod get_group_list;
+
+pub use get_group_details::*;
+pub use get_group_list::*;
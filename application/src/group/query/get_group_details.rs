@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    group::{
+        dto::{GroupDetailsDTO, GroupIdentity},
+        interface::GroupQueryService,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetGroupDetailsService {
+    group_query_service: Arc<dyn GroupQueryService>,
+}
+
+impl GetGroupDetailsService {
+    /// A group with a private library is hidden from everyone but its members and admins.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: GroupIdentity,
+    ) -> Result<GroupDetailsDTO, ApplicationError> {
+        let group = self
+            .group_query_service
+            .get_group_details(actor, identity)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        match group.private_library && !actor.is_admin() && !actor.is_member_of(identity.group_id) {
+            true => Err(ApplicationError::NotFound),
+            false => Ok(group),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    group::{dto::GroupSummaryDTO, interface::GroupQueryService},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetGroupListService {
+    group_query_service: Arc<dyn GroupQueryService>,
+}
+
+impl GetGroupListService {
+    pub async fn execute(&self, actor: &Actor) -> Result<Vec<GroupSummaryDTO>, ApplicationError> {
+        self.group_query_service
+            .get_group_list(actor)
+            .await
+            .map_err(|e| e.into())
+    }
+}
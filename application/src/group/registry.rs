@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use domain::{
+    audit::Clock, group::interface::GroupRepository, user::interface::UserDomainQueryService,
+};
+
+use crate::group::{command::*, interface::*, query::*};
+
+pub struct GroupRegistry {
+    create_group: Arc<CreateGroupService>,
+    update_group: Arc<UpdateGroupService>,
+    delete_group: Arc<DeleteGroupService>,
+    update_group_member: Arc<UpdateGroupMemberService>,
+    remove_group_member: Arc<RemoveGroupMemberService>,
+    get_group_details: Arc<GetGroupDetailsService>,
+    get_group_list: Arc<GetGroupListService>,
+}
+
+impl GroupRegistry {
+    pub fn new(
+        repository: Arc<dyn GroupRepository>,
+        query_service: Arc<dyn GroupQueryService>,
+        user_domain_query_service: Arc<dyn UserDomainQueryService>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let create_group = CreateGroupService::new(clock.clone(), repository.clone());
+        let update_group = UpdateGroupService::new(clock.clone(), repository.clone());
+        let delete_group = DeleteGroupService::new(clock.clone(), repository.clone());
+        let update_group_member = UpdateGroupMemberService::new(
+            clock.clone(),
+            repository.clone(),
+            user_domain_query_service.clone(),
+        );
+        let remove_group_member = RemoveGroupMemberService::new(clock.clone(), repository.clone());
+
+        let get_group_details = GetGroupDetailsService::new(query_service.clone());
+        let get_group_list = GetGroupListService::new(query_service.clone());
+
+        GroupRegistry {
+            create_group: Arc::new(create_group),
+            update_group: Arc::new(update_group),
+            delete_group: Arc::new(delete_group),
+            update_group_member: Arc::new(update_group_member),
+            remove_group_member: Arc::new(remove_group_member),
+            get_group_details: Arc::new(get_group_details),
+            get_group_list: Arc::new(get_group_list),
+        }
+    }
+
+    pub fn create_group(&self) -> Arc<CreateGroupService> {
+        self.create_group.clone()
+    }
+
+    pub fn update_group(&self) -> Arc<UpdateGroupService> {
+        self.update_group.clone()
+    }
+
+    pub fn delete_group(&self) -> Arc<DeleteGroupService> {
+        self.delete_group.clone()
+    }
+
+    pub fn update_group_member(&self) -> Arc<UpdateGroupMemberService> {
+        self.update_group_member.clone()
+    }
+
+    pub fn remove_group_member(&self) -> Arc<RemoveGroupMemberService> {
+        self.remove_group_member.clone()
+    }
+
+    pub fn get_group_details(&self) -> Arc<GetGroupDetailsService> {
+        self.get_group_details.clone()
+    }
+
+    pub fn get_group_list(&self) -> Arc<GetGroupListService> {
+        self.get_group_list.clone()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod command;
+pub mod dto;
+pub mod interface;
+pub mod registry;
+
+pub use registry::IdempotencyRegistry;
@@ -0,0 +1,7 @@
+mod begin_idempotent_request;
+mod complete_idempotent_request;
+mod purge_idempotency_keys;
+
+pub use begin_idempotent_request::*;
+pub use complete_idempotent_request::*;
+pub use purge_idempotency_keys::*;
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use derive_new::new;
+use domain::{
+    audit::{Actor, Clock},
+    shared::error::DomainError,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    idempotency::{dto::*, interface::IdempotencyRepository},
+    shared::error::ApplicationError,
+};
+
+const MAX_KEY_LENGTH: usize = 255;
+
+#[derive(new)]
+pub struct BeginIdempotentRequestService {
+    clock: Arc<dyn Clock>,
+    idempotency_repository: Arc<dyn IdempotencyRepository>,
+    ttl: Duration,
+    lock_timeout: Duration,
+}
+
+impl BeginIdempotentRequestService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        request: &IdempotentRequestDTO,
+    ) -> Result<IdempotencyOutcomeDTO, ApplicationError> {
+        if request.key.is_empty() || request.key.len() > MAX_KEY_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Idempotency-Key must be between 1 and {} characters",
+                MAX_KEY_LENGTH
+            ))
+            .into());
+        }
+
+        let now = self.clock.now();
+        let request_hash = request_hash(request);
+
+        // The record can expire between the two calls, so give the reservation a second try
+        for _ in 0..2 {
+            if self
+                .idempotency_repository
+                .try_begin(
+                    actor.id(),
+                    &request.key,
+                    &request_hash,
+                    now,
+                    now - self.lock_timeout,
+                    now + self.ttl,
+                )
+                .await?
+            {
+                return Ok(IdempotencyOutcomeDTO::Proceed);
+            }
+
+            if let Some(record) = self
+                .idempotency_repository
+                .find(actor.id(), &request.key, now)
+                .await?
+            {
+                return Ok(if record.request_hash != request_hash {
+                    IdempotencyOutcomeDTO::KeyReused
+                } else {
+                    match record.response {
+                        Some(response) => IdempotencyOutcomeDTO::Replay(response),
+                        None => IdempotencyOutcomeDTO::InProgress,
+                    }
+                });
+            }
+        }
+
+        Ok(IdempotencyOutcomeDTO::InProgress)
+    }
+}
+
+fn request_hash(request: &IdempotentRequestDTO) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(request.method.as_bytes());
+    hasher.update(b" ");
+    hasher.update(request.path.as_bytes());
+    if let Some(query) = &request.query {
+        hasher.update(b"?");
+        hasher.update(query.as_bytes());
+    }
+    hasher.update(b"\n");
+    hasher.update(&request.body);
+    format!("{:x}", hasher.finalize())
+}
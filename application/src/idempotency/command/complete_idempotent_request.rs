@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    idempotency::{dto::IdempotentResponseDTO, interface::IdempotencyRepository},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct CompleteIdempotentRequestService {
+    idempotency_repository: Arc<dyn IdempotencyRepository>,
+}
+
+impl CompleteIdempotentRequestService {
+    /// Stores the response for replay. Server errors are not stored, so the client can retry.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        key: &str,
+        response: &IdempotentResponseDTO,
+    ) -> Result<(), ApplicationError> {
+        if response.status >= 500 {
+            self.idempotency_repository.release(actor.id(), key).await?;
+        } else {
+            self.idempotency_repository
+                .complete(actor.id(), key, response)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
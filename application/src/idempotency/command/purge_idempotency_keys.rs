@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    idempotency::interface::IdempotencyRepository, job::interface::Job,
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct PurgeIdempotencyKeysService {
+    clock: Arc<dyn Clock>,
+    idempotency_repository: Arc<dyn IdempotencyRepository>,
+}
+
+impl PurgeIdempotencyKeysService {
+    pub async fn execute(&self) -> Result<u64, ApplicationError> {
+        self.idempotency_repository
+            .purge_expired(self.clock.now())
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[async_trait]
+impl Job for PurgeIdempotencyKeysService {
+    fn name(&self) -> &'static str {
+        "idempotency_key_cleanup"
+    }
+
+    async fn run(&self) -> Result<(), ApplicationError> {
+        let deleted = self.execute().await?;
+        tracing::info!(deleted, "Purged expired idempotency keys");
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+/// A mutating request sent with an `Idempotency-Key` header.
+#[derive(Debug)]
+pub struct IdempotentRequestDTO {
+    pub key: String,
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// The response produced for an idempotent request, as it will be replayed.
+#[derive(Debug, Clone)]
+pub struct IdempotentResponseDTO {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
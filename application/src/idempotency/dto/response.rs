@@ -0,0 +1,13 @@
+use crate::idempotency::dto::IdempotentResponseDTO;
+
+#[derive(Debug)]
+pub enum IdempotencyOutcomeDTO {
+    /// First request with this key; run it and complete the key with its response.
+    Proceed,
+    /// The key was already completed with this response.
+    Replay(IdempotentResponseDTO),
+    /// The first request with this key is still running.
+    InProgress,
+    /// The key was already used for a different request.
+    KeyReused,
+}
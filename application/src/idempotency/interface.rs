@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{shared::error::PersistenceError, user::values::UserId};
+
+use crate::idempotency::dto::IdempotentResponseDTO;
+
+pub struct IdempotencyRecord {
+    pub request_hash: String,
+    /// `None` while the first request is still running.
+    pub response: Option<IdempotentResponseDTO>,
+}
+
+#[async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    /// Reserves `key` for `actor_id`, taking over an expired reservation or one made before
+    /// `locked_before` that never got a response (its request is presumed lost).
+    /// Returns `false` when the key is still held by an earlier request.
+    async fn try_begin(
+        &self,
+        actor_id: UserId,
+        key: &str,
+        request_hash: &str,
+        now: DateTime<Utc>,
+        locked_before: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, PersistenceError>;
+
+    async fn find(
+        &self,
+        actor_id: UserId,
+        key: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, PersistenceError>;
+
+    async fn complete(
+        &self,
+        actor_id: UserId,
+        key: &str,
+        response: &IdempotentResponseDTO,
+    ) -> Result<(), PersistenceError>;
+
+    /// Drops the reservation so that the request can be retried with the same key.
+    async fn release(&self, actor_id: UserId, key: &str) -> Result<(), PersistenceError>;
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Result<u64, PersistenceError>;
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use domain::audit::Clock;
+
+use crate::idempotency::{command::*, interface::*};
+
+pub struct IdempotencyRegistry {
+    begin_idempotent_request: Arc<BeginIdempotentRequestService>,
+    complete_idempotent_request: Arc<CompleteIdempotentRequestService>,
+    purge_idempotency_keys: Arc<PurgeIdempotencyKeysService>,
+}
+
+impl IdempotencyRegistry {
+    pub fn new(
+        repository: Arc<dyn IdempotencyRepository>,
+        ttl: Duration,
+        lock_timeout: Duration,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let begin_idempotent_request = BeginIdempotentRequestService::new(
+            clock.clone(),
+            repository.clone(),
+            ttl,
+            lock_timeout,
+        );
+        let complete_idempotent_request = CompleteIdempotentRequestService::new(repository.clone());
+        let purge_idempotency_keys = PurgeIdempotencyKeysService::new(clock.clone(), repository);
+
+        IdempotencyRegistry {
+            begin_idempotent_request: Arc::new(begin_idempotent_request),
+            complete_idempotent_request: Arc::new(complete_idempotent_request),
+            purge_idempotency_keys: Arc::new(purge_idempotency_keys),
+        }
+    }
+
+    pub fn begin_idempotent_request(&self) -> Arc<BeginIdempotentRequestService> {
+        self.begin_idempotent_request.clone()
+    }
+
+    pub fn complete_idempotent_request(&self) -> Arc<CompleteIdempotentRequestService> {
+        self.complete_idempotent_request.clone()
+    }
+
+    pub fn purge_idempotency_keys(&self) -> Arc<PurgeIdempotencyKeysService> {
+        self.purge_idempotency_keys.clone()
+    }
+}
@@ -0,0 +1,7 @@
+mod run_due_jobs;
+mod sync_jobs;
+mod trigger_job;
+
+pub use run_due_jobs::*;
+pub use sync_jobs::*;
+pub use trigger_job::*;
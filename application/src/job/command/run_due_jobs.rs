@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    job::{
+        interface::{JobClaim, JobRepository},
+        schedule::ScheduledJob,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct RunDueJobsService {
+    clock: Arc<dyn Clock>,
+    job_repository: Arc<dyn JobRepository>,
+    jobs: Vec<ScheduledJob>,
+    lease: Duration,
+}
+
+impl RunDueJobsService {
+    /// Runs the jobs this replica managed to claim and returns how many were run.
+    pub async fn execute(&self) -> Result<usize, ApplicationError> {
+        let now = self.clock.now();
+
+        let candidates = self
+            .jobs
+            .iter()
+            .filter_map(|scheduled| {
+                Some(JobClaim {
+                    name: scheduled.name().to_string(),
+                    next_run_at: scheduled.schedule.next_after(now)?,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let claimed = self
+            .job_repository
+            .claim_due(now, now + self.lease, &candidates)
+            .await?;
+
+        for name in &claimed {
+            let Some(scheduled) = self.jobs.iter().find(|s| s.name() == name) else {
+                continue;
+            };
+
+            let result = scheduled.job.run().await;
+            if let Err(err) = &result {
+                tracing::error!(error = ?err, job = %name, "Job failed");
+            }
+
+            self.job_repository
+                .mark_finished(
+                    name,
+                    self.clock.now(),
+                    result.err().map(|e| e.to_string()).as_deref(),
+                )
+                .await?;
+        }
+
+        Ok(claimed.len())
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    job::{
+        interface::{JobDefinition, JobRepository},
+        schedule::ScheduledJob,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct SyncJobsService {
+    clock: Arc<dyn Clock>,
+    job_repository: Arc<dyn JobRepository>,
+    jobs: Vec<ScheduledJob>,
+}
+
+impl SyncJobsService {
+    /// Persists the jobs registered in this process so that they can be listed and claimed.
+    pub async fn execute(&self) -> Result<(), ApplicationError> {
+        let now = self.clock.now();
+
+        let definitions = self
+            .jobs
+            .iter()
+            .filter_map(|scheduled| {
+                Some(JobDefinition {
+                    name: scheduled.name().to_string(),
+                    schedule: scheduled.schedule.expression().to_string(),
+                    next_run_at: scheduled.schedule.next_after(now)?,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.job_repository.sync(&definitions).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{audit::Actor, shared::error::PersistenceError};
+
+use crate::{
+    job::{dto::JobIdentity, interface::JobRepository},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct TriggerJobService {
+    job_repository: Arc<dyn JobRepository>,
+}
+
+impl TriggerJobService {
+    /// Flags the job so that the next scheduler tick on any replica runs it.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: &JobIdentity,
+    ) -> Result<(), ApplicationError> {
+        if !actor.is_deployment_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        self.job_repository
+            .request_run(&identity.job_name)
+            .await
+            .map_err(|e| match e {
+                PersistenceError::NotFound => ApplicationError::NotFound,
+                e => e.into(),
+            })
+    }
+}
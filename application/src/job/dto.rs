@@ -0,0 +1,7 @@
+mod enums;
+mod identity;
+mod response;
+
+pub use enums::*;
+pub use identity::*;
+pub use response::*;
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumString};
+
+#[derive(
+    Debug,
+    EnumString,
+    AsRefStr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum JobStatusDTO {
+    Running,
+    Succeeded,
+    Failed,
+}
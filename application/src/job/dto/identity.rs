@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct JobIdentity {
+    pub job_name: String,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::job::dto::JobStatusDTO;
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobDTO {
+    pub name: String,
+    pub schedule: String,
+    pub next_run_at: DateTime<Utc>,
+    pub run_requested: bool,
+    pub last_status: Option<JobStatusDTO>,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_finished_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::shared::error::PersistenceError;
+
+use crate::{job::dto::*, shared::error::ApplicationError};
+
+/// Recurring work run by the job scheduler. Runs are at-most-once per scheduled time.
+#[async_trait]
+pub trait Job: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn run(&self) -> Result<(), ApplicationError>;
+}
+
+pub struct JobDefinition {
+    pub name: String,
+    pub schedule: String,
+    pub next_run_at: DateTime<Utc>,
+}
+
+pub struct JobClaim {
+    pub name: String,
+    pub next_run_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait JobRepository: Send + Sync {
+    /// Inserts new jobs and reschedules the ones whose schedule has changed.
+    async fn sync(&self, definitions: &[JobDefinition]) -> Result<(), PersistenceError>;
+
+    /// Claims the due or manually requested jobs among `candidates` until `lease_until`,
+    /// moving each to its `next_run_at` so that no other replica runs it again.
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        candidates: &[JobClaim],
+    ) -> Result<Vec<String>, PersistenceError>;
+
+    async fn mark_finished(
+        &self,
+        name: &str,
+        finished_at: DateTime<Utc>,
+        error: Option<&str>,
+    ) -> Result<(), PersistenceError>;
+
+    async fn request_run(&self, name: &str) -> Result<(), PersistenceError>;
+}
+
+#[async_trait]
+pub trait JobQueryService: Send + Sync {
+    async fn get_jobs(&self) -> Result<Vec<JobDTO>, PersistenceError>;
+}
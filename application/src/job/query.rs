@@ -0,0 +1,3 @@
+mod get_jobs;
+
+pub use get_jobs::*;
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    job::{dto::JobDTO, interface::JobQueryService},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetJobsService {
+    job_query_service: Arc<dyn JobQueryService>,
+}
+
+impl GetJobsService {
+    pub async fn execute(&self, actor: &Actor) -> Result<Vec<JobDTO>, ApplicationError> {
+        if !actor.is_deployment_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        self.job_query_service
+            .get_jobs()
+            .await
+            .map_err(|e| e.into())
+    }
+}
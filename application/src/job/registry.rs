@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use domain::audit::Clock;
+
+use crate::job::{command::*, interface::*, query::*, schedule::ScheduledJob};
+
+pub struct JobRegistry {
+    sync_jobs: Arc<SyncJobsService>,
+    run_due_jobs: Arc<RunDueJobsService>,
+    trigger_job: Arc<TriggerJobService>,
+    get_jobs: Arc<GetJobsService>,
+}
+
+impl JobRegistry {
+    pub fn new(
+        repository: Arc<dyn JobRepository>,
+        query_service: Arc<dyn JobQueryService>,
+        jobs: Vec<ScheduledJob>,
+        lease: Duration,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let sync_jobs = SyncJobsService::new(clock.clone(), repository.clone(), jobs.clone());
+        let run_due_jobs = RunDueJobsService::new(clock.clone(), repository.clone(), jobs, lease);
+        let trigger_job = TriggerJobService::new(repository.clone());
+
+        let get_jobs = GetJobsService::new(query_service.clone());
+
+        JobRegistry {
+            sync_jobs: Arc::new(sync_jobs),
+            run_due_jobs: Arc::new(run_due_jobs),
+            trigger_job: Arc::new(trigger_job),
+            get_jobs: Arc::new(get_jobs),
+        }
+    }
+
+    pub fn sync_jobs(&self) -> Arc<SyncJobsService> {
+        self.sync_jobs.clone()
+    }
+
+    pub fn run_due_jobs(&self) -> Arc<RunDueJobsService> {
+        self.run_due_jobs.clone()
+    }
+
+    pub fn trigger_job(&self) -> Arc<TriggerJobService> {
+        self.trigger_job.clone()
+    }
+
+    pub fn get_jobs(&self) -> Arc<GetJobsService> {
+        self.get_jobs.clone()
+    }
+}
@@ -0,0 +1,52 @@
+use std::{str::FromStr, sync::Arc};
+
+use chrono::{DateTime, Utc};
+
+use crate::{job::interface::Job, shared::error::ApplicationError};
+
+/// A cron expression with a leading seconds field, e.g. `0 */15 * * * *`.
+#[derive(Debug, Clone)]
+pub struct JobSchedule {
+    expression: String,
+    schedule: cron::Schedule,
+}
+
+impl JobSchedule {
+    pub fn parse(expression: &str) -> Result<Self, ApplicationError> {
+        let schedule = cron::Schedule::from_str(expression).map_err(|e| {
+            ApplicationError::InternalError(format!("Invalid schedule '{expression}': {e}"))
+        })?;
+
+        Ok(JobSchedule {
+            expression: expression.to_string(),
+            schedule,
+        })
+    }
+
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub fn next_after(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.schedule.after(&at).next()
+    }
+}
+
+#[derive(Clone)]
+pub struct ScheduledJob {
+    pub job: Arc<dyn Job>,
+    pub schedule: JobSchedule,
+}
+
+impl ScheduledJob {
+    pub fn new(job: Arc<dyn Job>, expression: &str) -> Result<Self, ApplicationError> {
+        Ok(ScheduledJob {
+            job,
+            schedule: JobSchedule::parse(expression)?,
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.job.name()
+    }
+}
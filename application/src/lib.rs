@@ -1,3 +1,12 @@
 pub mod book;
+pub mod book_import;
+pub mod event;
+pub mod group;
+pub mod idempotency;
+pub mod job;
+pub mod notification;
+pub mod outbox;
+pub mod personal_access_token;
 pub mod shared;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,9 @@
+pub mod command;
+pub mod dispatcher;
+pub mod interface;
+pub mod policy;
+pub mod registry;
+pub mod subscriber;
+pub mod template;
+
+pub use registry::NotificationRegistry;
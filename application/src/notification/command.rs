@@ -0,0 +1,3 @@
+mod send_due_reminders;
+
+pub use send_due_reminders::*;
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    job::interface::Job,
+    notification::{
+        dispatcher::NotificationDispatcher, interface::NotificationQueryService,
+        policy::NotificationPolicy, template::NotificationTemplate,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct SendDueRemindersService {
+    clock: Arc<dyn Clock>,
+    query_service: Arc<dyn NotificationQueryService>,
+    dispatcher: Arc<NotificationDispatcher>,
+    policy: NotificationPolicy,
+}
+
+impl SendDueRemindersService {
+    /// Emails borrowers whose loans are due soon or overdue and returns how many were sent.
+    pub async fn execute(&self) -> Result<usize, ApplicationError> {
+        let now = self.clock.now();
+
+        let checkouts = self
+            .query_service
+            .find_due_checkouts(self.policy.reminder_threshold(now))
+            .await?;
+
+        let mut sent = 0;
+        for checkout in checkouts {
+            let due_at = self.policy.due_at(checkout.checked_out_at);
+            let template = match due_at <= now {
+                true => NotificationTemplate::Overdue {
+                    book_title: checkout.book_title,
+                    due_at,
+                },
+                false => NotificationTemplate::DueSoon {
+                    book_title: checkout.book_title,
+                    due_at,
+                },
+            };
+
+            match self
+                .dispatcher
+                .dispatch(&checkout.borrower, checkout.checkout_id, template)
+                .await
+            {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(err) => tracing::warn!(
+                    error = ?err,
+                    checkout_id = %checkout.checkout_id,
+                    "Due reminder failed"
+                ),
+            }
+        }
+
+        Ok(sent)
+    }
+}
+
+#[async_trait]
+impl Job for SendDueRemindersService {
+    fn name(&self) -> &'static str {
+        "due_reminders"
+    }
+
+    async fn run(&self) -> Result<(), ApplicationError> {
+        let sent = self.execute().await?;
+        tracing::info!(sent, "Sent due reminders");
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Clock;
+use uuid::Uuid;
+
+use crate::{
+    notification::{
+        interface::{
+            NotificationLogRepository, NotificationRecipient, NotificationRecord, Notifier,
+        },
+        template::NotificationTemplate,
+    },
+    shared::error::ApplicationError,
+};
+
+/// Sends a notification at most once per recipient, kind and reference,
+/// skipping the kinds the recipient has opted out of.
+#[derive(new)]
+pub struct NotificationDispatcher {
+    clock: Arc<dyn Clock>,
+    log_repository: Arc<dyn NotificationLogRepository>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl NotificationDispatcher {
+    /// Returns whether a message was actually sent.
+    pub async fn dispatch(
+        &self,
+        recipient: &NotificationRecipient,
+        reference_id: Uuid,
+        template: NotificationTemplate,
+    ) -> Result<bool, ApplicationError> {
+        let kind = template.kind();
+        if !recipient.preferences.allows(kind) {
+            return Ok(false);
+        }
+
+        let record = NotificationRecord {
+            user_id: recipient.id,
+            kind,
+            reference_id,
+            sent_at: self.clock.now(),
+        };
+
+        if !self.log_repository.try_record(&record).await? {
+            return Ok(false);
+        }
+
+        // Forget the record on failure so that the next attempt sends it again
+        if let Err(err) = self.notifier.send(&template.render(recipient)).await {
+            self.log_repository.remove(&record).await?;
+            return Err(ApplicationError::InternalError(format!(
+                "Failed to send {} notification: {}",
+                kind.as_ref(),
+                err
+            )));
+        }
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    book::values::BookId,
+    shared::error::PersistenceError,
+    user::{
+        enums::NotificationKind,
+        values::{NotificationPreferences, UserId},
+    },
+};
+use uuid::Uuid;
+
+pub struct NotificationRecipient {
+    pub id: UserId,
+    pub name: String,
+    pub email: String,
+    pub preferences: NotificationPreferences,
+}
+
+pub struct DueCheckout {
+    pub checkout_id: Uuid,
+    pub book_title: String,
+    pub checked_out_at: DateTime<Utc>,
+    pub borrower: NotificationRecipient,
+}
+
+#[async_trait]
+pub trait NotificationQueryService: Send + Sync {
+    async fn find_recipient(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationRecipient>, PersistenceError>;
+
+    async fn find_book_title(&self, book_id: BookId) -> Result<Option<String>, PersistenceError>;
+
+    /// Returns active checkouts made before `checked_out_before`, except those already reported overdue.
+    async fn find_due_checkouts(
+        &self,
+        checked_out_before: DateTime<Utc>,
+    ) -> Result<Vec<DueCheckout>, PersistenceError>;
+}
+
+pub struct NotificationRecord {
+    pub user_id: UserId,
+    pub kind: NotificationKind,
+    pub reference_id: Uuid,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait NotificationLogRepository: Send + Sync {
+    /// Returns `false` when the same notification has already been recorded.
+    async fn try_record(&self, record: &NotificationRecord) -> Result<bool, PersistenceError>;
+
+    async fn remove(&self, record: &NotificationRecord) -> Result<(), PersistenceError>;
+}
+
+pub struct NotificationMessage {
+    pub to_name: String,
+    pub to_email: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn send(&self, message: &NotificationMessage) -> Result<(), String>;
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Duration, Utc};
+use derive_new::new;
+
+#[derive(Debug, Clone, Copy, new)]
+pub struct NotificationPolicy {
+    pub loan_period: Duration,
+    pub due_soon_window: Duration,
+}
+
+impl NotificationPolicy {
+    pub fn due_at(&self, checked_out_at: DateTime<Utc>) -> DateTime<Utc> {
+        checked_out_at + self.loan_period
+    }
+
+    /// Checkouts made before this instant are due within the reminder window.
+    pub fn reminder_threshold(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now + self.due_soon_window - self.loan_period
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use domain::audit::Clock;
+
+use crate::notification::{
+    command::*, dispatcher::NotificationDispatcher, interface::*, policy::NotificationPolicy,
+    subscriber::NotificationEventSubscriber,
+};
+
+pub struct NotificationRegistry {
+    send_due_reminders: Arc<SendDueRemindersService>,
+    event_subscriber: Arc<NotificationEventSubscriber>,
+}
+
+impl NotificationRegistry {
+    pub fn new(
+        query_service: Arc<dyn NotificationQueryService>,
+        log_repository: Arc<dyn NotificationLogRepository>,
+        notifier: Arc<dyn Notifier>,
+        policy: NotificationPolicy,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let dispatcher = Arc::new(NotificationDispatcher::new(
+            clock.clone(),
+            log_repository.clone(),
+            notifier.clone(),
+        ));
+
+        let send_due_reminders = SendDueRemindersService::new(
+            clock.clone(),
+            query_service.clone(),
+            dispatcher.clone(),
+            policy,
+        );
+
+        let event_subscriber =
+            NotificationEventSubscriber::new(query_service.clone(), dispatcher.clone());
+
+        NotificationRegistry {
+            send_due_reminders: Arc::new(send_due_reminders),
+            event_subscriber: Arc::new(event_subscriber),
+        }
+    }
+
+    pub fn send_due_reminders(&self) -> Arc<SendDueRemindersService> {
+        self.send_due_reminders.clone()
+    }
+
+    pub fn event_subscriber(&self) -> Arc<NotificationEventSubscriber> {
+        self.event_subscriber.clone()
+    }
+}
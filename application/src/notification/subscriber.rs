@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    book::values::BookId,
+    event::{DomainEvent, DomainEventKind},
+    user::values::UserId,
+};
+use uuid::Uuid;
+
+use crate::{
+    notification::{
+        dispatcher::NotificationDispatcher, interface::NotificationQueryService,
+        template::NotificationTemplate,
+    },
+    shared::{error::ApplicationError, event::EventSubscriber},
+};
+
+/// Emails book owners when someone else checks out or returns their book.
+#[derive(new)]
+pub struct NotificationEventSubscriber {
+    query_service: Arc<dyn NotificationQueryService>,
+    dispatcher: Arc<NotificationDispatcher>,
+}
+
+#[async_trait]
+impl EventSubscriber for NotificationEventSubscriber {
+    fn name(&self) -> &'static str {
+        "notification"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<(), ApplicationError> {
+        match *event.kind() {
+            DomainEventKind::BookCheckedOut {
+                book_id,
+                owner_id,
+                checkout_id,
+                checked_out_to,
+            } => {
+                self.notify_owner(
+                    book_id,
+                    owner_id,
+                    checkout_id,
+                    checked_out_to,
+                    |book_title, borrower_name| NotificationTemplate::BookCheckedOut {
+                        book_title,
+                        borrower_name,
+                    },
+                )
+                .await
+            }
+            DomainEventKind::BookReturned {
+                book_id,
+                owner_id,
+                checkout_id,
+                checked_out_to,
+            } => {
+                self.notify_owner(
+                    book_id,
+                    owner_id,
+                    checkout_id,
+                    checked_out_to,
+                    |book_title, borrower_name| NotificationTemplate::BookReturned {
+                        book_title,
+                        borrower_name,
+                    },
+                )
+                .await
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl NotificationEventSubscriber {
+    async fn notify_owner(
+        &self,
+        book_id: BookId,
+        owner_id: UserId,
+        checkout_id: Uuid,
+        borrower_id: UserId,
+        template: impl FnOnce(String, String) -> NotificationTemplate,
+    ) -> Result<(), ApplicationError> {
+        if owner_id == borrower_id {
+            return Ok(());
+        }
+
+        // The book or either user may have been deleted since the event was recorded
+        let Some(owner) = self.query_service.find_recipient(owner_id).await? else {
+            return Ok(());
+        };
+        let Some(borrower) = self.query_service.find_recipient(borrower_id).await? else {
+            return Ok(());
+        };
+        let Some(book_title) = self.query_service.find_book_title(book_id).await? else {
+            return Ok(());
+        };
+
+        self.dispatcher
+            .dispatch(&owner, checkout_id, template(book_title, borrower.name))
+            .await?;
+
+        Ok(())
+    }
+}
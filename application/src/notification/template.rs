@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use domain::user::enums::NotificationKind;
+
+use crate::notification::interface::{NotificationMessage, NotificationRecipient};
+
+pub enum NotificationTemplate {
+    DueSoon {
+        book_title: String,
+        due_at: DateTime<Utc>,
+    },
+    Overdue {
+        book_title: String,
+        due_at: DateTime<Utc>,
+    },
+    BookCheckedOut {
+        book_title: String,
+        borrower_name: String,
+    },
+    BookReturned {
+        book_title: String,
+        borrower_name: String,
+    },
+}
+
+impl NotificationTemplate {
+    pub fn kind(&self) -> NotificationKind {
+        match self {
+            NotificationTemplate::DueSoon { .. } => NotificationKind::DueSoon,
+            NotificationTemplate::Overdue { .. } => NotificationKind::Overdue,
+            NotificationTemplate::BookCheckedOut { .. } => NotificationKind::BookCheckedOut,
+            NotificationTemplate::BookReturned { .. } => NotificationKind::BookReturned,
+        }
+    }
+
+    pub fn render(&self, recipient: &NotificationRecipient) -> NotificationMessage {
+        let (subject, paragraphs) = match self {
+            NotificationTemplate::DueSoon { book_title, due_at } => (
+                format!("\"{book_title}\" is due soon"),
+                vec![
+                    format!(
+                        "Your loan of \"{book_title}\" is due on {}.",
+                        format_date(due_at)
+                    ),
+                    "Please return it by then.".to_string(),
+                ],
+            ),
+            NotificationTemplate::Overdue { book_title, due_at } => (
+                format!("\"{book_title}\" is overdue"),
+                vec![
+                    format!(
+                        "Your loan of \"{book_title}\" was due on {}.",
+                        format_date(due_at)
+                    ),
+                    "Please return it as soon as possible.".to_string(),
+                ],
+            ),
+            NotificationTemplate::BookCheckedOut {
+                book_title,
+                borrower_name,
+            } => (
+                format!("\"{book_title}\" was checked out"),
+                vec![format!(
+                    "{borrower_name} has checked out your book \"{book_title}\"."
+                )],
+            ),
+            NotificationTemplate::BookReturned {
+                book_title,
+                borrower_name,
+            } => (
+                format!("\"{book_title}\" was returned"),
+                vec![format!(
+                    "{borrower_name} has returned your book \"{book_title}\"."
+                )],
+            ),
+        };
+
+        let greeting = format!("Hi {},", recipient.name);
+        let footer = "You can change which emails you receive in your notification settings.";
+
+        let text_body = std::iter::once(greeting.as_str())
+            .chain(paragraphs.iter().map(String::as_str))
+            .chain(std::iter::once(footer))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let html_body = format!(
+            "<!DOCTYPE html>\n<html>\n<body>\n{}\n<p style=\"color:#888;font-size:small\">{}</p>\n</body>\n</html>\n",
+            std::iter::once(&greeting)
+                .chain(paragraphs.iter())
+                .map(|p| format!("<p>{}</p>", escape_html(p)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            escape_html(footer)
+        );
+
+        NotificationMessage {
+            to_name: recipient.name.clone(),
+            to_email: recipient.email.clone(),
+            subject,
+            text_body,
+            html_body,
+        }
+    }
+}
+
+fn format_date(at: &DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
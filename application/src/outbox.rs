@@ -0,0 +1,7 @@
+pub mod command;
+pub mod dto;
+pub mod interface;
+pub mod query;
+pub mod registry;
+
+pub use registry::OutboxRegistry;
@@ -0,0 +1,7 @@
+mod purge_outbox;
+mod relay_outbox;
+mod requeue_outbox_message;
+
+pub use purge_outbox::*;
+pub use relay_outbox::*;
+pub use requeue_outbox_message::*;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    job::interface::Job, outbox::interface::OutboxRepository, shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct PurgeOutboxService {
+    clock: Arc<dyn Clock>,
+    outbox_repository: Arc<dyn OutboxRepository>,
+    retention: Duration,
+}
+
+impl PurgeOutboxService {
+    /// Deletes delivered messages past the retention period. Their events can no longer be replayed.
+    pub async fn execute(&self) -> Result<u64, ApplicationError> {
+        self.outbox_repository
+            .purge_delivered(self.clock.now() - self.retention)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[async_trait]
+impl Job for PurgeOutboxService {
+    fn name(&self) -> &'static str {
+        "outbox_cleanup"
+    }
+
+    async fn run(&self) -> Result<(), ApplicationError> {
+        let deleted = self.execute().await?;
+        tracing::info!(deleted, "Purged delivered outbox messages");
+        Ok(())
+    }
+}
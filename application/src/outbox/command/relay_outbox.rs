@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    outbox::interface::{OutboxMessage, OutboxRepository},
+    shared::{error::ApplicationError, event::EventPublisher, relay::RelayPolicy},
+};
+
+#[derive(new)]
+pub struct RelayOutboxService {
+    clock: Arc<dyn Clock>,
+    outbox_repository: Arc<dyn OutboxRepository>,
+    publishers: Vec<Arc<dyn EventPublisher>>,
+    policy: RelayPolicy,
+}
+
+impl RelayOutboxService {
+    /// Relays one batch of due messages and returns how many were claimed.
+    pub async fn execute(&self) -> Result<usize, ApplicationError> {
+        let now = self.clock.now();
+
+        let messages = self
+            .outbox_repository
+            .claim_due(now, now + self.policy.lease, self.policy.batch_size)
+            .await?;
+
+        for message in &messages {
+            self.relay(message).await?;
+        }
+
+        Ok(messages.len())
+    }
+
+    async fn relay(&self, message: &OutboxMessage) -> Result<(), ApplicationError> {
+        let mut errors = vec![];
+        for publisher in &self.publishers {
+            if let Err(err) = publisher.publish(&message.event).await {
+                errors.push(format!("{}: {}", publisher.name(), err));
+            }
+        }
+
+        let now = self.clock.now();
+        if errors.is_empty() {
+            self.outbox_repository
+                .mark_delivered(message.id, now)
+                .await?;
+            return Ok(());
+        }
+
+        let attempts = message.attempts + 1;
+        let next_attempt_at = self.policy.next_attempt_at(now, attempts);
+
+        match next_attempt_at {
+            Some(at) => tracing::warn!(
+                message_id = %message.id,
+                attempts,
+                next_attempt_at = %at,
+                "Outbox message delivery failed; will retry"
+            ),
+            None => tracing::error!(
+                message_id = %message.id,
+                attempts,
+                "Outbox message delivery failed; moved to dead letters"
+            ),
+        }
+
+        self.outbox_repository
+            .mark_failed(message.id, attempts, &errors.join("; "), next_attempt_at)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, Clock},
+    shared::error::PersistenceError,
+};
+
+use crate::{
+    outbox::{dto::OutboxMessageIdentity, interface::OutboxRepository},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct RequeueOutboxMessageService {
+    clock: Arc<dyn Clock>,
+    outbox_repository: Arc<dyn OutboxRepository>,
+}
+
+impl RequeueOutboxMessageService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: OutboxMessageIdentity,
+    ) -> Result<(), ApplicationError> {
+        if !actor.is_deployment_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        self.outbox_repository
+            .requeue(identity.message_id, self.clock.now())
+            .await
+            .map_err(|e| match e {
+                PersistenceError::NotFound => ApplicationError::NotFound,
+                e => e.into(),
+            })
+    }
+}
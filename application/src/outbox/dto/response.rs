@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{outbox::dto::OutboxStatusDTO, shared::PaginationDTO};
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxMessageDTO {
+    pub id: Uuid,
+    pub event_type: String,
+    pub status: OutboxStatusDTO,
+    pub attempts: u32,
+    pub occurred_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+pub type OutboxMessageListDTO = PaginationDTO<OutboxMessageDTO>;
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxStatsDTO {
+    pub pending: u64,
+    pub delivered: u64,
+    pub dead: u64,
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{event::DomainEvent, shared::error::PersistenceError};
+use uuid::Uuid;
+
+use crate::outbox::dto::*;
+
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub attempts: u32,
+    pub event: DomainEvent,
+}
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Claims up to `limit` due messages until `lease_until`, so that other relays skip them.
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<OutboxMessage>, PersistenceError>;
+
+    async fn mark_delivered(
+        &self,
+        id: Uuid,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError>;
+
+    /// Schedules the next attempt, or dead-letters the message when `next_attempt_at` is `None`.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        attempts: u32,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError>;
+
+    async fn requeue(&self, id: Uuid, now: DateTime<Utc>) -> Result<(), PersistenceError>;
+
+    /// Deletes delivered messages older than `before` and returns how many were deleted.
+    async fn purge_delivered(&self, before: DateTime<Utc>) -> Result<u64, PersistenceError>;
+}
+
+#[async_trait]
+pub trait OutboxQueryService: Send + Sync {
+    async fn get_messages(
+        &self,
+        query: &OutboxListQueryDTO,
+    ) -> Result<OutboxMessageListDTO, PersistenceError>;
+
+    async fn get_stats(&self) -> Result<OutboxStatsDTO, PersistenceError>;
+}
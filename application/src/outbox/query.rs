@@ -0,0 +1,5 @@
+mod get_outbox_messages;
+mod get_outbox_stats;
+
+pub use get_outbox_messages::*;
+pub use get_outbox_stats::*;
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+use garde::Validate;
+
+use crate::{
+    outbox::{
+        dto::{OutboxListQueryDTO, OutboxMessageListDTO},
+        interface::OutboxQueryService,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetOutboxMessagesService {
+    outbox_query_service: Arc<dyn OutboxQueryService>,
+}
+
+impl GetOutboxMessagesService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        query: &OutboxListQueryDTO,
+    ) -> Result<OutboxMessageListDTO, ApplicationError> {
+        if !actor.is_deployment_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        query.validate()?;
+
+        self.outbox_query_service
+            .get_messages(query)
+            .await
+            .map_err(|e| e.into())
+    }
+}
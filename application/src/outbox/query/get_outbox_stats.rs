@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    outbox::{dto::OutboxStatsDTO, interface::OutboxQueryService},
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetOutboxStatsService {
+    outbox_query_service: Arc<dyn OutboxQueryService>,
+}
+
+impl GetOutboxStatsService {
+    pub async fn execute(&self, actor: &Actor) -> Result<OutboxStatsDTO, ApplicationError> {
+        if !actor.is_deployment_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        self.outbox_query_service
+            .get_stats()
+            .await
+            .map_err(|e| e.into())
+    }
+}
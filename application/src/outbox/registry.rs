@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use domain::audit::Clock;
+
+use crate::{
+    outbox::{command::*, interface::*, query::*},
+    shared::{event::EventPublisher, relay::RelayPolicy},
+};
+
+pub struct OutboxRegistry {
+    relay_outbox: Arc<RelayOutboxService>,
+    requeue_outbox_message: Arc<RequeueOutboxMessageService>,
+    purge_outbox: Arc<PurgeOutboxService>,
+    get_outbox_messages: Arc<GetOutboxMessagesService>,
+    get_outbox_stats: Arc<GetOutboxStatsService>,
+}
+
+impl OutboxRegistry {
+    pub fn new(
+        repository: Arc<dyn OutboxRepository>,
+        query_service: Arc<dyn OutboxQueryService>,
+        publishers: Vec<Arc<dyn EventPublisher>>,
+        policy: RelayPolicy,
+        retention: Duration,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let relay_outbox =
+            RelayOutboxService::new(clock.clone(), repository.clone(), publishers, policy);
+        let requeue_outbox_message =
+            RequeueOutboxMessageService::new(clock.clone(), repository.clone());
+        let purge_outbox = PurgeOutboxService::new(clock.clone(), repository.clone(), retention);
+
+        let get_outbox_messages = GetOutboxMessagesService::new(query_service.clone());
+        let get_outbox_stats = GetOutboxStatsService::new(query_service.clone());
+
+        OutboxRegistry {
+            relay_outbox: Arc::new(relay_outbox),
+            requeue_outbox_message: Arc::new(requeue_outbox_message),
+            purge_outbox: Arc::new(purge_outbox),
+            get_outbox_messages: Arc::new(get_outbox_messages),
+            get_outbox_stats: Arc::new(get_outbox_stats),
+        }
+    }
+
+    pub fn relay_outbox(&self) -> Arc<RelayOutboxService> {
+        self.relay_outbox.clone()
+    }
+
+    pub fn requeue_outbox_message(&self) -> Arc<RequeueOutboxMessageService> {
+        self.requeue_outbox_message.clone()
+    }
+
+    pub fn purge_outbox(&self) -> Arc<PurgeOutboxService> {
+        self.purge_outbox.clone()
+    }
+
+    pub fn get_outbox_messages(&self) -> Arc<GetOutboxMessagesService> {
+        self.get_outbox_messages.clone()
+    }
+
+    pub fn get_outbox_stats(&self) -> Arc<GetOutboxStatsService> {
+        self.get_outbox_stats.clone()
+    }
+}
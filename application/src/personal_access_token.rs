@@ -0,0 +1,8 @@
+pub mod command;
+pub mod dto;
+pub mod interface;
+pub mod query;
+pub mod registry;
+pub mod token;
+
+pub use registry::PersonalAccessTokenRegistry;
@@ -0,0 +1,7 @@
+mod authenticate_personal_access_token;
+mod create_personal_access_token;
+mod revoke_personal_access_token;
+
+pub use authenticate_personal_access_token::*;
+pub use create_personal_access_token::*;
+pub use revoke_personal_access_token::*;
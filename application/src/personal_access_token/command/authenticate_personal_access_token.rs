@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use derive_new::new;
+use domain::{audit::Clock, personal_access_token::interface::PersonalAccessTokenRepository};
+
+use crate::{
+    personal_access_token::{
+        dto::PersonalAccessTokenCredentialDTO, interface::PersonalAccessTokenQueryService,
+        token::hash_token,
+    },
+    shared::error::ApplicationError,
+};
+
+/// `last_used_at` is only rewritten once this much time has passed, to keep reads cheap.
+const USAGE_RECORD_INTERVAL: Duration = Duration::minutes(1);
+
+#[derive(new)]
+pub struct AuthenticatePersonalAccessTokenService {
+    clock: Arc<dyn Clock>,
+    personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository>,
+    personal_access_token_query_service: Arc<dyn PersonalAccessTokenQueryService>,
+}
+
+impl AuthenticatePersonalAccessTokenService {
+    /// Returns `None` for unknown and expired tokens.
+    pub async fn execute(
+        &self,
+        token: &str,
+    ) -> Result<Option<PersonalAccessTokenCredentialDTO>, ApplicationError> {
+        let now = self.clock.now();
+
+        let Some(credential) = self
+            .personal_access_token_query_service
+            .find_credential(&hash_token(token))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if credential.expires_at <= now {
+            return Ok(None);
+        }
+
+        if credential
+            .last_used_at
+            .is_none_or(|last_used_at| now - last_used_at >= USAGE_RECORD_INTERVAL)
+        {
+            self.personal_access_token_repository
+                .record_usage(credential.id.into(), now)
+                .await?;
+        }
+
+        Ok(Some(credential))
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    personal_access_token::{
+        entity::PersonalAccessToken, interface::PersonalAccessTokenRepository, values::TokenHash,
+    },
+};
+
+use crate::{
+    personal_access_token::{
+        dto::{CreatePersonalAccessTokenRequestDTO, PersonalAccessTokenCreatedDTO},
+        token::{generate_token, hash_token},
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct CreatePersonalAccessTokenService {
+    clock: Arc<dyn Clock>,
+    personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository>,
+}
+
+impl CreatePersonalAccessTokenService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        request: &CreatePersonalAccessTokenRequestDTO,
+    ) -> Result<PersonalAccessTokenCreatedDTO, ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+        let token = generate_token();
+
+        let mut personal_access_token = PersonalAccessToken::create_new(
+            &context,
+            request.name.clone().try_into()?,
+            request
+                .scopes
+                .iter()
+                .map(|&scope| scope.into())
+                .collect::<Vec<_>>()
+                .try_into()?,
+            request.expires_in_days.try_into()?,
+            TokenHash::hydrate(hash_token(&token)),
+        )?;
+
+        self.personal_access_token_repository
+            .save(&mut personal_access_token)
+            .await?;
+
+        Ok(PersonalAccessTokenCreatedDTO {
+            id: personal_access_token.audit().raw_id(),
+            token,
+            expires_at: personal_access_token.expires_at(),
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    personal_access_token::interface::PersonalAccessTokenRepository,
+};
+
+use crate::{
+    personal_access_token::dto::PersonalAccessTokenIdentity, shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct RevokePersonalAccessTokenService {
+    clock: Arc<dyn Clock>,
+    personal_access_token_repository: Arc<dyn PersonalAccessTokenRepository>,
+}
+
+impl RevokePersonalAccessTokenService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: PersonalAccessTokenIdentity,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let token = self
+            .personal_access_token_repository
+            .find_by_id(identity.token_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        token.validate_deletion(&context)?;
+
+        self.personal_access_token_repository
+            .delete(token.audit().id())
+            .await?;
+
+        Ok(())
+    }
+}
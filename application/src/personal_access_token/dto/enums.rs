@@ -0,0 +1,37 @@
+use domain::personal_access_token::enums::TokenScope;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumString};
+
+#[derive(
+    Debug,
+    EnumString,
+    AsRefStr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    schemars::JsonSchema,
+)]
+pub enum TokenScopeDTO {
+    #[serde(rename = "books:read")]
+    #[strum(serialize = "books:read")]
+    BooksRead,
+    #[serde(rename = "books:write")]
+    #[strum(serialize = "books:write")]
+    BooksWrite,
+    #[serde(rename = "checkouts:write")]
+    #[strum(serialize = "checkouts:write")]
+    CheckoutsWrite,
+}
+
+impl From<TokenScopeDTO> for TokenScope {
+    fn from(dto: TokenScopeDTO) -> Self {
+        match dto {
+            TokenScopeDTO::BooksRead => TokenScope::BooksRead,
+            TokenScopeDTO::BooksWrite => TokenScope::BooksWrite,
+            TokenScopeDTO::CheckoutsWrite => TokenScope::CheckoutsWrite,
+        }
+    }
+}
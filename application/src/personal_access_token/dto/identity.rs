@@ -0,0 +1,14 @@
+use domain::{personal_access_token::values::PersonalAccessTokenId, user::values::UserId};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct PersonalAccessTokenIdentity {
+    pub token_id: PersonalAccessTokenId,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenOwnerIdentity {
+    pub user_id: UserId,
+}
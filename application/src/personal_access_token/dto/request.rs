@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use crate::personal_access_token::dto::TokenScopeDTO;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePersonalAccessTokenRequestDTO {
+    pub name: String,
+    pub scopes: Vec<TokenScopeDTO>,
+    #[serde(default = "default_expires_in_days")]
+    pub expires_in_days: u32,
+}
+
+const fn default_expires_in_days() -> u32 {
+    30
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use domain::tenant::values::TenantId;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{personal_access_token::dto::TokenScopeDTO, user::dto::UserDetailsDTO};
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalAccessTokenDTO {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<TokenScopeDTO>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// The only response that contains the token itself.
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalAccessTokenCreatedDTO {
+    pub id: Uuid,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// A stored token looked up by its hash, together with the user it acts as.
+#[derive(Debug)]
+pub struct PersonalAccessTokenCredentialDTO {
+    pub id: Uuid,
+    pub user: UserDetailsDTO,
+    pub tenant: TenantId,
+    pub scopes: Vec<TokenScopeDTO>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use domain::{shared::error::PersistenceError, tenant::values::TenantId, user::values::UserId};
+
+use crate::personal_access_token::dto::*;
+
+#[async_trait]
+pub trait PersonalAccessTokenQueryService: Send + Sync {
+    /// Tokens of a user of another tenant are never listed.
+    async fn get_tokens(
+        &self,
+        tenant: &TenantId,
+        user_id: UserId,
+    ) -> Result<Vec<PersonalAccessTokenDTO>, PersistenceError>;
+
+    async fn find_credential(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalAccessTokenCredentialDTO>, PersistenceError>;
+}
@@ -0,0 +1,3 @@
+mod get_personal_access_tokens;
+
+pub use get_personal_access_tokens::*;
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{audit::Actor, auth::capability::Capability, user::values::UserId};
+
+use crate::{
+    personal_access_token::{
+        dto::PersonalAccessTokenDTO, interface::PersonalAccessTokenQueryService,
+    },
+    shared::error::ApplicationError,
+};
+
+#[derive(new)]
+pub struct GetPersonalAccessTokensService {
+    personal_access_token_query_service: Arc<dyn PersonalAccessTokenQueryService>,
+}
+
+impl GetPersonalAccessTokensService {
+    /// Users see their own tokens; those who manage users may list anyone's.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        user_id: UserId,
+    ) -> Result<Vec<PersonalAccessTokenDTO>, ApplicationError> {
+        if actor.id() != user_id && !actor.has_capability(Capability::ManageUsers) {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        self.personal_access_token_query_service
+            .get_tokens(actor.tenant(), user_id)
+            .await
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use domain::{audit::Clock, personal_access_token::interface::PersonalAccessTokenRepository};
+
+use crate::personal_access_token::{command::*, interface::*, query::*};
+
+pub struct PersonalAccessTokenRegistry {
+    create_personal_access_token: Arc<CreatePersonalAccessTokenService>,
+    revoke_personal_access_token: Arc<RevokePersonalAccessTokenService>,
+    authenticate_personal_access_token: Arc<AuthenticatePersonalAccessTokenService>,
+    get_personal_access_tokens: Arc<GetPersonalAccessTokensService>,
+}
+
+impl PersonalAccessTokenRegistry {
+    pub fn new(
+        repository: Arc<dyn PersonalAccessTokenRepository>,
+        query_service: Arc<dyn PersonalAccessTokenQueryService>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let create_personal_access_token =
+            CreatePersonalAccessTokenService::new(clock.clone(), repository.clone());
+        let revoke_personal_access_token =
+            RevokePersonalAccessTokenService::new(clock.clone(), repository.clone());
+        let authenticate_personal_access_token = AuthenticatePersonalAccessTokenService::new(
+            clock.clone(),
+            repository.clone(),
+            query_service.clone(),
+        );
+        let get_personal_access_tokens = GetPersonalAccessTokensService::new(query_service.clone());
+
+        PersonalAccessTokenRegistry {
+            create_personal_access_token: Arc::new(create_personal_access_token),
+            revoke_personal_access_token: Arc::new(revoke_personal_access_token),
+            authenticate_personal_access_token: Arc::new(authenticate_personal_access_token),
+            get_personal_access_tokens: Arc::new(get_personal_access_tokens),
+        }
+    }
+
+    pub fn create_personal_access_token(&self) -> Arc<CreatePersonalAccessTokenService> {
+        self.create_personal_access_token.clone()
+    }
+
+    pub fn revoke_personal_access_token(&self) -> Arc<RevokePersonalAccessTokenService> {
+        self.revoke_personal_access_token.clone()
+    }
+
+    pub fn authenticate_personal_access_token(
+        &self,
+    ) -> Arc<AuthenticatePersonalAccessTokenService> {
+        self.authenticate_personal_access_token.clone()
+    }
+
+    pub fn get_personal_access_tokens(&self) -> Arc<GetPersonalAccessTokensService> {
+        self.get_personal_access_tokens.clone()
+    }
+}
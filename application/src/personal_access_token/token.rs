@@ -0,0 +1,49 @@
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Distinguishes personal access tokens from OIDC bearer tokens in the `Authorization` header.
+pub const TOKEN_PREFIX: &str = "bmpat_";
+
+pub fn is_personal_access_token(token: &str) -> bool {
+    token.starts_with(TOKEN_PREFIX)
+}
+
+/// 244 random bits from two v4 UUIDs, behind the prefix.
+pub(crate) fn generate_token() -> String {
+    format!(
+        "{}{}{}",
+        TOKEN_PREFIX,
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+pub(crate) fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_prefixed_and_unique() {
+        let token = generate_token();
+
+        assert!(is_personal_access_token(&token));
+        assert_eq!(token.len(), TOKEN_PREFIX.len() + 64);
+        assert_ne!(token, generate_token());
+        assert!(!is_personal_access_token("eyJhbGciOiJSUzI1NiJ9.e30.sig"));
+    }
+
+    #[test]
+    fn hashes_are_stable_sha256_hex() {
+        assert_eq!(
+            hash_token("bmpat_example"),
+            format!("{:x}", Sha256::digest(b"bmpat_example"))
+        );
+        assert_eq!(hash_token("bmpat_example").len(), 64);
+        assert_eq!(hash_token("bmpat_example"), hash_token("bmpat_example"));
+        assert_ne!(hash_token("bmpat_example"), hash_token("bmpat_examplf"));
+    }
+}
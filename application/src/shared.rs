@@ -1,4 +1,8 @@
 mod dto;
 pub mod error;
+pub mod event;
+pub mod marc;
+pub mod query_param;
+pub mod relay;
 
 pub use dto::*;
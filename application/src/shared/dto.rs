@@ -1,6 +1,10 @@
 use chrono::{DateTime, Utc};
-use domain::{audit::EntityAudit, auth::permission::Permission, shared::EntityIdTrait};
-use serde::Serialize;
+use domain::{
+    audit::EntityAudit,
+    auth::{capability::Capability, permission::Permission},
+    shared::EntityIdTrait,
+};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -11,6 +15,7 @@ pub struct AuditDTO {
     pub updated_by: Option<UserReferenceDTO>,
     pub updated_at: Option<DateTime<Utc>>,
     pub permission: PermissionDTO,
+    pub version: u32,
 }
 
 #[derive(Debug, Serialize, schemars::JsonSchema)]
@@ -33,6 +38,7 @@ pub struct UserReferenceDTO {
 pub struct PermissionDTO {
     pub can_update: bool,
     pub can_delete: bool,
+    pub capabilities: Vec<CapabilityDTO>,
 }
 
 impl<T: Permission> From<T> for PermissionDTO {
@@ -40,6 +46,35 @@ impl<T: Permission> From<T> for PermissionDTO {
         PermissionDTO {
             can_update: permission.can_update(),
             can_delete: permission.can_delete(),
+            capabilities: permission.capabilities().iter().map(|c| c.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum CapabilityDTO {
+    ManageUsers,
+    CheckoutOnBehalf,
+    ViewAllHistory,
+}
+
+impl From<Capability> for CapabilityDTO {
+    fn from(capability: Capability) -> Self {
+        match capability {
+            Capability::ManageUsers => CapabilityDTO::ManageUsers,
+            Capability::CheckoutOnBehalf => CapabilityDTO::CheckoutOnBehalf,
+            Capability::ViewAllHistory => CapabilityDTO::ViewAllHistory,
+        }
+    }
+}
+
+impl From<CapabilityDTO> for Capability {
+    fn from(dto: CapabilityDTO) -> Self {
+        match dto {
+            CapabilityDTO::ManageUsers => Capability::ManageUsers,
+            CapabilityDTO::CheckoutOnBehalf => Capability::CheckoutOnBehalf,
+            CapabilityDTO::ViewAllHistory => Capability::ViewAllHistory,
         }
     }
 }
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::event::DomainEvent;
+
+use crate::shared::error::ApplicationError;
+
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn handle(&self, event: &DomainEvent) -> Result<(), ApplicationError>;
+}
+
+/// Destination of the events relayed from the outbox.
+/// Delivery is at-least-once, so publishers must tolerate redelivery.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ApplicationError>;
+}
+
+#[derive(new)]
+pub struct EventBus {
+    subscribers: Vec<Arc<dyn EventSubscriber>>,
+}
+
+#[async_trait]
+impl EventPublisher for EventBus {
+    fn name(&self) -> &'static str {
+        "event_bus"
+    }
+
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ApplicationError> {
+        let mut failed = vec![];
+
+        // Every subscriber gets the event even if an earlier one fails
+        for subscriber in &self.subscribers {
+            if let Err(err) = subscriber.handle(event).await {
+                tracing::error!(
+                    error = ?err,
+                    subscriber = subscriber.name(),
+                    event_id = %event.id(),
+                    "Event subscriber failed"
+                );
+                failed.push(subscriber.name());
+            }
+        }
+
+        match failed.is_empty() {
+            true => Ok(()),
+            false => Err(ApplicationError::InternalError(format!(
+                "Event subscribers failed: {}",
+                failed.join(", ")
+            ))),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod bibliographic;
+mod iso2709;
+mod marcxml;
+mod record;
+
+pub use bibliographic::MarcBibliographic;
+pub use iso2709::{read_iso2709, write_iso2709};
+pub use marcxml::{MARCXML_FOOTER, MARCXML_HEADER, read_marcxml, write_marcxml_record};
+pub use record::*;
@@ -0,0 +1,124 @@
+use crate::shared::marc::record::*;
+
+/// The part of a MARC21 bibliographic record that maps onto a book:
+/// 001 control number, 020 ISBN, 100/700 authors, 245 title and 520 summary.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MarcBibliographic {
+    pub control_number: Option<String>,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub isbn: Option<String>,
+    pub description: Option<String>,
+}
+
+impl MarcBibliographic {
+    pub fn to_record(&self) -> MarcRecord {
+        let mut record = MarcRecord::default();
+
+        if let Some(control_number) = &self.control_number {
+            record.control_fields.push(MarcControlField {
+                tag: "001".to_string(),
+                value: control_number.clone(),
+            });
+        }
+        if let Some(isbn) = &self.isbn {
+            record.data_fields.push(MarcDataField::new(
+                "020",
+                ' ',
+                ' ',
+                vec![('a', isbn.clone())],
+            ));
+        }
+
+        let mut authors = self.authors.iter();
+        let main_entry = authors.next();
+        if let Some(author) = main_entry {
+            record.data_fields.push(MarcDataField::new(
+                "100",
+                '1',
+                ' ',
+                vec![('a', author.clone())],
+            ));
+        }
+
+        // First indicator: whether the title is an added entry besides the main author entry
+        let title_indicator = if main_entry.is_some() { '1' } else { '0' };
+        record.data_fields.push(MarcDataField::new(
+            "245",
+            title_indicator,
+            '0',
+            vec![('a', self.title.clone())],
+        ));
+
+        if let Some(description) = &self.description {
+            record.data_fields.push(MarcDataField::new(
+                "520",
+                ' ',
+                ' ',
+                vec![('a', description.clone())],
+            ));
+        }
+        for author in authors {
+            record.data_fields.push(MarcDataField::new(
+                "700",
+                '1',
+                ' ',
+                vec![('a', author.clone())],
+            ));
+        }
+
+        record
+    }
+
+    /// Records from other systems carry ISBD punctuation and qualifiers, which are dropped.
+    pub fn from_record(record: &MarcRecord) -> Self {
+        let title = record
+            .data_fields("245")
+            .next()
+            .map(|field| {
+                let title = strip_punctuation(field.subfield('a').unwrap_or_default());
+                match field.subfield('b').map(strip_punctuation) {
+                    Some(subtitle) if !subtitle.is_empty() => format!("{}: {}", title, subtitle),
+                    _ => title.to_string(),
+                }
+            })
+            .unwrap_or_default();
+
+        let authors = record
+            .data_fields("100")
+            .chain(record.data_fields("700"))
+            .filter_map(|field| field.subfield('a'))
+            .map(|name| strip_punctuation(name).to_string())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        // `$a 9784000000000 (pbk.)`: the qualifier follows the number
+        let isbn = record
+            .data_fields("020")
+            .filter_map(|field| field.subfield('a'))
+            .filter_map(|isbn| isbn.split_whitespace().next())
+            .map(|isbn| isbn.replace('-', ""))
+            .next();
+
+        let description = record
+            .data_fields("520")
+            .filter_map(|field| field.subfield('a'))
+            .map(|summary| summary.trim().to_string())
+            .find(|summary| !summary.is_empty());
+
+        Self {
+            control_number: record.control_field("001").map(str::to_string),
+            title,
+            authors,
+            isbn,
+            description,
+        }
+    }
+}
+
+fn strip_punctuation(value: &str) -> &str {
+    value
+        .trim()
+        .trim_end_matches([' ', '/', ':', ';', ',', '=', '.'])
+        .trim()
+}
@@ -0,0 +1,217 @@
+use crate::shared::marc::record::*;
+
+const FIELD_TERMINATOR: char = '\u{1E}';
+const RECORD_TERMINATOR: char = '\u{1D}';
+const SUBFIELD_DELIMITER: char = '\u{1F}';
+
+const LEADER_LENGTH: usize = 24;
+const DIRECTORY_ENTRY_LENGTH: usize = 12;
+
+/// Serializes a record as MARC21 exchange format (ISO 2709) with UTF-8 content.
+pub fn write_iso2709(record: &MarcRecord) -> Result<String, MarcError> {
+    let mut directory = String::new();
+    let mut data = String::new();
+
+    let control_fields = record
+        .control_fields
+        .iter()
+        .map(|field| (field.tag.as_str(), field.value.clone()));
+    let data_fields = record.data_fields.iter().map(|field| {
+        let mut content = format!("{}{}", field.ind1, field.ind2);
+        for subfield in &field.subfields {
+            content.push(SUBFIELD_DELIMITER);
+            content.push(subfield.code);
+            content.push_str(&subfield.value);
+        }
+        (field.tag.as_str(), content)
+    });
+
+    for (tag, content) in control_fields.chain(data_fields) {
+        let start = data.len();
+        data.push_str(&content);
+        data.push(FIELD_TERMINATOR);
+
+        let length = data.len() - start;
+        if tag.len() != 3 || length > 9999 || start > 99999 {
+            return Err(MarcError(format!(
+                "Field {} does not fit into ISO 2709",
+                tag
+            )));
+        }
+        directory.push_str(&format!("{}{:04}{:05}", tag, length, start));
+    }
+    directory.push(FIELD_TERMINATOR);
+
+    let base_address = LEADER_LENGTH + directory.len();
+    let record_length = base_address + data.len() + 1;
+    if record_length > 99999 {
+        return Err(MarcError("Record is too long for ISO 2709".to_string()));
+    }
+
+    let leader = match record.leader.len() == LEADER_LENGTH && record.leader.is_ascii() {
+        true => record.leader.as_str(),
+        false => DEFAULT_LEADER,
+    };
+    // Position 09 declares the character coding; everything written here is UTF-8
+    Ok(format!(
+        "{:05}{}a{}{:05}{}{}{}{}",
+        record_length,
+        &leader[5..9],
+        &leader[10..12],
+        base_address,
+        &leader[17..],
+        directory,
+        data,
+        RECORD_TERMINATOR
+    ))
+}
+
+/// Splits a MARC21 exchange file into records. A record that cannot be decoded is
+/// returned as an error in its place so that the remaining records can still be read.
+pub fn read_iso2709(input: &str) -> Vec<Result<MarcRecord, MarcError>> {
+    input
+        .split(RECORD_TERMINATOR)
+        .map(|record| record.trim_start_matches(['\r', '\n']))
+        .filter(|record| !record.trim().is_empty())
+        .map(read_record)
+        .collect()
+}
+
+fn read_record(raw: &str) -> Result<MarcRecord, MarcError> {
+    let leader = raw
+        .get(..LEADER_LENGTH)
+        .filter(|leader| leader.is_ascii())
+        .ok_or_else(|| MarcError("Record does not start with a valid leader".to_string()))?;
+    if leader.as_bytes()[9] != b'a' {
+        return Err(MarcError(
+            "Only UTF-8 records (leader position 09 = 'a') are supported".to_string(),
+        ));
+    }
+
+    let base_address: usize = leader[12..17]
+        .parse()
+        .map_err(|_| MarcError("Invalid base address in leader".to_string()))?;
+    // The leader is not delimited, so a stray terminator inside it must not end the directory
+    let directory_end = raw[LEADER_LENGTH..]
+        .find(FIELD_TERMINATOR)
+        .map(|end| end + LEADER_LENGTH)
+        .filter(|end| *end < base_address)
+        .ok_or_else(|| MarcError("Directory is not terminated".to_string()))?;
+    let directory = &raw[LEADER_LENGTH..directory_end];
+    if !directory.len().is_multiple_of(DIRECTORY_ENTRY_LENGTH) {
+        return Err(MarcError("Directory has a partial entry".to_string()));
+    }
+
+    let mut record = MarcRecord {
+        leader: leader.to_string(),
+        ..Default::default()
+    };
+
+    for entry in directory.as_bytes().chunks(DIRECTORY_ENTRY_LENGTH) {
+        let entry = std::str::from_utf8(entry)
+            .ok()
+            .filter(|entry| entry.is_ascii())
+            .ok_or_else(|| MarcError("Directory is not ASCII".to_string()))?;
+        let tag = &entry[..3];
+        let length: usize = entry[3..7]
+            .parse()
+            .map_err(|_| MarcError(format!("Invalid length for field {}", tag)))?;
+        let start: usize = entry[7..]
+            .parse()
+            .map_err(|_| MarcError(format!("Invalid start position for field {}", tag)))?;
+
+        let content = raw
+            .get(base_address + start..base_address + start + length)
+            .ok_or_else(|| MarcError(format!("Field {} lies outside the record", tag)))?
+            .trim_end_matches(FIELD_TERMINATOR);
+
+        if MarcRecord::is_control_tag(tag) {
+            record.control_fields.push(MarcControlField {
+                tag: tag.to_string(),
+                value: content.to_string(),
+            });
+            continue;
+        }
+
+        let mut parts = content.split(SUBFIELD_DELIMITER);
+        let mut indicators = parts.next().unwrap_or_default().chars();
+        record.data_fields.push(MarcDataField {
+            tag: tag.to_string(),
+            ind1: indicators.next().unwrap_or(' '),
+            ind2: indicators.next().unwrap_or(' '),
+            subfields: parts
+                .filter_map(|part| {
+                    let mut chars = part.chars();
+                    let code = chars.next()?;
+                    Some(MarcSubfield {
+                        code,
+                        value: chars.as_str().to_string(),
+                    })
+                })
+                .collect(),
+        });
+    }
+
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("fixtures/books.mrc");
+
+    fn read_all(input: &str) -> Vec<MarcRecord> {
+        read_iso2709(input)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_fixture_records() {
+        let records = read_all(FIXTURE);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].control_field("001"), Some("bm-0001"));
+        let title = records[0].data_fields("245").next().unwrap();
+        assert_eq!((title.ind1, title.ind2), ('1', '0'));
+        assert_eq!(title.subfield('a'), Some("ドメイン駆動設計入門 :"));
+        assert_eq!(title.subfield('b'), Some("Rust で学ぶ /"));
+        assert_eq!(
+            records[1].data_fields("700").next().unwrap().subfield('a'),
+            Some("Nichols, Carol")
+        );
+    }
+
+    #[test]
+    fn write_reproduces_the_fixture() {
+        let written = read_all(FIXTURE)
+            .iter()
+            .map(|record| write_iso2709(record).unwrap())
+            .collect::<String>();
+
+        assert_eq!(written, FIXTURE);
+        assert_eq!(read_all(&written), read_all(FIXTURE));
+    }
+
+    #[test]
+    fn rejects_records_that_are_not_utf8() {
+        let marc8 = FIXTURE.replacen("nam a22", "nam  22", 1);
+
+        let results = read_iso2709(&marc8);
+
+        assert!(results[0].is_err());
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn field_terminators_in_the_leader_do_not_end_the_directory() {
+        let stray = FIXTURE.replacen("i 4500", "\u{1E} 4500", 1);
+        let leader_only = &stray[..LEADER_LENGTH];
+
+        assert_eq!(read_all(&stray)[1], read_all(FIXTURE)[1]);
+        assert_eq!(read_all(&stray)[0].control_field("001"), Some("bm-0001"));
+        assert!(read_iso2709(leader_only)[0].is_err());
+    }
+}
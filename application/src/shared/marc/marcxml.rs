@@ -0,0 +1,219 @@
+use quick_xml::{
+    Reader,
+    events::{BytesStart, Event},
+};
+
+use crate::shared::marc::record::*;
+
+pub const MARCXML_HEADER: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<collection xmlns=\"http://www.loc.gov/MARC21/slim\">\n";
+pub const MARCXML_FOOTER: &str = "</collection>\n";
+
+/// Renders one `<record>` element; wrap the records in `MARCXML_HEADER` and `MARCXML_FOOTER`.
+pub fn write_marcxml_record(record: &MarcRecord) -> String {
+    let mut output = String::from("  <record>\n");
+    output.push_str(&format!(
+        "    <leader>{}</leader>\n",
+        escape(&record.leader)
+    ));
+    for field in &record.control_fields {
+        output.push_str(&format!(
+            "    <controlfield tag=\"{}\">{}</controlfield>\n",
+            escape(&field.tag),
+            escape(&field.value)
+        ));
+    }
+    for field in &record.data_fields {
+        output.push_str(&format!(
+            "    <datafield tag=\"{}\" ind1=\"{}\" ind2=\"{}\">\n",
+            escape(&field.tag),
+            escape(&field.ind1.to_string()),
+            escape(&field.ind2.to_string())
+        ));
+        for subfield in &field.subfields {
+            output.push_str(&format!(
+                "      <subfield code=\"{}\">{}</subfield>\n",
+                escape(&subfield.code.to_string()),
+                escape(&subfield.value)
+            ));
+        }
+        output.push_str("    </datafield>\n");
+    }
+    output.push_str("  </record>\n");
+    output
+}
+
+/// Reads every `<record>` of a MARCXML document, with or without a `<collection>` around them.
+pub fn read_marcxml(input: &str) -> Result<Vec<MarcRecord>, MarcError> {
+    let mut reader = Reader::from_str(input);
+
+    let mut records = vec![];
+    let mut record: Option<MarcRecord> = None;
+    let mut field: Option<MarcDataField> = None;
+    let mut element: Option<(Element, String)> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| xml_error(&reader, e.to_string()))?;
+        match event {
+            Event::Start(start) => match start.local_name().as_ref() {
+                b"record" => record = Some(MarcRecord::default()),
+                b"leader" => element = Some((Element::Leader, String::new())),
+                b"controlfield" => {
+                    let tag = attribute(&start, "tag")?.unwrap_or_default();
+                    element = Some((Element::ControlField(tag), String::new()));
+                }
+                b"datafield" => {
+                    let tag = attribute(&start, "tag")?.unwrap_or_default();
+                    field = Some(MarcDataField::new(
+                        &tag,
+                        char_attribute(&start, "ind1")?,
+                        char_attribute(&start, "ind2")?,
+                        vec![],
+                    ));
+                }
+                b"subfield" => {
+                    let code = char_attribute(&start, "code")?;
+                    element = Some((Element::Subfield(code), String::new()));
+                }
+                _ => {}
+            },
+            Event::Text(text) => {
+                if let Some((_, content)) = element.as_mut() {
+                    let text = text
+                        .unescape()
+                        .map_err(|e| xml_error(&reader, e.to_string()))?;
+                    content.push_str(&text);
+                }
+            }
+            Event::CData(data) => {
+                if let Some((_, content)) = element.as_mut() {
+                    content.push_str(&String::from_utf8_lossy(&data));
+                }
+            }
+            Event::End(end) => match end.local_name().as_ref() {
+                b"record" => records.extend(record.take()),
+                b"datafield" => {
+                    if let (Some(record), Some(field)) = (record.as_mut(), field.take()) {
+                        record.data_fields.push(field);
+                    }
+                }
+                b"leader" | b"controlfield" | b"subfield" => {
+                    match (element.take(), record.as_mut()) {
+                        (Some((Element::Leader, value)), Some(record)) => record.leader = value,
+                        (Some((Element::ControlField(tag), value)), Some(record)) => {
+                            record.control_fields.push(MarcControlField { tag, value })
+                        }
+                        (Some((Element::Subfield(code), value)), _) => {
+                            if let Some(field) = field.as_mut() {
+                                field.subfields.push(MarcSubfield { code, value });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(records)
+}
+
+enum Element {
+    Leader,
+    ControlField(String),
+    Subfield(char),
+}
+
+fn attribute(start: &BytesStart, name: &str) -> Result<Option<String>, MarcError> {
+    start
+        .try_get_attribute(name)
+        .map_err(|e| MarcError(e.to_string()))?
+        .map(|attribute| {
+            attribute
+                .unescape_value()
+                .map(|value| value.into_owned())
+                .map_err(|e| MarcError(e.to_string()))
+        })
+        .transpose()
+}
+
+/// Indicators and subfield codes are single characters; a missing one reads as blank.
+fn char_attribute(start: &BytesStart, name: &str) -> Result<char, MarcError> {
+    Ok(attribute(start, name)?
+        .and_then(|value| value.chars().next())
+        .unwrap_or(' '))
+}
+
+fn xml_error(reader: &Reader<&[u8]>, message: String) -> MarcError {
+    MarcError(format!(
+        "Invalid MARCXML at byte {}: {}",
+        reader.error_position(),
+        message
+    ))
+}
+
+fn escape(value: &str) -> String {
+    quick_xml::escape::escape(value).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::marc::{read_iso2709, write_iso2709};
+
+    const FIXTURE: &str = include_str!("fixtures/books.xml");
+
+    fn write_collection(records: &[MarcRecord]) -> String {
+        let body = records.iter().map(write_marcxml_record).collect::<String>();
+        format!("{MARCXML_HEADER}{body}{MARCXML_FOOTER}")
+    }
+
+    #[test]
+    fn reads_fixture_records() {
+        let records = read_marcxml(FIXTURE).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].control_field("001"), Some("bm-0001"));
+        assert_eq!(
+            records[0].data_fields("520").next().unwrap().subfield('a'),
+            Some("Entities, value objects & aggregates <explained> — \"in depth\".")
+        );
+        let title = records[1].data_fields("245").next().unwrap();
+        assert_eq!((title.ind1, title.ind2), ('1', '4'));
+        assert_eq!(title.subfield('a'), Some("The Rust programming language /"));
+    }
+
+    #[test]
+    fn write_then_read_round_trips() {
+        let records = read_marcxml(FIXTURE).unwrap();
+
+        let written = write_collection(&records);
+
+        assert_eq!(read_marcxml(&written).unwrap(), records);
+        assert!(written.contains("value objects &amp; aggregates &lt;explained&gt;"));
+    }
+
+    #[test]
+    fn converts_to_iso2709_without_losing_fields() {
+        let records = read_marcxml(FIXTURE).unwrap();
+
+        let converted = records
+            .iter()
+            .map(|record| write_iso2709(record).unwrap())
+            .collect::<String>();
+        let converted = read_iso2709(&converted)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(converted.len(), records.len());
+        for (converted, original) in converted.iter().zip(&records) {
+            assert_eq!(converted.control_fields, original.control_fields);
+            assert_eq!(converted.data_fields, original.data_fields);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use thiserror::Error;
+
+/// Leader of a new record: language material, monograph, UTF-8, ISBD punctuation.
+/// Record length and base address are filled in when the record is written.
+pub const DEFAULT_LEADER: &str = "00000nam a2200000 i 4500";
+
+#[derive(Error, Debug)]
+#[error("{0}")]
+pub struct MarcError(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarcRecord {
+    pub leader: String,
+    pub control_fields: Vec<MarcControlField>,
+    pub data_fields: Vec<MarcDataField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarcControlField {
+    pub tag: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarcDataField {
+    pub tag: String,
+    pub ind1: char,
+    pub ind2: char,
+    pub subfields: Vec<MarcSubfield>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarcSubfield {
+    pub code: char,
+    pub value: String,
+}
+
+impl Default for MarcRecord {
+    fn default() -> Self {
+        Self {
+            leader: DEFAULT_LEADER.to_string(),
+            control_fields: vec![],
+            data_fields: vec![],
+        }
+    }
+}
+
+impl MarcRecord {
+    pub fn control_field(&self, tag: &str) -> Option<&str> {
+        self.control_fields
+            .iter()
+            .find(|field| field.tag == tag)
+            .map(|field| field.value.as_str())
+    }
+
+    pub fn data_fields<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a MarcDataField> {
+        self.data_fields
+            .iter()
+            .filter(move |field| field.tag == tag)
+    }
+
+    /// Control fields are the tags `001` to `009`; everything else carries indicators.
+    pub fn is_control_tag(tag: &str) -> bool {
+        tag.starts_with("00")
+    }
+}
+
+impl MarcDataField {
+    pub fn new(tag: &str, ind1: char, ind2: char, subfields: Vec<(char, String)>) -> Self {
+        Self {
+            tag: tag.to_string(),
+            ind1,
+            ind2,
+            subfields: subfields
+                .into_iter()
+                .map(|(code, value)| MarcSubfield { code, value })
+                .collect(),
+        }
+    }
+
+    pub fn subfield(&self, code: char) -> Option<&str> {
+        self.subfields
+            .iter()
+            .find(|subfield| subfield.code == code)
+            .map(|subfield| subfield.value.as_str())
+    }
+}
@@ -0,0 +1,30 @@
+//! Deserializers for query-string DTOs that `#[serde(flatten)]` another struct.
+//!
+//! serde buffers every field of a struct with a flattened member, and a query string only
+//! provides strings, so numbers and booleans have to be parsed from their text here.
+
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, de::Error};
+
+pub fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(D::Error::custom)
+}
+
+pub fn option_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse().map_err(D::Error::custom))
+        .transpose()
+}
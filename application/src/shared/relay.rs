@@ -0,0 +1,23 @@
+use chrono::{DateTime, Duration, Utc};
+use derive_new::new;
+
+#[derive(Debug, Clone, Copy, new)]
+pub struct RelayPolicy {
+    pub batch_size: u64,
+    pub max_attempts: u32,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    pub lease: Duration,
+}
+
+impl RelayPolicy {
+    /// Returns when the next attempt is due, or `None` once `max_attempts` is exhausted.
+    pub fn next_attempt_at(&self, now: DateTime<Utc>, attempts: u32) -> Option<DateTime<Utc>> {
+        (attempts < self.max_attempts).then(|| now + self.backoff(attempts))
+    }
+
+    fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 2_i32.saturating_pow(attempts.saturating_sub(1));
+        (self.base_backoff * factor).min(self.max_backoff)
+    }
+}
@@ -1,3 +1,9 @@
 mod get_or_create_actor;
+mod update_book_settings;
+mod update_notification_settings;
+mod update_role_permissions;
 
 pub use get_or_create_actor::*;
+pub use update_book_settings::*;
+pub use update_notification_settings::*;
+pub use update_role_permissions::*;
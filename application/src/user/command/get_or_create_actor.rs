@@ -3,6 +3,8 @@ use std::sync::Arc;
 use derive_new::new;
 use domain::{
     audit::{Actor, AuditContext, Clock},
+    auth::capability::CapabilitySet,
+    shared::error::PersistenceError,
     user::{
         entity::User,
         interface::{UserDomainQueryService, UserRepository},
@@ -19,17 +21,38 @@ pub struct GetOrCreateActorService {
 }
 
 impl GetOrCreateActorService {
+    /// The returned actor holds its role's capabilities, narrowed to the scopes if the request has any.
     pub async fn execute(
         &self,
         request: &GetOrCreateUserRequestDTO,
     ) -> Result<Actor, ApplicationError> {
-        let context = AuditContext::new(&Actor::new_system(), self.clock.as_ref());
+        let actor = self.get_or_create(request).await?;
+
+        Ok(match &request.scopes {
+            Some(scopes) => actor.restrict_to(&CapabilitySet::from_scopes(scopes)),
+            None => actor,
+        })
+    }
+
+    async fn get_or_create(
+        &self,
+        request: &GetOrCreateUserRequestDTO,
+    ) -> Result<Actor, ApplicationError> {
+        let context = AuditContext::new(
+            &Actor::new_system(request.tenant.clone()),
+            self.clock.as_ref(),
+        );
 
         if let Some(actor) = self
             .user_domain_query_service
             .find_actor_by_id(request.id)
             .await?
         {
+            // User ids are global, so a user signing in to another tenant is turned away
+            if *actor.tenant() != request.tenant {
+                return Err(ApplicationError::Forbidden);
+            }
+
             // If the user info from the request is different from the existing user, update it
             if actor.name() != request.name || actor.role() != request.role.into() {
                 let mut user_from_request = self
@@ -45,14 +68,12 @@ impl GetOrCreateActorService {
                     request.role.into(),
                 )?;
 
-                self.user_repository.save(&user_from_request).await?;
-
-                Ok(user_from_request.into_actor())
+                self.save_or_reload(&mut user_from_request).await
             } else {
                 Ok(actor)
             }
         } else {
-            let new_user = User::create_new(
+            let mut new_user = User::create_new(
                 &context,
                 request.id,
                 request.name.clone().try_into()?,
@@ -60,9 +81,20 @@ impl GetOrCreateActorService {
                 request.role.into(),
             )?;
 
-            self.user_repository.save(&new_user).await?;
+            self.save_or_reload(&mut new_user).await
+        }
+    }
 
-            Ok(new_user.into_actor())
+    /// Reads the actor back after saving so that it carries the capabilities of its stored role.
+    async fn save_or_reload(&self, user: &mut User) -> Result<Actor, ApplicationError> {
+        match self.user_repository.save(user).await {
+            // On a conflict a concurrent request for the same user won the race; use what it stored
+            Ok(()) | Err(PersistenceError::Conflict) => self
+                .user_domain_query_service
+                .find_actor_by_id(user.audit().id())
+                .await?
+                .ok_or(ApplicationError::NotFound),
+            Err(e) => Err(e.into()),
         }
     }
 }
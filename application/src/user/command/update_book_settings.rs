@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    user::interface::UserRepository,
+};
+
+use crate::{shared::error::ApplicationError, user::dto::UpdateBookSettingsRequestDTO};
+
+#[derive(new)]
+pub struct UpdateBookSettingsService {
+    clock: Arc<dyn Clock>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl UpdateBookSettingsService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        request: &UpdateBookSettingsRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut user = self
+            .user_repository
+            .find_by_id(actor.id())
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        user.update_default_book_visibility(&context, request.default_visibility.into())?;
+
+        self.user_repository.save(&mut user).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    user::interface::UserRepository,
+};
+
+use crate::{shared::error::ApplicationError, user::dto::UpdateNotificationSettingsRequestDTO};
+
+#[derive(new)]
+pub struct UpdateNotificationSettingsService {
+    clock: Arc<dyn Clock>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl UpdateNotificationSettingsService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        request: &UpdateNotificationSettingsRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut user = self
+            .user_repository
+            .find_by_id(actor.id())
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        user.update_notification_preferences(&context, request.into())?;
+
+        self.user_repository.save(&mut user).await?;
+
+        Ok(())
+    }
+}
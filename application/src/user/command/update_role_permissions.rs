@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::Actor,
+    auth::{
+        capability::{Capability, CapabilitySet},
+        interface::RolePermissionRepository,
+    },
+    shared::error::DomainError,
+    user::enums::UserRole,
+};
+
+use crate::{
+    shared::error::ApplicationError,
+    user::dto::{RoleIdentity, UpdateRolePermissionsRequestDTO},
+};
+
+#[derive(new)]
+pub struct UpdateRolePermissionsService {
+    role_permission_repository: Arc<dyn RolePermissionRepository>,
+}
+
+impl UpdateRolePermissionsService {
+    /// Takes effect for each user on their next request, when their actor is loaded again.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: RoleIdentity,
+        request: &UpdateRolePermissionsRequestDTO,
+    ) -> Result<(), ApplicationError> {
+        // Roles are shared by every tenant, so only the default tenant may change them
+        if !actor.has_capability(Capability::ManageUsers) || !actor.tenant().is_default() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        let role: UserRole = identity.role.into();
+        if role == UserRole::System {
+            return Err(DomainError::ValidationError(
+                "The system role always has every capability".to_string(),
+            )
+            .into());
+        }
+
+        let capabilities = request
+            .capabilities
+            .iter()
+            .map(|&c| c.into())
+            .collect::<CapabilitySet>();
+
+        self.role_permission_repository
+            .save(role, &capabilities)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 mod enums;
+mod identity;
 mod request;
 mod response;
 
 pub use enums::*;
+pub use identity::*;
 pub use request::*;
 pub use response::*;
@@ -18,6 +18,7 @@ use strum::EnumString;
 #[strum(ascii_case_insensitive)]
 pub enum UserRoleDTO {
     Admin,
+    Librarian,
     #[default]
     Regular,
     System,
@@ -27,8 +28,20 @@ impl From<UserRoleDTO> for UserRole {
     fn from(dto: UserRoleDTO) -> Self {
         match dto {
             UserRoleDTO::Admin => UserRole::Admin,
+            UserRoleDTO::Librarian => UserRole::Librarian,
             UserRoleDTO::Regular => UserRole::Regular,
             UserRoleDTO::System => UserRole::System,
         }
     }
 }
+
+impl From<UserRole> for UserRoleDTO {
+    fn from(role: UserRole) -> Self {
+        match role {
+            UserRole::Admin => UserRoleDTO::Admin,
+            UserRole::Librarian => UserRoleDTO::Librarian,
+            UserRole::Regular => UserRoleDTO::Regular,
+            UserRole::System => UserRoleDTO::System,
+        }
+    }
+}
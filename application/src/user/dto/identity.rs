@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+use crate::user::dto::UserRoleDTO;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct RoleIdentity {
+    pub role: UserRoleDTO,
+}
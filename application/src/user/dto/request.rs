@@ -1,7 +1,10 @@
-use domain::user::values::UserId;
+use domain::{
+    tenant::values::TenantId,
+    user::values::{NotificationPreferences, UserId},
+};
 use serde::Deserialize;
 
-use crate::user::dto::UserRoleDTO;
+use crate::{book::dto::BookVisibilityDTO, shared::CapabilityDTO, user::dto::UserRoleDTO};
 
 #[derive(Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -10,4 +13,38 @@ pub struct GetOrCreateUserRequestDTO {
     pub name: String,
     pub email: String,
     pub role: UserRoleDTO,
+    pub scopes: Option<Vec<String>>,
+    pub tenant: TenantId,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationSettingsRequestDTO {
+    pub due_soon: bool,
+    pub overdue: bool,
+    pub book_checked_out: bool,
+    pub book_returned: bool,
+}
+
+impl From<&UpdateNotificationSettingsRequestDTO> for NotificationPreferences {
+    fn from(dto: &UpdateNotificationSettingsRequestDTO) -> Self {
+        NotificationPreferences::new(
+            dto.due_soon,
+            dto.overdue,
+            dto.book_checked_out,
+            dto.book_returned,
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBookSettingsRequestDTO {
+    pub default_visibility: BookVisibilityDTO,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRolePermissionsRequestDTO {
+    pub capabilities: Vec<CapabilityDTO>,
 }
@@ -1,7 +1,7 @@
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::user::dto::UserRoleDTO;
+use crate::{book::dto::BookVisibilityDTO, shared::CapabilityDTO, user::dto::UserRoleDTO};
 
 #[derive(Serialize, Debug, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -10,4 +10,27 @@ pub struct UserDetailsDTO {
     pub name: String,
     pub email: String,
     pub role: UserRoleDTO,
+    pub version: u32,
+}
+
+#[derive(Serialize, Debug, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSettingsDTO {
+    pub due_soon: bool,
+    pub overdue: bool,
+    pub book_checked_out: bool,
+    pub book_returned: bool,
+}
+
+#[derive(Serialize, Debug, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BookSettingsDTO {
+    pub default_visibility: BookVisibilityDTO,
+}
+
+#[derive(Serialize, Debug, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePermissionsDTO {
+    pub role: UserRoleDTO,
+    pub capabilities: Vec<CapabilityDTO>,
 }
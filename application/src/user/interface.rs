@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use domain::{shared::error::PersistenceError, user::values::UserId};
 
-use crate::user::dto::UserDetailsDTO;
+use crate::user::dto::{BookSettingsDTO, NotificationSettingsDTO, UserDetailsDTO};
 
 #[async_trait]
 pub trait UserQueryService: Send + Sync {
@@ -9,4 +9,14 @@ pub trait UserQueryService: Send + Sync {
         &self,
         user_id: UserId,
     ) -> Result<Option<UserDetailsDTO>, PersistenceError>;
+
+    async fn get_notification_settings(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationSettingsDTO>, PersistenceError>;
+
+    async fn get_book_settings(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<BookSettingsDTO>, PersistenceError>;
 }
@@ -1,3 +1,9 @@
+pub mod get_book_settings;
+pub mod get_notification_settings;
+pub mod get_role_permissions;
 pub mod get_user_details;
 
+pub use get_book_settings::*;
+pub use get_notification_settings::*;
+pub use get_role_permissions::*;
 pub use get_user_details::*;
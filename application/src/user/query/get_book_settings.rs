@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::{
+    shared::error::ApplicationError,
+    user::{dto::BookSettingsDTO, interface::UserQueryService},
+};
+use derive_new::new;
+use domain::user::values::UserId;
+
+#[derive(new)]
+pub struct GetBookSettingsService {
+    user_query_service: Arc<dyn UserQueryService>,
+}
+
+impl GetBookSettingsService {
+    pub async fn execute(&self, user_id: UserId) -> Result<BookSettingsDTO, ApplicationError> {
+        self.user_query_service
+            .get_book_settings(user_id)
+            .await
+            .map_err(|e| e.into())
+            .and_then(|opt| opt.ok_or(ApplicationError::NotFound))
+    }
+}
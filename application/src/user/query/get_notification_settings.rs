@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    shared::error::ApplicationError,
+    user::{dto::NotificationSettingsDTO, interface::UserQueryService},
+};
+use derive_new::new;
+use domain::user::values::UserId;
+
+#[derive(new)]
+pub struct GetNotificationSettingsService {
+    user_query_service: Arc<dyn UserQueryService>,
+}
+
+impl GetNotificationSettingsService {
+    pub async fn execute(
+        &self,
+        user_id: UserId,
+    ) -> Result<NotificationSettingsDTO, ApplicationError> {
+        self.user_query_service
+            .get_notification_settings(user_id)
+            .await
+            .map_err(|e| e.into())
+            .and_then(|opt| opt.ok_or(ApplicationError::NotFound))
+    }
+}
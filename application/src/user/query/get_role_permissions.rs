@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::Actor,
+    auth::{capability::Capability, interface::RolePermissionRepository},
+    user::enums::UserRole,
+};
+use strum::IntoEnumIterator;
+
+use crate::{shared::error::ApplicationError, user::dto::RolePermissionsDTO};
+
+#[derive(new)]
+pub struct GetRolePermissionsService {
+    role_permission_repository: Arc<dyn RolePermissionRepository>,
+}
+
+impl GetRolePermissionsService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+    ) -> Result<Vec<RolePermissionsDTO>, ApplicationError> {
+        if !actor.has_capability(Capability::ManageUsers) {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        let mut roles = vec![];
+        for role in UserRole::iter().filter(|role| *role != UserRole::System) {
+            let capabilities = self.role_permission_repository.find_by_role(role).await?;
+            roles.push(RolePermissionsDTO {
+                role: role.into(),
+                capabilities: capabilities.iter().map(|c| c.into()).collect(),
+            });
+        }
+
+        Ok(roles)
+    }
+}
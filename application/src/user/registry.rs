@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use domain::{
     audit::Clock,
+    auth::interface::RolePermissionRepository,
     user::interface::{UserDomainQueryService, UserRepository},
 };
 
@@ -9,7 +10,13 @@ use crate::user::{command::*, interface::*, query::*};
 
 pub struct UserRegistry {
     get_or_create_user: Arc<GetOrCreateActorService>,
+    update_notification_settings: Arc<UpdateNotificationSettingsService>,
+    update_book_settings: Arc<UpdateBookSettingsService>,
     get_user_details: Arc<GetUserDetailsService>,
+    get_notification_settings: Arc<GetNotificationSettingsService>,
+    get_book_settings: Arc<GetBookSettingsService>,
+    get_role_permissions: Arc<GetRolePermissionsService>,
+    update_role_permissions: Arc<UpdateRolePermissionsService>,
 }
 
 impl UserRegistry {
@@ -17,6 +24,7 @@ impl UserRegistry {
         repository: Arc<dyn UserRepository>,
         query_service: Arc<dyn UserQueryService>,
         domain_query_service: Arc<dyn UserDomainQueryService>,
+        role_permission_repository: Arc<dyn RolePermissionRepository>,
         clock: Arc<dyn Clock>,
     ) -> Self {
         let get_or_create_actor = GetOrCreateActorService::new(
@@ -24,11 +32,27 @@ impl UserRegistry {
             domain_query_service.clone(),
             repository.clone(),
         );
+        let update_notification_settings =
+            UpdateNotificationSettingsService::new(clock.clone(), repository.clone());
+        let update_book_settings =
+            UpdateBookSettingsService::new(clock.clone(), repository.clone());
         let get_user_details = GetUserDetailsService::new(query_service.clone());
+        let get_notification_settings = GetNotificationSettingsService::new(query_service.clone());
+        let get_book_settings = GetBookSettingsService::new(query_service.clone());
+        let get_role_permissions =
+            GetRolePermissionsService::new(role_permission_repository.clone());
+        let update_role_permissions =
+            UpdateRolePermissionsService::new(role_permission_repository.clone());
 
         UserRegistry {
             get_or_create_user: Arc::new(get_or_create_actor),
+            update_notification_settings: Arc::new(update_notification_settings),
+            update_book_settings: Arc::new(update_book_settings),
             get_user_details: Arc::new(get_user_details),
+            get_notification_settings: Arc::new(get_notification_settings),
+            get_book_settings: Arc::new(get_book_settings),
+            get_role_permissions: Arc::new(get_role_permissions),
+            update_role_permissions: Arc::new(update_role_permissions),
         }
     }
 
@@ -36,7 +60,31 @@ impl UserRegistry {
         self.get_or_create_user.clone()
     }
 
+    pub fn update_notification_settings(&self) -> Arc<UpdateNotificationSettingsService> {
+        self.update_notification_settings.clone()
+    }
+
+    pub fn update_book_settings(&self) -> Arc<UpdateBookSettingsService> {
+        self.update_book_settings.clone()
+    }
+
     pub fn get_user_details(&self) -> Arc<GetUserDetailsService> {
         self.get_user_details.clone()
     }
+
+    pub fn get_notification_settings(&self) -> Arc<GetNotificationSettingsService> {
+        self.get_notification_settings.clone()
+    }
+
+    pub fn get_book_settings(&self) -> Arc<GetBookSettingsService> {
+        self.get_book_settings.clone()
+    }
+
+    pub fn get_role_permissions(&self) -> Arc<GetRolePermissionsService> {
+        self.get_role_permissions.clone()
+    }
+
+    pub fn update_role_permissions(&self) -> Arc<UpdateRolePermissionsService> {
+        self.update_role_permissions.clone()
+    }
 }
@@ -0,0 +1,11 @@
+mod create_webhook;
+mod delete_webhook;
+mod deliver_webhooks;
+mod purge_webhook_deliveries;
+mod send_test_webhook;
+
+pub use create_webhook::*;
+pub use delete_webhook::*;
+pub use deliver_webhooks::*;
+pub use purge_webhook_deliveries::*;
+pub use send_test_webhook::*;
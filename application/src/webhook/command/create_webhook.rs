@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    webhook::{entity::Webhook, interface::WebhookRepository},
+};
+
+use crate::{
+    shared::{EntityCreationDTO, error::ApplicationError},
+    webhook::dto::CreateWebhookRequestDTO,
+};
+
+#[derive(new)]
+pub struct CreateWebhookService {
+    clock: Arc<dyn Clock>,
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl CreateWebhookService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        request: &CreateWebhookRequestDTO,
+    ) -> Result<EntityCreationDTO, ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let mut webhook = Webhook::create_new(
+            &context,
+            request.url.clone().try_into()?,
+            request.event_types.clone().try_into()?,
+            request.secret.clone().try_into()?,
+        )?;
+
+        self.webhook_repository.save(&mut webhook).await?;
+
+        Ok(webhook.audit().into())
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, AuditContext, Clock},
+    webhook::interface::WebhookRepository,
+};
+
+use crate::{shared::error::ApplicationError, webhook::dto::WebhookIdentity};
+
+#[derive(new)]
+pub struct DeleteWebhookService {
+    clock: Arc<dyn Clock>,
+    webhook_repository: Arc<dyn WebhookRepository>,
+}
+
+impl DeleteWebhookService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: WebhookIdentity,
+    ) -> Result<(), ApplicationError> {
+        let context = AuditContext::new(actor, self.clock.as_ref());
+
+        let webhook = self
+            .webhook_repository
+            .find_by_id(identity.webhook_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+
+        webhook.validate_deletion(&context)?;
+
+        self.webhook_repository.delete(webhook.audit().id()).await?;
+
+        Ok(())
+    }
+}
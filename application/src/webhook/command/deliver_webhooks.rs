@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    shared::{error::ApplicationError, relay::RelayPolicy},
+    webhook::interface::{WebhookDelivery, WebhookDeliveryRepository, WebhookSender},
+};
+
+#[derive(new)]
+pub struct DeliverWebhooksService {
+    clock: Arc<dyn Clock>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    sender: Arc<dyn WebhookSender>,
+    policy: RelayPolicy,
+}
+
+impl DeliverWebhooksService {
+    /// Sends one batch of due deliveries and returns how many were claimed.
+    pub async fn execute(&self) -> Result<usize, ApplicationError> {
+        let now = self.clock.now();
+
+        let deliveries = self
+            .delivery_repository
+            .claim_due(now, now + self.policy.lease, self.policy.batch_size)
+            .await?;
+
+        for delivery in &deliveries {
+            let attempt = attempt_delivery(
+                self.sender.as_ref(),
+                self.delivery_repository.as_ref(),
+                self.clock.as_ref(),
+                delivery,
+                |now, attempts| self.policy.next_attempt_at(now, attempts),
+            )
+            .await?;
+
+            if let Some(error) = attempt.error {
+                tracing::warn!(
+                    delivery_id = %delivery.id,
+                    attempts = delivery.attempts + 1,
+                    response_status = ?attempt.response_status,
+                    error,
+                    "Webhook delivery failed"
+                );
+            }
+        }
+
+        Ok(deliveries.len())
+    }
+}
+
+pub(super) struct DeliveryAttempt {
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+}
+
+/// Sends a delivery once and records the outcome.
+pub(super) async fn attempt_delivery(
+    sender: &dyn WebhookSender,
+    delivery_repository: &dyn WebhookDeliveryRepository,
+    clock: &dyn Clock,
+    delivery: &WebhookDelivery,
+    next_attempt_at: impl Fn(DateTime<Utc>, u32) -> Option<DateTime<Utc>>,
+) -> Result<DeliveryAttempt, ApplicationError> {
+    let result = sender.send(delivery, clock.now()).await;
+
+    let now = clock.now();
+    let (response_status, error) = match result {
+        Ok(response) if response.is_success() => {
+            delivery_repository
+                .mark_delivered(delivery.id, response.status, now)
+                .await?;
+            return Ok(DeliveryAttempt {
+                response_status: Some(response.status),
+                error: None,
+            });
+        }
+        Ok(response) => (
+            Some(response.status),
+            format!("Unexpected response status {}", response.status),
+        ),
+        Err(err) => (None, err),
+    };
+
+    let attempts = delivery.attempts + 1;
+    delivery_repository
+        .mark_failed(
+            delivery.id,
+            attempts,
+            response_status,
+            &error,
+            next_attempt_at(now, attempts),
+        )
+        .await?;
+
+    Ok(DeliveryAttempt {
+        response_status,
+        error: Some(error),
+    })
+}
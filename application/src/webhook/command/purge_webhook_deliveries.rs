@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use derive_new::new;
+use domain::audit::Clock;
+
+use crate::{
+    job::interface::Job, shared::error::ApplicationError,
+    webhook::interface::WebhookDeliveryRepository,
+};
+
+#[derive(new)]
+pub struct PurgeWebhookDeliveriesService {
+    clock: Arc<dyn Clock>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    retention: Duration,
+}
+
+impl PurgeWebhookDeliveriesService {
+    pub async fn execute(&self) -> Result<u64, ApplicationError> {
+        self.delivery_repository
+            .purge_finished(self.clock.now() - self.retention)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[async_trait]
+impl Job for PurgeWebhookDeliveriesService {
+    fn name(&self) -> &'static str {
+        "webhook_delivery_cleanup"
+    }
+
+    async fn run(&self) -> Result<(), ApplicationError> {
+        let deleted = self.execute().await?;
+        tracing::info!(deleted, "Purged finished webhook deliveries");
+        Ok(())
+    }
+}
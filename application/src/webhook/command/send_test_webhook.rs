@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    audit::{Actor, Clock},
+    webhook::interface::WebhookRepository,
+};
+use uuid::Uuid;
+
+use crate::{
+    shared::error::ApplicationError,
+    webhook::{
+        command::deliver_webhooks::attempt_delivery,
+        dto::{WebhookIdentity, WebhookTestResultDTO},
+        interface::{
+            NewWebhookDelivery, WebhookDelivery, WebhookDeliveryRepository, WebhookSender,
+        },
+    },
+};
+
+const TEST_EVENT_TYPE: &str = "webhook_test";
+
+#[derive(new)]
+pub struct SendTestWebhookService {
+    clock: Arc<dyn Clock>,
+    webhook_repository: Arc<dyn WebhookRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    sender: Arc<dyn WebhookSender>,
+}
+
+impl SendTestWebhookService {
+    /// Sends a synthetic event right away; the attempt is recorded but never retried.
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: WebhookIdentity,
+    ) -> Result<WebhookTestResultDTO, ApplicationError> {
+        if !actor.is_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        let webhook = self
+            .webhook_repository
+            .find_by_id(identity.webhook_id)
+            .await?
+            .ok_or(ApplicationError::NotFound)?;
+        webhook.audit().ensure_tenant(actor)?;
+
+        let now = self.clock.now();
+        let event_id = Uuid::new_v4();
+        let payload = serde_json::json!({
+            "id": event_id,
+            "occurredAt": now,
+            "actorId": actor.id(),
+            "tenantId": actor.tenant(),
+            "kind": { "type": TEST_EVENT_TYPE, "webhookId": webhook.audit().id() },
+        })
+        .to_string();
+
+        let new_delivery = NewWebhookDelivery {
+            id: Uuid::new_v4(),
+            webhook_id: webhook.audit().id(),
+            event_id,
+            event_type: TEST_EVENT_TYPE.into(),
+            payload: payload.clone(),
+            created_at: now,
+        };
+        self.delivery_repository
+            .enqueue(std::slice::from_ref(&new_delivery))
+            .await?;
+
+        let delivery = WebhookDelivery {
+            id: new_delivery.id,
+            url: webhook.url().into(),
+            secret: webhook.secret().into(),
+            event_type: new_delivery.event_type,
+            payload,
+            attempts: 0,
+        };
+
+        let attempt = attempt_delivery(
+            self.sender.as_ref(),
+            self.delivery_repository.as_ref(),
+            self.clock.as_ref(),
+            &delivery,
+            |_, _| None,
+        )
+        .await?;
+
+        Ok(WebhookTestResultDTO {
+            delivery_id: delivery.id,
+            success: attempt.error.is_none(),
+            response_status: attempt.response_status,
+            error: attempt.error,
+        })
+    }
+}
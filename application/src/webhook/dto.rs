@@ -0,0 +1,11 @@
+mod enums;
+mod identity;
+mod query;
+mod request;
+mod response;
+
+pub use enums::*;
+pub use identity::*;
+pub use query::*;
+pub use request::*;
+pub use response::*;
@@ -0,0 +1,8 @@
+use domain::webhook::values::WebhookId;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub struct WebhookIdentity {
+    pub webhook_id: WebhookId,
+}
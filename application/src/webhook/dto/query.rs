@@ -0,0 +1,25 @@
+use garde::Validate;
+use serde::Deserialize;
+
+use crate::webhook::dto::WebhookDeliveryStatusDTO;
+
+#[derive(Debug, Deserialize, Validate, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct WebhookDeliveryQueryDTO {
+    #[garde(range(min = 1))]
+    #[serde(default = "default_page_size")]
+    pub page_size: u64,
+    #[garde(range(min = 1))]
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[garde(skip)]
+    pub status: Option<WebhookDeliveryStatusDTO>,
+}
+
+const fn default_page_size() -> u64 {
+    10
+}
+
+const fn default_page() -> u64 {
+    1
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookRequestDTO {
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub secret: String,
+}
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    shared::{AuditDTO, PaginationDTO},
+    webhook::dto::WebhookDeliveryStatusDTO,
+};
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDTO {
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub audit: AuditDTO,
+}
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryDTO {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub status: WebhookDeliveryStatusDTO,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+pub type WebhookDeliveryListDTO = PaginationDTO<WebhookDeliveryDTO>;
+
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookTestResultDTO {
+    pub delivery_id: Uuid,
+    pub success: bool,
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    audit::Actor, shared::error::PersistenceError, tenant::values::TenantId,
+    webhook::values::WebhookId,
+};
+use uuid::Uuid;
+
+use crate::webhook::dto::*;
+
+pub struct NewWebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: WebhookId,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub payload: String,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_type: String,
+    pub payload: String,
+    pub attempts: u32,
+}
+
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Deliveries already enqueued for the same webhook and event are skipped.
+    async fn enqueue(&self, deliveries: &[NewWebhookDelivery]) -> Result<(), PersistenceError>;
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError>;
+
+    async fn mark_delivered(
+        &self,
+        id: Uuid,
+        response_status: u16,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError>;
+
+    /// Schedules the next attempt, or gives up on the delivery when `next_attempt_at` is `None`.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        attempts: u32,
+        response_status: Option<u16>,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError>;
+
+    /// Deletes delivered and failed deliveries created before `before` and returns how many were deleted.
+    async fn purge_finished(&self, before: DateTime<Utc>) -> Result<u64, PersistenceError>;
+}
+
+pub struct WebhookResponse {
+    pub status: u16,
+}
+
+impl WebhookResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Sends the signed payload; transport failures are reported as `Err`.
+    async fn send(
+        &self,
+        delivery: &WebhookDelivery,
+        timestamp: DateTime<Utc>,
+    ) -> Result<WebhookResponse, String>;
+}
+
+#[async_trait]
+pub trait WebhookQueryService: Send + Sync {
+    async fn get_webhooks(&self, actor: &Actor) -> Result<Vec<WebhookDTO>, PersistenceError>;
+
+    async fn find_subscribed(
+        &self,
+        tenant: &TenantId,
+        event_type: &str,
+    ) -> Result<Vec<WebhookId>, PersistenceError>;
+
+    /// A webhook of another tenant has no deliveries.
+    async fn get_deliveries(
+        &self,
+        tenant: &TenantId,
+        identity: WebhookIdentity,
+        query: &WebhookDeliveryQueryDTO,
+    ) -> Result<WebhookDeliveryListDTO, PersistenceError>;
+}
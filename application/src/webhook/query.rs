@@ -0,0 +1,5 @@
+mod get_webhook_deliveries;
+mod get_webhooks;
+
+pub use get_webhook_deliveries::*;
+pub use get_webhooks::*;
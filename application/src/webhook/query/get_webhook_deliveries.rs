@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+use garde::Validate;
+
+use crate::{
+    shared::error::ApplicationError,
+    webhook::{
+        dto::{WebhookDeliveryListDTO, WebhookDeliveryQueryDTO, WebhookIdentity},
+        interface::WebhookQueryService,
+    },
+};
+
+#[derive(new)]
+pub struct GetWebhookDeliveriesService {
+    webhook_query_service: Arc<dyn WebhookQueryService>,
+}
+
+impl GetWebhookDeliveriesService {
+    pub async fn execute(
+        &self,
+        actor: &Actor,
+        identity: WebhookIdentity,
+        query: &WebhookDeliveryQueryDTO,
+    ) -> Result<WebhookDeliveryListDTO, ApplicationError> {
+        if !actor.is_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        query.validate()?;
+
+        self.webhook_query_service
+            .get_deliveries(actor.tenant(), identity, query)
+            .await
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::audit::Actor;
+
+use crate::{
+    shared::error::ApplicationError,
+    webhook::{dto::WebhookDTO, interface::WebhookQueryService},
+};
+
+#[derive(new)]
+pub struct GetWebhooksService {
+    webhook_query_service: Arc<dyn WebhookQueryService>,
+}
+
+impl GetWebhooksService {
+    pub async fn execute(&self, actor: &Actor) -> Result<Vec<WebhookDTO>, ApplicationError> {
+        if !actor.is_admin() {
+            return Err(ApplicationError::Forbidden);
+        }
+
+        self.webhook_query_service
+            .get_webhooks(actor)
+            .await
+            .map_err(|e| e.into())
+    }
+}
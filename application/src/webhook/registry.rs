@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use domain::{audit::Clock, webhook::interface::WebhookRepository};
+
+use crate::{
+    shared::relay::RelayPolicy,
+    webhook::{command::*, interface::*, query::*, subscriber::WebhookEventSubscriber},
+};
+
+pub struct WebhookRegistry {
+    create_webhook: Arc<CreateWebhookService>,
+    delete_webhook: Arc<DeleteWebhookService>,
+    send_test_webhook: Arc<SendTestWebhookService>,
+    deliver_webhooks: Arc<DeliverWebhooksService>,
+    purge_webhook_deliveries: Arc<PurgeWebhookDeliveriesService>,
+    get_webhooks: Arc<GetWebhooksService>,
+    get_webhook_deliveries: Arc<GetWebhookDeliveriesService>,
+    event_subscriber: Arc<WebhookEventSubscriber>,
+}
+
+impl WebhookRegistry {
+    pub fn new(
+        repository: Arc<dyn WebhookRepository>,
+        query_service: Arc<dyn WebhookQueryService>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        sender: Arc<dyn WebhookSender>,
+        policy: RelayPolicy,
+        retention: Duration,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let create_webhook = CreateWebhookService::new(clock.clone(), repository.clone());
+        let delete_webhook = DeleteWebhookService::new(clock.clone(), repository.clone());
+        let send_test_webhook = SendTestWebhookService::new(
+            clock.clone(),
+            repository.clone(),
+            delivery_repository.clone(),
+            sender.clone(),
+        );
+        let deliver_webhooks = DeliverWebhooksService::new(
+            clock.clone(),
+            delivery_repository.clone(),
+            sender.clone(),
+            policy,
+        );
+        let purge_webhook_deliveries = PurgeWebhookDeliveriesService::new(
+            clock.clone(),
+            delivery_repository.clone(),
+            retention,
+        );
+
+        let get_webhooks = GetWebhooksService::new(query_service.clone());
+        let get_webhook_deliveries = GetWebhookDeliveriesService::new(query_service.clone());
+
+        let event_subscriber = WebhookEventSubscriber::new(
+            clock.clone(),
+            query_service.clone(),
+            delivery_repository.clone(),
+        );
+
+        WebhookRegistry {
+            create_webhook: Arc::new(create_webhook),
+            delete_webhook: Arc::new(delete_webhook),
+            send_test_webhook: Arc::new(send_test_webhook),
+            deliver_webhooks: Arc::new(deliver_webhooks),
+            purge_webhook_deliveries: Arc::new(purge_webhook_deliveries),
+            get_webhooks: Arc::new(get_webhooks),
+            get_webhook_deliveries: Arc::new(get_webhook_deliveries),
+            event_subscriber: Arc::new(event_subscriber),
+        }
+    }
+
+    pub fn create_webhook(&self) -> Arc<CreateWebhookService> {
+        self.create_webhook.clone()
+    }
+
+    pub fn delete_webhook(&self) -> Arc<DeleteWebhookService> {
+        self.delete_webhook.clone()
+    }
+
+    pub fn send_test_webhook(&self) -> Arc<SendTestWebhookService> {
+        self.send_test_webhook.clone()
+    }
+
+    pub fn deliver_webhooks(&self) -> Arc<DeliverWebhooksService> {
+        self.deliver_webhooks.clone()
+    }
+
+    pub fn purge_webhook_deliveries(&self) -> Arc<PurgeWebhookDeliveriesService> {
+        self.purge_webhook_deliveries.clone()
+    }
+
+    pub fn get_webhooks(&self) -> Arc<GetWebhooksService> {
+        self.get_webhooks.clone()
+    }
+
+    pub fn get_webhook_deliveries(&self) -> Arc<GetWebhookDeliveriesService> {
+        self.get_webhook_deliveries.clone()
+    }
+
+    pub fn event_subscriber(&self) -> Arc<WebhookEventSubscriber> {
+        self.event_subscriber.clone()
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{audit::Clock, event::DomainEvent};
+use uuid::Uuid;
+
+use crate::{
+    shared::{error::ApplicationError, event::EventSubscriber},
+    webhook::interface::{NewWebhookDelivery, WebhookDeliveryRepository, WebhookQueryService},
+};
+
+/// Fans each event out into one pending delivery per subscribed webhook.
+#[derive(new)]
+pub struct WebhookEventSubscriber {
+    clock: Arc<dyn Clock>,
+    webhook_query_service: Arc<dyn WebhookQueryService>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+}
+
+#[async_trait]
+impl EventSubscriber for WebhookEventSubscriber {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<(), ApplicationError> {
+        let webhook_ids = self
+            .webhook_query_service
+            .find_subscribed(event.tenant_id(), event.event_type())
+            .await?;
+
+        if webhook_ids.is_empty() {
+            return Ok(());
+        }
+
+        let payload = serde_json::to_string(event)
+            .map_err(|e| ApplicationError::InternalError(e.to_string()))?;
+        let now = self.clock.now();
+
+        let deliveries = webhook_ids
+            .into_iter()
+            .map(|webhook_id| NewWebhookDelivery {
+                id: Uuid::new_v4(),
+                webhook_id,
+                event_id: event.id(),
+                event_type: event.event_type().into(),
+                payload: payload.clone(),
+                created_at: now,
+            })
+            .collect::<Vec<_>>();
+
+        self.delivery_repository.enqueue(&deliveries).await?;
+
+        Ok(())
+    }
+}
@@ -6,4 +6,4 @@ mod entity_audit;
 pub use actor::Actor;
 pub use audit_context::AuditContext;
 pub use clock::Clock;
-pub use entity_audit::EntityAudit;
+pub use entity_audit::{EntityAudit, PersistedAudit};
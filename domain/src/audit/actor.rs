@@ -1,14 +1,22 @@
 use uuid::Uuid;
 
-use crate::user::{
-    enums::UserRole,
-    values::{UserId, UserReference},
+use crate::{
+    auth::capability::{Capability, CapabilitySet},
+    group::values::GroupId,
+    tenant::values::TenantId,
+    user::{
+        enums::UserRole,
+        values::{UserId, UserReference},
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Actor {
     pub(super) user: UserReference,
     pub(super) role: UserRole,
+    pub(super) capabilities: CapabilitySet,
+    pub(super) groups: Vec<GroupId>,
+    pub(super) tenant: TenantId,
 }
 
 impl Actor {
@@ -30,18 +38,61 @@ impl Actor {
     pub fn is_system(&self) -> bool {
         self.role == UserRole::System
     }
+    pub fn capabilities(&self) -> &CapabilitySet {
+        &self.capabilities
+    }
+    pub fn has_capability(&self, capability: Capability) -> bool {
+        self.capabilities.contains(capability)
+    }
+    pub fn groups(&self) -> &[GroupId] {
+        &self.groups
+    }
+    pub fn is_member_of(&self, group_id: GroupId) -> bool {
+        self.groups.contains(&group_id)
+    }
+    pub fn tenant(&self) -> &TenantId {
+        &self.tenant
+    }
+    /// The system actor works across tenants; everyone else stays within their own.
+    pub fn can_access_tenant(&self, tenant: &TenantId) -> bool {
+        self.is_system() || self.tenant == *tenant
+    }
+    /// Admins of the default tenant operate the deployment itself (outbox, jobs and roles).
+    pub fn is_deployment_admin(&self) -> bool {
+        self.is_admin() && self.tenant.is_default()
+    }
 
-    pub fn hydrate(id: Uuid, name: String, role: UserRole) -> Self {
+    pub fn hydrate(
+        id: Uuid,
+        name: String,
+        role: UserRole,
+        capabilities: CapabilitySet,
+        groups: Vec<GroupId>,
+        tenant: TenantId,
+    ) -> Self {
         Actor {
             user: UserReference::hydrate(id, name),
             role,
+            capabilities,
+            groups,
+            tenant,
         }
     }
 
-    pub fn new_system() -> Self {
+    /// Narrows the role's capabilities to those the access token was granted.
+    pub fn restrict_to(mut self, granted: &CapabilitySet) -> Self {
+        self.capabilities = self.capabilities.intersection(granted);
+        self
+    }
+
+    /// Entities the system actor creates belong to `tenant`.
+    pub fn new_system(tenant: TenantId) -> Self {
         Actor {
             user: UserReference::hydrate(Uuid::default(), "System".to_string()),
             role: UserRole::System,
+            capabilities: CapabilitySet::all(),
+            groups: vec![],
+            tenant,
         }
     }
 }
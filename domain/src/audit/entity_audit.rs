@@ -2,19 +2,35 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
-    audit::AuditContext,
+    audit::{Actor, AuditContext},
     auth::permission::Permission,
     shared::{EntityIdTrait, error::DomainError},
+    tenant::values::TenantId,
     user::values::UserReference,
 };
 
+/// Audit columns as they are stored, used to rebuild an [`EntityAudit`].
+pub struct PersistedAudit {
+    pub id: Uuid,
+    pub tenant: String,
+    pub created_at: DateTime<Utc>,
+    pub created_by_id: Uuid,
+    pub created_by_name: String,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub updated_by_id: Option<Uuid>,
+    pub updated_by_name: Option<String>,
+    pub version: u32,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct EntityAudit<EId: EntityIdTrait> {
     id: EId,
+    tenant: TenantId,
     created_at: DateTime<Utc>,
     created_by: UserReference,
     updated_at: Option<DateTime<Utc>>,
     updated_by: Option<UserReference>,
+    version: u32,
 }
 
 impl<EId: EntityIdTrait> EntityAudit<EId> {
@@ -24,6 +40,9 @@ impl<EId: EntityIdTrait> EntityAudit<EId> {
     pub fn raw_id(&self) -> Uuid {
         self.id.into()
     }
+    pub fn tenant(&self) -> &TenantId {
+        &self.tenant
+    }
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -36,25 +55,22 @@ impl<EId: EntityIdTrait> EntityAudit<EId> {
     pub fn updated_by(&self) -> Option<&UserReference> {
         self.updated_by.as_ref()
     }
+    pub fn version(&self) -> u32 {
+        self.version
+    }
 
-    pub fn hydrate(
-        id: Uuid,
-        created_at: DateTime<Utc>,
-        created_by_id: Uuid,
-        created_by_name: String,
-        updated_at: Option<DateTime<Utc>>,
-        updated_by_id: Option<Uuid>,
-        updated_by_name: Option<String>,
-    ) -> Self {
+    pub fn hydrate(persisted: PersistedAudit) -> Self {
         EntityAudit {
-            id: id.into(),
-            created_at,
-            created_by: UserReference::hydrate(created_by_id, created_by_name),
-            updated_at,
-            updated_by: match (updated_by_id, updated_by_name) {
+            id: persisted.id.into(),
+            tenant: TenantId::hydrate(persisted.tenant),
+            created_at: persisted.created_at,
+            created_by: UserReference::hydrate(persisted.created_by_id, persisted.created_by_name),
+            updated_at: persisted.updated_at,
+            updated_by: match (persisted.updated_by_id, persisted.updated_by_name) {
                 (Some(id), Some(name)) => Some(UserReference::hydrate(id, name)),
                 _ => None,
             },
+            version: persisted.version,
         }
     }
 
@@ -76,10 +92,12 @@ impl<EId: EntityIdTrait> EntityAudit<EId> {
 
         Ok(EntityAudit {
             id,
+            tenant: context.actor().tenant().clone(),
             created_at: context.timestamp(),
             created_by: context.actor_user().clone(),
             updated_at: None,
             updated_by: None,
+            version: 0,
         })
     }
 
@@ -88,6 +106,8 @@ impl<EId: EntityIdTrait> EntityAudit<EId> {
         context: &AuditContext,
         permission: &dyn Permission,
     ) -> Result<(), DomainError> {
+        self.ensure_tenant(context.actor())?;
+
         if !permission.can_update() {
             return Err(DomainError::Forbidden);
         }
@@ -97,4 +117,25 @@ impl<EId: EntityIdTrait> EntityAudit<EId> {
 
         Ok(())
     }
+
+    /// Moves to the version the repository has just written, so the entity can be saved again.
+    pub fn mark_saved(&mut self) {
+        self.version += 1;
+    }
+
+    /// Entities of another tenant are reported as missing rather than forbidden.
+    pub fn ensure_tenant(&self, actor: &Actor) -> Result<(), DomainError> {
+        match actor.can_access_tenant(&self.tenant) {
+            true => Ok(()),
+            false => Err(DomainError::NotFound),
+        }
+    }
+
+    /// Checks the version the client last saw (e.g. from `If-Match`) against the loaded one.
+    pub fn ensure_version(&self, expected: Option<u32>) -> Result<(), DomainError> {
+        match expected {
+            Some(expected) if expected != self.version => Err(DomainError::VersionMismatch),
+            _ => Ok(()),
+        }
+    }
 }
@@ -1 +1,3 @@
+pub mod capability;
+pub mod interface;
 pub mod permission;
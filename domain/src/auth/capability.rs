@@ -0,0 +1,85 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
+
+/// A named permission that is granted to a role rather than derived from entity ownership.
+#[derive(
+    Debug, EnumString, AsRefStr, EnumIter, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Capability {
+    ManageUsers,
+    CheckoutOnBehalf,
+    ViewAllHistory,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CapabilitySet(BTreeSet<Capability>);
+
+impl CapabilitySet {
+    pub fn all() -> Self {
+        CapabilitySet(Capability::iter().collect())
+    }
+
+    /// Reads OAuth scope values, ignoring those that do not name a capability.
+    pub fn from_scopes<S: AsRef<str>>(scopes: &[S]) -> Self {
+        CapabilitySet(
+            scopes
+                .iter()
+                .filter_map(|scope| Capability::from_str(scope.as_ref()).ok())
+                .collect(),
+        )
+    }
+
+    pub fn contains(&self, capability: Capability) -> bool {
+        self.0.contains(&capability)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn intersection(&self, other: &CapabilitySet) -> Self {
+        CapabilitySet(self.0.intersection(&other.0).copied().collect())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromIterator<Capability> for CapabilitySet {
+    fn from_iter<I: IntoIterator<Item = Capability>>(iter: I) -> Self {
+        CapabilitySet(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_that_do_not_name_a_capability_are_ignored() {
+        let granted = CapabilitySet::from_scopes(&["openid", "view-all-history", "manage-tags"]);
+
+        assert_eq!(
+            granted.iter().collect::<Vec<_>>(),
+            vec![Capability::ViewAllHistory]
+        );
+        assert!(CapabilitySet::from_scopes(&["openid", "profile", "email"]).is_empty());
+    }
+
+    #[test]
+    fn intersection_keeps_only_shared_capabilities() {
+        let librarian: CapabilitySet = [Capability::CheckoutOnBehalf, Capability::ViewAllHistory]
+            .into_iter()
+            .collect();
+        let granted = CapabilitySet::from_scopes(&["manage-users", "view-all-history"]);
+
+        assert_eq!(
+            librarian.intersection(&granted).iter().collect::<Vec<_>>(),
+            vec![Capability::ViewAllHistory]
+        );
+        assert_eq!(CapabilitySet::all().intersection(&granted), granted);
+    }
+}
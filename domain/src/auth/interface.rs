@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::{
+    auth::capability::CapabilitySet, shared::error::PersistenceError, user::enums::UserRole,
+};
+
+/// The capabilities granted to each role, kept in the database so they can change without a deploy.
+#[async_trait]
+pub trait RolePermissionRepository: Send + Sync {
+    async fn find_by_role(&self, role: UserRole) -> Result<CapabilitySet, PersistenceError>;
+    async fn save(
+        &self,
+        role: UserRole,
+        capabilities: &CapabilitySet,
+    ) -> Result<(), PersistenceError>;
+}
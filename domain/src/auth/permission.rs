@@ -1,11 +1,17 @@
 use derive_new::new;
 
-use crate::{audit::Actor, user::values::UserId};
+use crate::{
+    audit::Actor, auth::capability::CapabilitySet, group::values::GroupId, user::values::UserId,
+};
 
 pub trait Permission {
     fn can_create(&self) -> bool;
     fn can_update(&self) -> bool;
     fn can_delete(&self) -> bool;
+    /// The acting user's named permissions, reported alongside the entity-level checks.
+    fn capabilities(&self) -> CapabilitySet {
+        CapabilitySet::default()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -27,12 +33,17 @@ impl Permission for AdminPermission {
     fn can_delete(&self) -> bool {
         self.0.is_admin()
     }
+    fn capabilities(&self) -> CapabilitySet {
+        self.0.capabilities().clone()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct EntityPermission {
     actor: Option<Actor>,
     owner_user_id: UserId,
+    co_owner_ids: Vec<UserId>,
+    group_id: Option<GroupId>,
 }
 
 impl EntityPermission {
@@ -40,12 +51,31 @@ impl EntityPermission {
         EntityPermission {
             actor: actor.cloned(),
             owner_user_id,
+            co_owner_ids: vec![],
+            group_id: None,
         }
     }
 
+    /// Grants the same rights as the owner to these users.
+    pub fn with_co_owners(mut self, co_owner_ids: impl IntoIterator<Item = UserId>) -> Self {
+        self.co_owner_ids.extend(co_owner_ids);
+        self
+    }
+
+    /// Grants the same rights as the owner to the members of the group that holds the entity.
+    pub fn with_group(mut self, group_id: Option<GroupId>) -> Self {
+        self.group_id = group_id;
+        self
+    }
+
     fn can_edit(&self) -> bool {
         match self.actor {
-            Some(ref actor) => actor.is_admin() || actor.id() == self.owner_user_id,
+            Some(ref actor) => {
+                actor.is_admin()
+                    || actor.id() == self.owner_user_id
+                    || self.co_owner_ids.contains(&actor.id())
+                    || self.group_id.is_some_and(|g| actor.is_member_of(g))
+            }
             None => false,
         }
     }
@@ -61,6 +91,47 @@ impl Permission for EntityPermission {
     fn can_delete(&self) -> bool {
         self.can_edit()
     }
+    fn capabilities(&self) -> CapabilitySet {
+        self.actor
+            .as_ref()
+            .map(|actor| actor.capabilities().clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Group admins manage their group; system admins may step in.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GroupPermission {
+    actor: Actor,
+    admin_ids: Vec<UserId>,
+}
+
+impl GroupPermission {
+    pub fn new(actor: &Actor, admin_ids: Vec<UserId>) -> Self {
+        GroupPermission {
+            actor: actor.clone(),
+            admin_ids,
+        }
+    }
+
+    fn can_manage(&self) -> bool {
+        self.actor.is_admin() || self.admin_ids.contains(&self.actor.id())
+    }
+}
+
+impl Permission for GroupPermission {
+    fn can_create(&self) -> bool {
+        self.can_manage()
+    }
+    fn can_update(&self) -> bool {
+        self.can_manage()
+    }
+    fn can_delete(&self) -> bool {
+        self.can_manage()
+    }
+    fn capabilities(&self) -> CapabilitySet {
+        self.actor.capabilities().clone()
+    }
 }
 
 #[derive(Debug, new, PartialEq, Eq)]
@@ -1,3 +1,4 @@
 pub mod entity;
+pub mod enums;
 pub mod interface;
 pub mod values;
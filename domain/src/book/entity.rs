@@ -1,3 +1,3 @@
 pub mod book_entity;
 
-pub use book_entity::Book;
+pub use book_entity::{Book, NewBook, PersistedBook};
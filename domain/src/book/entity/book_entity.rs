@@ -1,11 +1,39 @@
 use crate::{
     audit::{Actor, AuditContext, EntityAudit},
     auth::permission::{AdminPermission, EntityPermission, Permission},
-    book::values::*,
+    book::{enums::BookVisibility, values::*},
+    event::{DomainEvent, DomainEventKind},
+    group::values::GroupId,
     shared::error::DomainError,
     user::values::UserReference,
 };
 
+/// A book as it is stored, used to rebuild a [`Book`]. `checkouts` only needs the active
+/// checkout and the latest one; the aggregate never looks further back than that.
+pub struct PersistedBook {
+    pub audit: EntityAudit<BookId>,
+    pub title: String,
+    pub authors: Vec<(BookAuthorName, usize)>,
+    pub isbn: Option<String>,
+    pub description: Option<String>,
+    pub owner: UserReference,
+    pub co_owners: Vec<UserReference>,
+    pub group_id: Option<GroupId>,
+    pub visibility: BookVisibility,
+    pub checkouts: Vec<BookCheckout>,
+}
+
+/// The validated values a new [`Book`] starts with.
+pub struct NewBook {
+    pub title: BookTitle,
+    pub authors: BookAuthorList,
+    pub isbn: BookIsbn,
+    pub description: BookDescription,
+    pub owner: BookOwner,
+    pub group_id: Option<GroupId>,
+    pub visibility: BookVisibility,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Book {
     audit: EntityAudit<BookId>,
@@ -14,7 +42,12 @@ pub struct Book {
     isbn: BookIsbn,
     description: BookDescription,
     owner: BookOwner,
+    co_owners: BookCoOwnerList,
+    group_id: Option<GroupId>,
+    visibility: BookVisibility,
     checkouts: BookCheckoutList,
+    changes: BookChanges,
+    events: Vec<DomainEvent>,
 }
 
 impl Book {
@@ -36,49 +69,86 @@ impl Book {
     pub fn owner(&self) -> &UserReference {
         self.owner.raw()
     }
+    pub fn co_owners(&self) -> &[UserReference] {
+        self.co_owners.raw()
+    }
+    pub fn group_id(&self) -> Option<GroupId> {
+        self.group_id
+    }
+    pub fn visibility(&self) -> BookVisibility {
+        self.visibility
+    }
     pub fn checkouts(&self) -> &[BookCheckout] {
         self.checkouts.raw()
     }
+    pub fn is_checked_out(&self) -> bool {
+        self.checkouts.is_checked_out()
+    }
+    pub fn changes(&self) -> &BookChanges {
+        &self.changes
+    }
+    pub fn changed_checkouts(&self) -> impl Iterator<Item = &BookCheckout> {
+        self.checkouts()
+            .iter()
+            .filter(|checkout| self.changes.is_checkout_changed(checkout.id()))
+    }
+    pub fn events(&self) -> &[DomainEvent] {
+        &self.events
+    }
 
-    pub fn hydrate(
-        audit: EntityAudit<BookId>,
-        title: String,
-        authors: Vec<(BookAuthorName, usize)>,
-        isbn: Option<String>,
-        description: Option<String>,
-        owner: UserReference,
-        checkouts: Vec<BookCheckout>,
-    ) -> Self {
+    /// Called once the book is written: the events are in the outbox, the child rows are up to
+    /// date and the row carries the next version.
+    pub fn mark_saved(&mut self) {
+        self.audit.mark_saved();
+        self.changes = BookChanges::default();
+        self.events.clear();
+    }
+
+    pub fn hydrate(persisted: PersistedBook) -> Self {
         Book {
-            audit,
-            title: BookTitle::hydrate(title),
-            authors: BookAuthorList::hydrate(authors),
-            isbn: BookIsbn::hydrate(isbn),
-            description: BookDescription::hydrate(description),
-            owner: BookOwner::hydrate(owner),
-            checkouts: BookCheckoutList::hydrate(checkouts),
+            audit: persisted.audit,
+            title: BookTitle::hydrate(persisted.title),
+            authors: BookAuthorList::hydrate(persisted.authors),
+            isbn: BookIsbn::hydrate(persisted.isbn),
+            description: BookDescription::hydrate(persisted.description),
+            owner: BookOwner::hydrate(persisted.owner),
+            co_owners: BookCoOwnerList::hydrate(persisted.co_owners),
+            group_id: persisted.group_id,
+            visibility: persisted.visibility,
+            checkouts: BookCheckoutList::hydrate(persisted.checkouts),
+            changes: BookChanges::default(),
+            events: vec![],
         }
     }
 
-    pub fn create_new(
-        context: &AuditContext,
-        title: BookTitle,
-        authors: BookAuthorList,
-        isbn: BookIsbn,
-        description: BookDescription,
-        owner: BookOwner,
-    ) -> Result<Self, DomainError> {
-        let permission = EntityPermission::new(Some(context.actor()), owner.id());
+    pub fn create_new(context: &AuditContext, new: NewBook) -> Result<Self, DomainError> {
+        let permission = EntityPermission::new(Some(context.actor()), new.owner.id());
+        Self::ensure_group_access(context.actor(), new.group_id)?;
 
-        Ok(Self {
+        let mut book = Self {
             audit: EntityAudit::create_new(context, &permission)?,
-            title,
-            authors,
-            isbn,
-            description,
-            owner,
+            title: new.title,
+            authors: new.authors,
+            isbn: new.isbn,
+            description: new.description,
+            owner: new.owner,
+            co_owners: BookCoOwnerList::default(),
+            group_id: new.group_id,
+            visibility: new.visibility,
             checkouts: BookCheckoutList::hydrate(vec![]),
-        })
+            changes: BookChanges::new_book(),
+            events: vec![],
+        };
+        book.record_event(
+            context,
+            DomainEventKind::BookCreated {
+                book_id: book.audit.id(),
+                owner_id: book.owner.id(),
+                title: book.title.raw().to_string(),
+            },
+        );
+
+        Ok(book)
     }
 
     pub fn update(
@@ -88,19 +158,35 @@ impl Book {
         authors: BookAuthorList,
         isbn: BookIsbn,
         description: BookDescription,
+        visibility: BookVisibility,
     ) -> Result<(), DomainError> {
         let permission = self.permission_to_update(context.actor());
 
         self.audit.mark_updated(context, &permission)?;
         self.title = title;
-        self.authors = authors;
+        if self.authors != authors {
+            self.authors = authors;
+            self.changes.mark_authors();
+        }
         self.isbn = isbn;
         self.description = description;
+        self.visibility = visibility;
+
+        self.record_event(
+            context,
+            DomainEventKind::BookUpdated {
+                book_id: self.audit.id(),
+                owner_id: self.owner.id(),
+                title: self.title.raw().to_string(),
+            },
+        );
 
         Ok(())
     }
 
     pub fn validate_deletion(&self, context: &AuditContext) -> Result<(), DomainError> {
+        self.audit.ensure_tenant(context.actor())?;
+
         let permission = self.permission_to_update(context.actor());
 
         match permission.can_delete() {
@@ -109,12 +195,50 @@ impl Book {
         }
     }
 
-    pub fn do_checkout(&mut self, context: &AuditContext) -> Result<(), DomainError> {
-        self.checkouts.do_checkout(context)
+    pub fn do_checkout(
+        &mut self,
+        context: &AuditContext,
+        borrower: UserReference,
+    ) -> Result<(), DomainError> {
+        self.audit.ensure_tenant(context.actor())?;
+        // A private book is lent by those who manage it; to anyone else it does not exist
+        if !self.is_visible_to(context.actor()) {
+            return Err(DomainError::NotFound);
+        }
+
+        let checkout = self.checkouts.do_checkout(context, borrower)?;
+        self.changes.mark_checkout(checkout.id());
+
+        self.record_event(
+            context,
+            DomainEventKind::BookCheckedOut {
+                book_id: self.audit.id(),
+                owner_id: self.owner.id(),
+                checkout_id: checkout.id(),
+                checked_out_to: checkout.checked_out_to().id(),
+            },
+        );
+
+        Ok(())
     }
 
     pub fn do_return(&mut self, context: &AuditContext) -> Result<(), DomainError> {
-        self.checkouts.do_return(context)
+        self.audit.ensure_tenant(context.actor())?;
+
+        let checkout = self.checkouts.do_return(context)?;
+        self.changes.mark_checkout(checkout.id());
+
+        self.record_event(
+            context,
+            DomainEventKind::BookReturned {
+                book_id: self.audit.id(),
+                owner_id: self.owner.id(),
+                checkout_id: checkout.id(),
+                checked_out_to: checkout.checked_out_to().id(),
+            },
+        );
+
+        Ok(())
     }
 
     pub fn change_owner(
@@ -125,12 +249,221 @@ impl Book {
         let permission = AdminPermission::new(context.actor());
 
         self.audit.mark_updated(context, &permission)?;
+        let previous_owner_id = self.owner.id();
         self.owner = owner;
 
+        self.record_event(
+            context,
+            DomainEventKind::OwnerChanged {
+                book_id: self.audit.id(),
+                previous_owner_id,
+                new_owner_id: self.owner.id(),
+            },
+        );
+
+        // The owner already manages the book, so a co-owner taking over is no longer listed
+        if self.co_owners.contains(self.owner.id()) {
+            self.co_owners = self.co_owners.without(self.owner.id());
+            self.changes.mark_co_owners();
+
+            self.record_event(
+                context,
+                DomainEventKind::CoOwnersChanged {
+                    book_id: self.audit.id(),
+                    owner_id: self.owner.id(),
+                    co_owner_ids: self.co_owners.ids(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Only the owner (or an admin) decides who else manages the book.
+    pub fn update_co_owners(
+        &mut self,
+        context: &AuditContext,
+        co_owners: BookCoOwnerList,
+    ) -> Result<(), DomainError> {
+        let permission = EntityPermission::new(Some(context.actor()), self.owner.id());
+
+        if co_owners.contains(self.owner.id()) {
+            return Err(DomainError::ValidationError(
+                "Book owner cannot also be a co-owner".to_string(),
+            ));
+        }
+        self.audit.mark_updated(context, &permission)?;
+        if self.co_owners != co_owners {
+            self.co_owners = co_owners;
+            self.changes.mark_co_owners();
+        }
+
+        self.record_event(
+            context,
+            DomainEventKind::CoOwnersChanged {
+                book_id: self.audit.id(),
+                owner_id: self.owner.id(),
+                co_owner_ids: self.co_owners.ids(),
+            },
+        );
+
         Ok(())
     }
 
+    /// Moves the book into a group's library, or back out of it with `None`.
+    pub fn change_group(
+        &mut self,
+        context: &AuditContext,
+        group_id: Option<GroupId>,
+    ) -> Result<(), DomainError> {
+        let permission = self.permission_to_update(context.actor());
+
+        self.audit.mark_updated(context, &permission)?;
+        Self::ensure_group_access(context.actor(), group_id)?;
+        self.group_id = group_id;
+
+        self.record_event(
+            context,
+            DomainEventKind::BookUpdated {
+                book_id: self.audit.id(),
+                owner_id: self.owner.id(),
+                title: self.title.raw().to_string(),
+            },
+        );
+
+        Ok(())
+    }
+
+    fn is_visible_to(&self, actor: &Actor) -> bool {
+        self.visibility != BookVisibility::Private || self.permission_to_update(actor).can_update()
+    }
+
     fn permission_to_update(&self, actor: &Actor) -> EntityPermission {
         EntityPermission::new(Some(actor), self.owner.id())
+            .with_co_owners(self.co_owners.ids())
+            .with_group(self.group_id)
+    }
+
+    /// Only members (or admins) may put a book into a group's library.
+    fn ensure_group_access(actor: &Actor, group_id: Option<GroupId>) -> Result<(), DomainError> {
+        match group_id {
+            Some(group_id) if !actor.is_admin() && !actor.is_member_of(group_id) => {
+                Err(DomainError::Forbidden)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn record_event(&mut self, context: &AuditContext, kind: DomainEventKind) {
+        self.events.push(DomainEvent::new(context, kind));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        audit::clock::SystemClock, auth::capability::CapabilitySet, tenant::values::TenantId,
+        user::enums::UserRole,
+    };
+
+    fn actor(name: &str, role: UserRole) -> Actor {
+        Actor::hydrate(
+            Uuid::new_v4(),
+            name.to_string(),
+            role,
+            CapabilitySet::default(),
+            vec![],
+            TenantId::default(),
+        )
+    }
+
+    fn book_with_co_owners(owner: &Actor, co_owners: &[&Actor]) -> Book {
+        let context = AuditContext::new(owner, &SystemClock);
+        let mut book = Book::create_new(
+            &context,
+            NewBook {
+                title: "Dune".to_string().try_into().unwrap(),
+                authors: vec!["Frank Herbert".to_string()].try_into().unwrap(),
+                isbn: None.try_into().unwrap(),
+                description: None.try_into().unwrap(),
+                owner: owner.into(),
+                group_id: None,
+                visibility: BookVisibility::Public,
+            },
+        )
+        .unwrap();
+        book.update_co_owners(&context, co_owner_list(co_owners))
+            .unwrap();
+        book
+    }
+
+    fn co_owner_list(co_owners: &[&Actor]) -> BookCoOwnerList {
+        co_owners
+            .iter()
+            .map(|&actor| UserReference::from(actor))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn a_co_owner_taking_over_the_book_leaves_the_co_owners() {
+        let owner = actor("owner", UserRole::Regular);
+        let first = actor("first", UserRole::Regular);
+        let second = actor("second", UserRole::Regular);
+        let admin = actor("admin", UserRole::Admin);
+        let mut book = book_with_co_owners(&owner, &[&first, &second]);
+        book.mark_saved();
+
+        book.change_owner(&AuditContext::new(&admin, &SystemClock), (&first).into())
+            .unwrap();
+
+        assert_eq!(book.owner().id(), first.id());
+        assert_eq!(book.co_owners.ids(), vec![second.id()]);
+        assert!(book.changes().co_owners_changed());
+        assert!(matches!(
+            book.events().last().unwrap().kind(),
+            DomainEventKind::CoOwnersChanged { co_owner_ids, .. } if *co_owner_ids == vec![second.id()]
+        ));
+    }
+
+    #[test]
+    fn handing_the_book_to_someone_else_keeps_the_co_owners() {
+        let owner = actor("owner", UserRole::Regular);
+        let co_owner = actor("co-owner", UserRole::Regular);
+        let admin = actor("admin", UserRole::Admin);
+        let mut book = book_with_co_owners(&owner, &[&co_owner]);
+        book.mark_saved();
+
+        book.change_owner(
+            &AuditContext::new(&admin, &SystemClock),
+            (&actor("new owner", UserRole::Regular)).into(),
+        )
+        .unwrap();
+
+        assert_eq!(book.co_owners.ids(), vec![co_owner.id()]);
+        assert!(!book.changes().co_owners_changed());
+    }
+
+    #[test]
+    fn listing_the_owner_as_co_owner_is_rejected_without_touching_the_book() {
+        let owner = actor("owner", UserRole::Regular);
+        let co_owner = actor("co-owner", UserRole::Regular);
+        let mut book = book_with_co_owners(&owner, &[&co_owner]);
+        book.mark_saved();
+        let updated_at = book.audit().updated_at();
+
+        let result = book.update_co_owners(
+            &AuditContext::new(&owner, &SystemClock),
+            co_owner_list(&[&co_owner, &owner]),
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        assert_eq!(book.audit().updated_at(), updated_at);
+        assert_eq!(book.co_owners.ids(), vec![co_owner.id()]);
+        assert!(book.events().is_empty());
     }
 }
@@ -0,0 +1,14 @@
+use strum::{AsRefStr, EnumString};
+
+/// Who may see a book besides those who manage it.
+#[derive(Debug, Default, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+#[strum(serialize_all = "snake_case")]
+pub enum BookVisibility {
+    /// Everyone, including anonymous visitors.
+    #[default]
+    Public,
+    /// Signed-in users of the same tenant.
+    Internal,
+    /// Only the owner, co-owners and members of the book's group, plus admins.
+    Private,
+}
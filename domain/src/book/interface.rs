@@ -8,6 +8,7 @@ use crate::{
 #[async_trait]
 pub trait BookRepository: Send + Sync {
     async fn find_by_id(&self, id: BookId) -> Result<Option<Book>, PersistenceError>;
-    async fn save(&self, book: &Book) -> Result<(), PersistenceError>;
-    async fn delete(&self, id: BookId) -> Result<(), PersistenceError>;
+    async fn save(&self, book: &mut Book) -> Result<(), PersistenceError>;
+    /// Deletes the book unless it was saved again since it was loaded.
+    async fn delete(&self, book: &Book) -> Result<(), PersistenceError>;
 }
@@ -1,6 +1,8 @@
 mod book_author_list;
 mod book_author_name;
+mod book_changes;
 mod book_checkout;
+mod book_co_owner_list;
 mod book_description;
 mod book_isbn;
 mod book_owner;
@@ -10,7 +12,9 @@ use crate::define_id;
 
 pub use book_author_list::*;
 pub use book_author_name::BookAuthorName;
+pub use book_changes::BookChanges;
 pub use book_checkout::{BookCheckout, BookCheckoutList};
+pub use book_co_owner_list::BookCoOwnerList;
 pub use book_description::BookDescription;
 pub use book_isbn::BookIsbn;
 pub use book_owner::BookOwner;
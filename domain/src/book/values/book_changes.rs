@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+/// What a `Book` has changed since it was hydrated, so that saving it only writes the
+/// affected child rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookChanges {
+    authors: bool,
+    co_owners: bool,
+    checkouts: Vec<Uuid>,
+}
+
+impl BookChanges {
+    pub fn new_book() -> Self {
+        BookChanges {
+            authors: true,
+            co_owners: true,
+            checkouts: vec![],
+        }
+    }
+
+    pub fn authors_changed(&self) -> bool {
+        self.authors
+    }
+    pub fn co_owners_changed(&self) -> bool {
+        self.co_owners
+    }
+    pub fn is_checkout_changed(&self, checkout_id: Uuid) -> bool {
+        self.checkouts.contains(&checkout_id)
+    }
+
+    pub fn mark_authors(&mut self) {
+        self.authors = true;
+    }
+    pub fn mark_co_owners(&mut self) {
+        self.co_owners = true;
+    }
+    pub fn mark_checkout(&mut self, checkout_id: Uuid) {
+        if !self.checkouts.contains(&checkout_id) {
+            self.checkouts.push(checkout_id);
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::{audit::AuditContext, shared::error::DomainError, user::values::UserReference};
+use crate::{
+    audit::AuditContext, auth::capability::Capability, shared::error::DomainError,
+    user::values::UserReference,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BookCheckoutList(Vec<BookCheckout>);
@@ -12,6 +15,8 @@ pub enum BookCheckout {
     Returned {
         checkout: CheckoutRecord,
         returned_at: DateTime<Utc>,
+        /// `None` for returns recorded before the returning user was tracked.
+        returned_by: Option<UserReference>,
     },
 }
 
@@ -19,6 +24,7 @@ pub enum BookCheckout {
 pub struct CheckoutRecord {
     checkout_id: Uuid,
     checked_out_to: UserReference,
+    checked_out_by: UserReference,
     checked_out_at: DateTime<Utc>,
 }
 
@@ -27,35 +33,49 @@ impl BookCheckoutList {
         Self(checkouts)
     }
 
-    pub fn do_checkout(&mut self, context: &AuditContext) -> Result<(), DomainError> {
+    /// Lending to someone other than the actor requires `CheckoutOnBehalf`.
+    pub fn do_checkout(
+        &mut self,
+        context: &AuditContext,
+        borrower: UserReference,
+    ) -> Result<BookCheckout, DomainError> {
+        let actor = context.actor();
+        if borrower.id() != actor.id() && !actor.has_capability(Capability::CheckoutOnBehalf) {
+            return Err(DomainError::Forbidden);
+        }
+
         if self.is_checked_out() {
-            return Err(DomainError::ValidationError(
-                "Book is already checked out".to_string(),
-            ));
+            return Err(DomainError::AlreadyCheckedOut);
         }
 
-        self.0.push(BookCheckout::Active(CheckoutRecord {
+        let checkout = BookCheckout::Active(CheckoutRecord {
             checkout_id: Uuid::new_v4(),
-            checked_out_to: context.actor().into(),
+            checked_out_to: borrower,
+            checked_out_by: actor.into(),
             checked_out_at: context.timestamp(),
-        }));
+        });
+        self.0.push(checkout.clone());
 
-        Ok(())
+        Ok(checkout)
     }
 
-    pub fn do_return(&mut self, context: &AuditContext) -> Result<(), DomainError> {
+    pub fn do_return(&mut self, context: &AuditContext) -> Result<BookCheckout, DomainError> {
         if let Some((idx, latest)) = self.latest_active_with_idx() {
             let actor = context.actor();
-            if latest.checked_out_to.id() != actor.id() && !actor.is_admin() {
+            if latest.checked_out_to.id() != actor.id()
+                && !actor.has_capability(Capability::CheckoutOnBehalf)
+            {
                 return Err(DomainError::Forbidden);
             }
 
-            self.0[idx] = BookCheckout::Returned {
+            let returned = BookCheckout::Returned {
                 checkout: latest.clone(),
                 returned_at: context.timestamp(),
+                returned_by: Some(actor.into()),
             };
+            self.0[idx] = returned.clone();
 
-            Ok(())
+            Ok(returned)
         } else if self.is_returned() {
             Err(DomainError::ValidationError(
                 "Book has already been returned".to_string(),
@@ -70,6 +90,11 @@ impl BookCheckoutList {
     pub fn raw(&self) -> &[BookCheckout] {
         &self.0
     }
+    pub fn is_checked_out(&self) -> bool {
+        self.latest()
+            .map(|checkout| matches!(checkout, BookCheckout::Active(_)))
+            .unwrap_or(false)
+    }
 
     fn latest(&self) -> Option<&BookCheckout> {
         self.0.iter().max_by_key(|checkout| match checkout {
@@ -90,11 +115,6 @@ impl BookCheckoutList {
             })
             .max_by_key(|(_, record)| record.checked_out_at)
     }
-    fn is_checked_out(&self) -> bool {
-        self.latest()
-            .map(|checkout| matches!(checkout, BookCheckout::Active(_)))
-            .unwrap_or(false)
-    }
     fn is_returned(&self) -> bool {
         self.latest()
             .map(|checkout| matches!(checkout, BookCheckout::Returned { .. }))
@@ -106,18 +126,22 @@ impl BookCheckout {
     pub fn hydrate(
         checkout_id: Uuid,
         checked_out_to: UserReference,
+        checked_out_by: UserReference,
         checked_out_at: DateTime<Utc>,
         returned_at: Option<DateTime<Utc>>,
+        returned_by: Option<UserReference>,
     ) -> Self {
         let checkout = CheckoutRecord {
             checkout_id,
             checked_out_to,
+            checked_out_by,
             checked_out_at,
         };
         match returned_at {
             Some(returned_at) => BookCheckout::Returned {
                 checkout,
                 returned_at,
+                returned_by,
             },
             None => BookCheckout::Active(checkout),
         }
@@ -142,7 +166,10 @@ impl BookCheckout {
         }
     }
     pub fn checked_out_by(&self) -> &UserReference {
-        self.checked_out_to()
+        match self {
+            BookCheckout::Active(checkout) => &checkout.checked_out_by,
+            BookCheckout::Returned { checkout, .. } => &checkout.checked_out_by,
+        }
     }
     pub fn returned_at(&self) -> Option<DateTime<Utc>> {
         match self {
@@ -150,4 +177,10 @@ impl BookCheckout {
             BookCheckout::Returned { returned_at, .. } => Some(*returned_at),
         }
     }
+    pub fn returned_by(&self) -> Option<&UserReference> {
+        match self {
+            BookCheckout::Active(_) => None,
+            BookCheckout::Returned { returned_by, .. } => returned_by.as_ref(),
+        }
+    }
 }
@@ -0,0 +1,52 @@
+use itertools::Itertools;
+
+use crate::{
+    shared::error::DomainError,
+    user::values::{UserId, UserReference},
+};
+
+/// Users who manage a book alongside its owner, such as the rest of a team that bought it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookCoOwnerList(Vec<UserReference>);
+
+impl BookCoOwnerList {
+    pub fn hydrate(co_owners: Vec<UserReference>) -> Self {
+        Self(co_owners)
+    }
+
+    pub fn raw(&self) -> &[UserReference] {
+        &self.0
+    }
+
+    pub fn ids(&self) -> Vec<UserId> {
+        self.0.iter().map(|user| user.id()).collect()
+    }
+
+    pub fn contains(&self, user_id: UserId) -> bool {
+        self.0.iter().any(|user| user.id() == user_id)
+    }
+
+    pub fn without(&self, user_id: UserId) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|user| user.id() != user_id)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<Vec<UserReference>> for BookCoOwnerList {
+    type Error = DomainError;
+
+    fn try_from(value: Vec<UserReference>) -> Result<Self, Self::Error> {
+        if !value.iter().map(|user| user.id()).all_unique() {
+            return Err(DomainError::ValidationError(
+                "Co-owner list contains duplicate users".to_string(),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
@@ -0,0 +1,3 @@
+mod domain_event;
+
+pub use domain_event::{DomainEvent, DomainEventKind};
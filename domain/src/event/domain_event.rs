@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantNames};
+use uuid::Uuid;
+
+use crate::{
+    audit::AuditContext,
+    book::values::BookId,
+    tenant::values::TenantId,
+    user::{enums::UserRole, values::UserId},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainEvent {
+    id: Uuid,
+    occurred_at: DateTime<Utc>,
+    actor_id: UserId,
+    // Events recorded before tenants existed belong to the default tenant
+    #[serde(default)]
+    tenant_id: TenantId,
+    kind: DomainEventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, IntoStaticStr, VariantNames)]
+#[serde(
+    tag = "type",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum DomainEventKind {
+    BookCreated {
+        book_id: BookId,
+        owner_id: UserId,
+        title: String,
+    },
+    BookUpdated {
+        book_id: BookId,
+        owner_id: UserId,
+        title: String,
+    },
+    BookCheckedOut {
+        book_id: BookId,
+        owner_id: UserId,
+        checkout_id: Uuid,
+        checked_out_to: UserId,
+    },
+    BookReturned {
+        book_id: BookId,
+        owner_id: UserId,
+        checkout_id: Uuid,
+        checked_out_to: UserId,
+    },
+    OwnerChanged {
+        book_id: BookId,
+        previous_owner_id: UserId,
+        new_owner_id: UserId,
+    },
+    CoOwnersChanged {
+        book_id: BookId,
+        owner_id: UserId,
+        co_owner_ids: Vec<UserId>,
+    },
+    UserRoleChanged {
+        user_id: UserId,
+        previous_role: UserRole,
+        new_role: UserRole,
+    },
+}
+
+impl DomainEvent {
+    pub fn new(context: &AuditContext, kind: DomainEventKind) -> Self {
+        DomainEvent {
+            id: Uuid::new_v4(),
+            occurred_at: context.timestamp(),
+            actor_id: context.actor().id(),
+            tenant_id: context.actor().tenant().clone(),
+            kind,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+    pub fn actor_id(&self) -> UserId {
+        self.actor_id
+    }
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+    pub fn kind(&self) -> &DomainEventKind {
+        &self.kind
+    }
+    pub fn event_type(&self) -> &'static str {
+        (&self.kind).into()
+    }
+}
+
+impl DomainEventKind {
+    /// The book the event is about, if any.
+    pub fn book_id(&self) -> Option<BookId> {
+        match self {
+            DomainEventKind::BookCreated { book_id, .. }
+            | DomainEventKind::BookUpdated { book_id, .. }
+            | DomainEventKind::BookCheckedOut { book_id, .. }
+            | DomainEventKind::BookReturned { book_id, .. }
+            | DomainEventKind::OwnerChanged { book_id, .. }
+            | DomainEventKind::CoOwnersChanged { book_id, .. } => Some(*book_id),
+            DomainEventKind::UserRoleChanged { .. } => None,
+        }
+    }
+}
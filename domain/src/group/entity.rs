@@ -0,0 +1,3 @@
+pub mod group_entity;
+
+pub use group_entity::Group;
@@ -0,0 +1,134 @@
+use crate::{
+    audit::{Actor, AuditContext, EntityAudit},
+    auth::permission::{EntityPermission, GroupPermission, Permission},
+    group::{enums::GroupMemberRole, values::*},
+    shared::error::DomainError,
+    user::values::UserId,
+};
+
+/// A team with its own members and library. Books join the library through
+/// `Book::change_group`; with `private_library` set they are only listed to members.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Group {
+    audit: EntityAudit<GroupId>,
+    name: GroupName,
+    private_library: bool,
+    members: GroupMemberList,
+}
+
+impl Group {
+    pub fn audit(&self) -> &EntityAudit<GroupId> {
+        &self.audit
+    }
+    pub fn name(&self) -> &str {
+        self.name.raw()
+    }
+    pub fn private_library(&self) -> bool {
+        self.private_library
+    }
+    pub fn members(&self) -> &[GroupMember] {
+        self.members.raw()
+    }
+
+    /// Called once the group is written and its row carries the next version.
+    pub fn mark_saved(&mut self) {
+        self.audit.mark_saved();
+    }
+
+    pub fn hydrate(
+        audit: EntityAudit<GroupId>,
+        name: String,
+        private_library: bool,
+        members: Vec<GroupMember>,
+    ) -> Self {
+        Group {
+            audit,
+            name: GroupName::hydrate(name),
+            private_library,
+            members: GroupMemberList::hydrate(members),
+        }
+    }
+
+    /// Anyone may start a group and becomes its first admin.
+    pub fn create_new(
+        context: &AuditContext,
+        name: GroupName,
+        private_library: bool,
+    ) -> Result<Self, DomainError> {
+        let creator = context.actor_user().clone();
+        let permission = EntityPermission::new(Some(context.actor()), creator.id());
+
+        Ok(Group {
+            audit: EntityAudit::create_new(context, &permission)?,
+            name,
+            private_library,
+            members: GroupMemberList::hydrate(vec![GroupMember::new(
+                creator,
+                GroupMemberRole::Admin,
+            )]),
+        })
+    }
+
+    pub fn update(
+        &mut self,
+        context: &AuditContext,
+        name: GroupName,
+        private_library: bool,
+    ) -> Result<(), DomainError> {
+        let permission = self.permission(context.actor());
+
+        self.audit.mark_updated(context, &permission)?;
+        self.name = name;
+        self.private_library = private_library;
+
+        Ok(())
+    }
+
+    /// Adds a member or changes the role of an existing one.
+    pub fn upsert_member(
+        &mut self,
+        context: &AuditContext,
+        member: GroupMember,
+    ) -> Result<(), DomainError> {
+        let permission = self.permission(context.actor());
+
+        self.audit.mark_updated(context, &permission)?;
+        self.members = self.members.upsert(member)?;
+
+        Ok(())
+    }
+
+    /// Group admins remove members; members may also leave on their own.
+    pub fn remove_member(
+        &mut self,
+        context: &AuditContext,
+        user_id: UserId,
+    ) -> Result<(), DomainError> {
+        let as_admin = self.permission(context.actor());
+        let as_member = EntityPermission::new(Some(context.actor()), user_id);
+        let permission: &dyn Permission = match context.actor().id() == user_id {
+            true => &as_member,
+            false => &as_admin,
+        };
+
+        self.audit.mark_updated(context, permission)?;
+        self.members = self.members.remove(user_id)?;
+
+        Ok(())
+    }
+
+    pub fn validate_deletion(&self, context: &AuditContext) -> Result<(), DomainError> {
+        self.audit.ensure_tenant(context.actor())?;
+
+        let permission = self.permission(context.actor());
+
+        match permission.can_delete() {
+            true => Ok(()),
+            false => Err(DomainError::Forbidden),
+        }
+    }
+
+    fn permission(&self, actor: &Actor) -> GroupPermission {
+        GroupPermission::new(actor, self.members.admin_ids())
+    }
+}
@@ -0,0 +1,8 @@
+use strum::{AsRefStr, EnumString};
+
+#[derive(Debug, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+#[strum(ascii_case_insensitive)]
+pub enum GroupMemberRole {
+    Admin,
+    Member,
+}
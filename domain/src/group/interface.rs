@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::{
+    group::{entity::Group, values::GroupId},
+    shared::error::PersistenceError,
+};
+
+#[async_trait]
+pub trait GroupRepository: Send + Sync {
+    async fn find_by_id(&self, id: GroupId) -> Result<Option<Group>, PersistenceError>;
+    async fn save(&self, group: &mut Group) -> Result<(), PersistenceError>;
+    /// Deletes the group unless it was saved again since it was loaded.
+    async fn delete(&self, group: &Group) -> Result<(), PersistenceError>;
+}
@@ -0,0 +1,9 @@
+mod group_member_list;
+mod group_name;
+
+use crate::define_id;
+
+pub use group_member_list::{GroupMember, GroupMemberList};
+pub use group_name::GroupName;
+
+define_id!(GroupId);
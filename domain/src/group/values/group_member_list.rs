@@ -0,0 +1,87 @@
+use crate::{
+    group::enums::GroupMemberRole,
+    shared::error::DomainError,
+    user::values::{UserId, UserReference},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMember {
+    user: UserReference,
+    role: GroupMemberRole,
+}
+
+impl GroupMember {
+    pub fn new(user: UserReference, role: GroupMemberRole) -> Self {
+        Self { user, role }
+    }
+
+    pub fn user(&self) -> &UserReference {
+        &self.user
+    }
+    pub fn role(&self) -> GroupMemberRole {
+        self.role
+    }
+    pub fn is_admin(&self) -> bool {
+        self.role == GroupMemberRole::Admin
+    }
+}
+
+/// A group always keeps at least one admin, so that someone can still manage it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMemberList(Vec<GroupMember>);
+
+impl GroupMemberList {
+    pub fn hydrate(members: Vec<GroupMember>) -> Self {
+        Self(members)
+    }
+
+    pub fn raw(&self) -> &[GroupMember] {
+        &self.0
+    }
+
+    pub fn admin_ids(&self) -> Vec<UserId> {
+        self.0
+            .iter()
+            .filter(|member| member.is_admin())
+            .map(|member| member.user.id())
+            .collect()
+    }
+
+    pub fn contains(&self, user_id: UserId) -> bool {
+        self.0.iter().any(|member| member.user.id() == user_id)
+    }
+
+    /// Adds the user, or changes their role when they are already a member.
+    pub fn upsert(&self, member: GroupMember) -> Result<Self, DomainError> {
+        let mut members = self.0.clone();
+        match members.iter_mut().find(|m| m.user.id() == member.user.id()) {
+            Some(existing) => existing.role = member.role,
+            None => members.push(member),
+        }
+        Self::ensure_admin(members)
+    }
+
+    pub fn remove(&self, user_id: UserId) -> Result<Self, DomainError> {
+        if !self.contains(user_id) {
+            return Err(DomainError::ValidationError(
+                "User is not a member of the group".to_string(),
+            ));
+        }
+        Self::ensure_admin(
+            self.0
+                .iter()
+                .filter(|member| member.user.id() != user_id)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    fn ensure_admin(members: Vec<GroupMember>) -> Result<Self, DomainError> {
+        if !members.iter().any(|member| member.is_admin()) {
+            return Err(DomainError::ValidationError(
+                "Group must keep at least one admin".to_string(),
+            ));
+        }
+        Ok(Self(members))
+    }
+}
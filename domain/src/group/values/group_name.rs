@@ -0,0 +1,30 @@
+use crate::shared::error::DomainError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupName(String);
+
+impl GroupName {
+    pub fn hydrate(name: String) -> Self {
+        Self(name)
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for GroupName {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "" => Err(DomainError::ValidationError(
+                "Group name cannot be empty".to_string(),
+            )),
+            n if n.len() > 100 => Err(DomainError::ValidationError(
+                "Group name cannot exceed 100 characters".to_string(),
+            )),
+            n => Ok(Self(n.to_string())),
+        }
+    }
+}
@@ -1,5 +1,10 @@
 pub mod audit;
 pub mod auth;
 pub mod book;
+pub mod event;
+pub mod group;
+pub mod personal_access_token;
 pub mod shared;
+pub mod tenant;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,3 @@
+pub mod personal_access_token_entity;
+
+pub use personal_access_token_entity::PersonalAccessToken;
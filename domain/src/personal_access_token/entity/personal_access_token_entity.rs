@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    audit::{AuditContext, EntityAudit},
+    auth::permission::{EntityPermission, Permission},
+    personal_access_token::{enums::TokenScope, values::*},
+    shared::error::DomainError,
+    user::values::UserReference,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PersonalAccessToken {
+    audit: EntityAudit<PersonalAccessTokenId>,
+    owner: UserReference,
+    name: TokenName,
+    scopes: TokenScopes,
+    token_hash: TokenHash,
+    expires_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl PersonalAccessToken {
+    pub fn audit(&self) -> &EntityAudit<PersonalAccessTokenId> {
+        &self.audit
+    }
+    pub fn owner(&self) -> &UserReference {
+        &self.owner
+    }
+    pub fn name(&self) -> &str {
+        self.name.raw()
+    }
+    pub fn scopes(&self) -> &[TokenScope] {
+        self.scopes.raw()
+    }
+    pub fn token_hash(&self) -> &str {
+        self.token_hash.raw()
+    }
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+    pub fn last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+
+    /// Called once the token is written and its row carries the next version.
+    pub fn mark_saved(&mut self) {
+        self.audit.mark_saved();
+    }
+
+    pub fn hydrate(
+        audit: EntityAudit<PersonalAccessTokenId>,
+        owner: UserReference,
+        name: String,
+        scopes: Vec<TokenScope>,
+        token_hash: String,
+        expires_at: DateTime<Utc>,
+        last_used_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        PersonalAccessToken {
+            audit,
+            owner,
+            name: TokenName::hydrate(name),
+            scopes: TokenScopes::hydrate(scopes),
+            token_hash: TokenHash::hydrate(token_hash),
+            expires_at,
+            last_used_at,
+        }
+    }
+
+    /// Tokens are always issued to the acting user.
+    pub fn create_new(
+        context: &AuditContext,
+        name: TokenName,
+        scopes: TokenScopes,
+        lifetime: TokenLifetime,
+        token_hash: TokenHash,
+    ) -> Result<Self, DomainError> {
+        let owner = context.actor_user().clone();
+        let permission = EntityPermission::new(Some(context.actor()), owner.id());
+
+        Ok(PersonalAccessToken {
+            audit: EntityAudit::create_new(context, &permission)?,
+            owner,
+            name,
+            scopes,
+            token_hash,
+            expires_at: context.timestamp() + lifetime.raw(),
+            last_used_at: None,
+        })
+    }
+
+    /// The owner and admins may revoke a token.
+    pub fn validate_deletion(&self, context: &AuditContext) -> Result<(), DomainError> {
+        self.audit.ensure_tenant(context.actor())?;
+
+        let permission = EntityPermission::new(Some(context.actor()), self.owner.id());
+
+        match permission.can_delete() {
+            true => Ok(()),
+            false => Err(DomainError::Forbidden),
+        }
+    }
+}
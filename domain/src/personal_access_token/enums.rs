@@ -0,0 +1,11 @@
+use strum::{AsRefStr, EnumString, VariantNames};
+
+#[derive(Debug, EnumString, AsRefStr, VariantNames, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TokenScope {
+    #[strum(serialize = "books:read")]
+    BooksRead,
+    #[strum(serialize = "books:write")]
+    BooksWrite,
+    #[strum(serialize = "checkouts:write")]
+    CheckoutsWrite,
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    personal_access_token::{entity::PersonalAccessToken, values::PersonalAccessTokenId},
+    shared::error::PersistenceError,
+};
+
+#[async_trait]
+pub trait PersonalAccessTokenRepository: Send + Sync {
+    async fn find_by_id(
+        &self,
+        id: PersonalAccessTokenId,
+    ) -> Result<Option<PersonalAccessToken>, PersistenceError>;
+    async fn save(&self, token: &mut PersonalAccessToken) -> Result<(), PersistenceError>;
+    async fn delete(&self, id: PersonalAccessTokenId) -> Result<(), PersistenceError>;
+    /// Writes `last_used_at` only, without a version bump, since it changes on every request.
+    async fn record_usage(
+        &self,
+        id: PersonalAccessTokenId,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError>;
+}
@@ -0,0 +1,13 @@
+mod token_hash;
+mod token_lifetime;
+mod token_name;
+mod token_scopes;
+
+use crate::define_id;
+
+pub use token_hash::TokenHash;
+pub use token_lifetime::TokenLifetime;
+pub use token_name::TokenName;
+pub use token_scopes::TokenScopes;
+
+define_id!(PersonalAccessTokenId);
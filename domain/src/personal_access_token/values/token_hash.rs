@@ -0,0 +1,19 @@
+/// SHA-256 of the token in lowercase hex; the token itself is never stored.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TokenHash(String);
+
+impl TokenHash {
+    pub fn hydrate(hash: String) -> Self {
+        Self(hash)
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for TokenHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TokenHash(***)")
+    }
+}
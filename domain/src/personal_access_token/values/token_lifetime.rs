@@ -0,0 +1,31 @@
+use chrono::Duration;
+
+use crate::shared::error::DomainError;
+
+const MAX_LIFETIME_DAYS: u32 = 365;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenLifetime(Duration);
+
+impl TokenLifetime {
+    pub fn raw(&self) -> Duration {
+        self.0
+    }
+}
+
+impl TryFrom<u32> for TokenLifetime {
+    type Error = DomainError;
+
+    fn try_from(days: u32) -> Result<Self, Self::Error> {
+        match days {
+            0 => Err(DomainError::ValidationError(
+                "Token lifetime must be at least 1 day".to_string(),
+            )),
+            d if d > MAX_LIFETIME_DAYS => Err(DomainError::ValidationError(format!(
+                "Token lifetime cannot exceed {} days",
+                MAX_LIFETIME_DAYS
+            ))),
+            d => Ok(Self(Duration::days(d as i64))),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use itertools::Itertools;
+
+use crate::{personal_access_token::enums::TokenScope, shared::error::DomainError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenScopes(Vec<TokenScope>);
+
+impl TokenScopes {
+    pub fn hydrate(scopes: Vec<TokenScope>) -> Self {
+        Self(scopes)
+    }
+
+    pub fn raw(&self) -> &[TokenScope] {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<TokenScope>> for TokenScopes {
+    type Error = DomainError;
+
+    fn try_from(value: Vec<TokenScope>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Token must have at least one scope".to_string(),
+            ));
+        }
+
+        Ok(Self(value.into_iter().unique().collect()))
+    }
+}
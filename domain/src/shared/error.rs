@@ -8,6 +8,10 @@ pub enum DomainError {
     NotFound,
     #[error("Forbidden")]
     Forbidden,
+    #[error("Version mismatch")]
+    VersionMismatch,
+    #[error("Book is already checked out")]
+    AlreadyCheckedOut,
 }
 
 #[derive(Error, Debug)]
@@ -18,6 +22,8 @@ pub enum PersistenceError {
     TransactionError,
     #[error("Entity not found")]
     NotFound,
+    #[error("Entity was modified concurrently")]
+    Conflict,
     #[error("{0}")]
     EntityConversionError(String),
 }
@@ -0,0 +1,3 @@
+mod tenant_id;
+
+pub use tenant_id::TenantId;
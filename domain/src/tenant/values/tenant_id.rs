@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::error::DomainError;
+
+const DEFAULT_TENANT: &str = "default";
+
+/// The organization that users and everything they create belong to, e.g. `acme` for
+/// `acme.books.example.com`. Deployments without tenants keep everything in `default`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(transparent)]
+pub struct TenantId(String);
+
+impl TenantId {
+    pub fn hydrate(id: String) -> Self {
+        Self(id)
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.0 == DEFAULT_TENANT
+    }
+}
+
+impl Default for TenantId {
+    fn default() -> Self {
+        Self(DEFAULT_TENANT.to_string())
+    }
+}
+
+impl TryFrom<String> for TenantId {
+    type Error = DomainError;
+
+    /// Accepts a DNS label so that every tenant can also be addressed by subdomain.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let id = value.trim().to_ascii_lowercase();
+        let is_label = !id.is_empty()
+            && id.len() <= 63
+            && !id.starts_with('-')
+            && !id.ends_with('-')
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+        match is_label {
+            true => Ok(Self(id)),
+            false => Err(DomainError::ValidationError(format!(
+                "Invalid tenant id: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for TenantId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
-    audit::{Actor, AuditContext, EntityAudit},
+    audit::{AuditContext, EntityAudit},
     auth::permission::{EntityPermission, PassThroughPermission, Permission},
+    book::enums::BookVisibility,
+    event::{DomainEvent, DomainEventKind},
     shared::error::DomainError,
     user::{enums::*, values::*},
 };
@@ -11,6 +13,9 @@ pub struct User {
     name: UserName,
     email: UserEmail,
     role: UserRole,
+    notification_preferences: NotificationPreferences,
+    default_book_visibility: BookVisibility,
+    events: Vec<DomainEvent>,
 }
 
 impl User {
@@ -26,18 +31,39 @@ impl User {
     pub fn role(&self) -> UserRole {
         self.role
     }
+    pub fn notification_preferences(&self) -> NotificationPreferences {
+        self.notification_preferences
+    }
+    pub fn default_book_visibility(&self) -> BookVisibility {
+        self.default_book_visibility
+    }
+    pub fn events(&self) -> &[DomainEvent] {
+        &self.events
+    }
+
+    /// Called once the user is written: the events are in the outbox and the row carries the
+    /// next version.
+    pub fn mark_saved(&mut self) {
+        self.audit.mark_saved();
+        self.events.clear();
+    }
 
     pub fn hydrate(
         audit: EntityAudit<UserId>,
         name: String,
         email: String,
         role: UserRole,
+        notification_preferences: NotificationPreferences,
+        default_book_visibility: BookVisibility,
     ) -> Self {
         Self {
             audit,
             name: UserName::hydrate(name),
             email: UserEmail::hydrate(email),
             role,
+            notification_preferences,
+            default_book_visibility,
+            events: vec![],
         }
     }
 
@@ -55,6 +81,9 @@ impl User {
             name,
             email,
             role,
+            notification_preferences: NotificationPreferences::default(),
+            default_book_visibility: BookVisibility::default(),
+            events: vec![],
         })
     }
 
@@ -74,16 +103,46 @@ impl User {
         self.audit.mark_updated(context, permission)?;
         self.name = name;
         self.email = email;
-        self.role = role;
+
+        if self.role != role {
+            self.events.push(DomainEvent::new(
+                context,
+                DomainEventKind::UserRoleChanged {
+                    user_id: self.audit.id(),
+                    previous_role: self.role,
+                    new_role: role,
+                },
+            ));
+            self.role = role;
+        }
 
         Ok(())
     }
 
-    pub fn into_actor(&self) -> Actor {
-        Actor::hydrate(
-            self.audit.id().into(),
-            self.name.raw().to_string(),
-            self.role,
-        )
+    pub fn update_notification_preferences(
+        &mut self,
+        context: &AuditContext,
+        notification_preferences: NotificationPreferences,
+    ) -> Result<(), DomainError> {
+        let permission = EntityPermission::new(Some(context.actor()), self.audit.id());
+
+        self.audit.mark_updated(context, &permission)?;
+        self.notification_preferences = notification_preferences;
+
+        Ok(())
+    }
+
+    /// Visibility of the books this user creates without choosing one.
+    pub fn update_default_book_visibility(
+        &mut self,
+        context: &AuditContext,
+        default_book_visibility: BookVisibility,
+    ) -> Result<(), DomainError> {
+        let permission = EntityPermission::new(Some(context.actor()), self.audit.id());
+
+        self.audit.mark_updated(context, &permission)?;
+        self.default_book_visibility = default_book_visibility;
+
+        Ok(())
     }
 }
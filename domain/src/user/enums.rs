@@ -1,9 +1,22 @@
-use strum::{AsRefStr, EnumString};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter, EnumString};
 
-#[derive(Debug, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+#[derive(
+    Debug, EnumString, AsRefStr, EnumIter, PartialEq, Eq, Clone, Copy, Serialize, Deserialize,
+)]
 #[strum(ascii_case_insensitive)]
 pub enum UserRole {
     Admin,
+    Librarian,
     Regular,
     System,
 }
+
+#[derive(Debug, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationKind {
+    DueSoon,
+    Overdue,
+    BookCheckedOut,
+    BookReturned,
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 
 use crate::{
     audit::Actor,
+    book::enums::BookVisibility,
     shared::error::PersistenceError,
     user::{entity::User, values::UserId},
 };
@@ -9,11 +10,15 @@ use crate::{
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: UserId) -> Result<Option<User>, PersistenceError>;
-    async fn save(&self, user: &User) -> Result<(), PersistenceError>;
+    async fn save(&self, user: &mut User) -> Result<(), PersistenceError>;
     async fn delete(&self, id: UserId) -> Result<(), PersistenceError>;
 }
 
 #[async_trait]
 pub trait UserDomainQueryService: Send + Sync {
     async fn find_actor_by_id(&self, id: UserId) -> Result<Option<Actor>, PersistenceError>;
+    async fn find_default_book_visibility(
+        &self,
+        id: UserId,
+    ) -> Result<Option<BookVisibility>, PersistenceError>;
 }
@@ -0,0 +1,49 @@
+use crate::user::enums::NotificationKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationPreferences {
+    due_soon: bool,
+    overdue: bool,
+    book_checked_out: bool,
+    book_returned: bool,
+}
+
+impl NotificationPreferences {
+    pub fn new(due_soon: bool, overdue: bool, book_checked_out: bool, book_returned: bool) -> Self {
+        Self {
+            due_soon,
+            overdue,
+            book_checked_out,
+            book_returned,
+        }
+    }
+
+    pub fn due_soon(&self) -> bool {
+        self.due_soon
+    }
+    pub fn overdue(&self) -> bool {
+        self.overdue
+    }
+    pub fn book_checked_out(&self) -> bool {
+        self.book_checked_out
+    }
+    pub fn book_returned(&self) -> bool {
+        self.book_returned
+    }
+
+    pub fn allows(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::DueSoon => self.due_soon,
+            NotificationKind::Overdue => self.overdue,
+            NotificationKind::BookCheckedOut => self.book_checked_out,
+            NotificationKind::BookReturned => self.book_returned,
+        }
+    }
+}
+
+/// Every notification is enabled until the user opts out.
+impl Default for NotificationPreferences {
+    fn default() -> Self {
+        Self::new(true, true, true, true)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod interface;
+pub mod values;
@@ -0,0 +1,3 @@
+pub mod webhook_entity;
+
+pub use webhook_entity::Webhook;
@@ -0,0 +1,75 @@
+use crate::{
+    audit::{AuditContext, EntityAudit},
+    auth::permission::{AdminPermission, Permission},
+    shared::error::DomainError,
+    webhook::values::*,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Webhook {
+    audit: EntityAudit<WebhookId>,
+    url: WebhookUrl,
+    event_types: WebhookEventTypes,
+    secret: WebhookSecret,
+}
+
+impl Webhook {
+    pub fn audit(&self) -> &EntityAudit<WebhookId> {
+        &self.audit
+    }
+    pub fn url(&self) -> &str {
+        self.url.raw()
+    }
+    pub fn event_types(&self) -> &[String] {
+        self.event_types.raw()
+    }
+    pub fn secret(&self) -> &str {
+        self.secret.raw()
+    }
+
+    /// Called once the webhook is written and its row carries the next version.
+    pub fn mark_saved(&mut self) {
+        self.audit.mark_saved();
+    }
+
+    pub fn hydrate(
+        audit: EntityAudit<WebhookId>,
+        url: String,
+        event_types: Vec<String>,
+        secret: String,
+    ) -> Self {
+        Webhook {
+            audit,
+            url: WebhookUrl::hydrate(url),
+            event_types: WebhookEventTypes::hydrate(event_types),
+            secret: WebhookSecret::hydrate(secret),
+        }
+    }
+
+    pub fn create_new(
+        context: &AuditContext,
+        url: WebhookUrl,
+        event_types: WebhookEventTypes,
+        secret: WebhookSecret,
+    ) -> Result<Self, DomainError> {
+        let permission = AdminPermission::new(context.actor());
+
+        Ok(Webhook {
+            audit: EntityAudit::create_new(context, &permission)?,
+            url,
+            event_types,
+            secret,
+        })
+    }
+
+    pub fn validate_deletion(&self, context: &AuditContext) -> Result<(), DomainError> {
+        self.audit.ensure_tenant(context.actor())?;
+
+        let permission = AdminPermission::new(context.actor());
+
+        match permission.can_delete() {
+            true => Ok(()),
+            false => Err(DomainError::Forbidden),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::{
+    shared::error::PersistenceError,
+    webhook::{entity::Webhook, values::WebhookId},
+};
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, PersistenceError>;
+    async fn save(&self, webhook: &mut Webhook) -> Result<(), PersistenceError>;
+    async fn delete(&self, id: WebhookId) -> Result<(), PersistenceError>;
+}
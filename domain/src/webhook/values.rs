@@ -0,0 +1,11 @@
+mod webhook_event_types;
+mod webhook_secret;
+mod webhook_url;
+
+use crate::define_id;
+
+pub use webhook_event_types::WebhookEventTypes;
+pub use webhook_secret::WebhookSecret;
+pub use webhook_url::WebhookUrl;
+
+define_id!(WebhookId);
@@ -0,0 +1,41 @@
+use itertools::Itertools;
+use strum::VariantNames;
+
+use crate::{event::DomainEventKind, shared::error::DomainError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookEventTypes(Vec<String>);
+
+impl WebhookEventTypes {
+    pub fn hydrate(event_types: Vec<String>) -> Self {
+        Self(event_types)
+    }
+
+    pub fn raw(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<String>> for WebhookEventTypes {
+    type Error = DomainError;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Webhook must subscribe to at least one event type".to_string(),
+            ));
+        }
+
+        if let Some(unknown) = value
+            .iter()
+            .find(|t| !DomainEventKind::VARIANTS.contains(&t.as_str()))
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Unknown event type: {}",
+                unknown
+            )));
+        }
+
+        Ok(Self(value.into_iter().unique().collect()))
+    }
+}
@@ -0,0 +1,36 @@
+use crate::shared::error::DomainError;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    pub fn hydrate(secret: String) -> Self {
+        Self(secret)
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WebhookSecret(***)")
+    }
+}
+
+impl TryFrom<String> for WebhookSecret {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value {
+            s if s.len() < 16 => Err(DomainError::ValidationError(
+                "Webhook secret must be at least 16 characters".to_string(),
+            )),
+            s if s.len() > 255 => Err(DomainError::ValidationError(
+                "Webhook secret cannot exceed 255 characters".to_string(),
+            )),
+            s => Ok(Self(s)),
+        }
+    }
+}
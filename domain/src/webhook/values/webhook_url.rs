@@ -0,0 +1,30 @@
+use crate::shared::error::DomainError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookUrl(String);
+
+impl WebhookUrl {
+    pub fn hydrate(url: String) -> Self {
+        Self(url)
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for WebhookUrl {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value {
+            u if !u.starts_with("http://") && !u.starts_with("https://") => Err(
+                DomainError::ValidationError("Webhook URL must use http or https".to_string()),
+            ),
+            u if u.len() > 2048 => Err(DomainError::ValidationError(
+                "Webhook URL cannot exceed 2048 characters".to_string(),
+            )),
+            u => Ok(Self(u)),
+        }
+    }
+}
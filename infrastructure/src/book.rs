@@ -1,3 +1,4 @@
+mod loader;
 mod query_service;
 mod repository;
 
@@ -0,0 +1,80 @@
+use domain::book::values::BookId;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+use crate::database::{
+    entity::{book_authors, book_checkouts, book_co_owners, books, groups, users},
+    row::book::{
+        aggregate::AggregatedBookDetails,
+        rows::{BookAuthorReferenceRow, BookCheckoutRow, BookCoOwnerRow, BookDetailsRow},
+    },
+};
+
+/// Loads a book with its authors, co-owners, the active checkout and the latest checkout.
+/// Older history is left to `BookQueryService::get_checkout_history`.
+pub(super) async fn load_book_details<C: ConnectionTrait>(
+    db: &C,
+    id: BookId,
+) -> Result<Option<AggregatedBookDetails>, DbErr> {
+    let Some(row) = books::Entity::find_by_id(id)
+        .inner_join(users::Entity)
+        .left_join(groups::Entity)
+        .into_partial_model::<BookDetailsRow>()
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let authors = book_authors::Entity::find()
+        .filter(book_authors::Column::BookId.eq(row.id))
+        .order_by_asc(book_authors::Column::OrderIndex)
+        .into_partial_model::<BookAuthorReferenceRow>()
+        .all(db)
+        .await?;
+
+    let co_owners = load_co_owners(db, [row.id]).await?;
+
+    // The partial unique index allows at most one active checkout per book
+    let mut checkouts = book_checkouts::Entity::find()
+        .filter(book_checkouts::Column::BookId.eq(row.id))
+        .filter(book_checkouts::Column::ReturnedAt.is_null())
+        .into_partial_model::<BookCheckoutRow>()
+        .all(db)
+        .await?;
+
+    // The latest checkout tells "already returned" apart from "never checked out"
+    if checkouts.is_empty() {
+        checkouts.extend(
+            book_checkouts::Entity::find()
+                .filter(book_checkouts::Column::BookId.eq(row.id))
+                .order_by_desc(book_checkouts::Column::CheckedOutAt)
+                .limit(1)
+                .into_partial_model::<BookCheckoutRow>()
+                .one(db)
+                .await?,
+        );
+    }
+
+    Ok(Some(AggregatedBookDetails {
+        row,
+        authors,
+        co_owners,
+        checkouts,
+    }))
+}
+
+pub(super) async fn load_co_owners<C: ConnectionTrait>(
+    db: &C,
+    book_ids: impl IntoIterator<Item = Uuid>,
+) -> Result<Vec<BookCoOwnerRow>, DbErr> {
+    book_co_owners::Entity::find()
+        .inner_join(users::Entity)
+        .filter(book_co_owners::Column::BookId.is_in(book_ids))
+        .order_by_asc(users::Column::Name)
+        .into_partial_model::<BookCoOwnerRow>()
+        .all(db)
+        .await
+}
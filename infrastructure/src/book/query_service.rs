@@ -1,20 +1,37 @@
+use std::collections::HashSet;
+
 use application::{
     book::{dto::*, interface::BookQueryService},
     shared::UserReferenceDTO,
 };
 use async_trait::async_trait;
 use derive_new::new;
-use domain::{audit::Actor, auth::permission::EntityPermission, shared::error::PersistenceError};
+use domain::{
+    audit::Actor,
+    auth::permission::{EntityPermission, Permission},
+    book::{enums::BookVisibility, values::BookId},
+    group::values::GroupId,
+    shared::error::PersistenceError,
+    tenant::values::TenantId,
+    user::values::UserId,
+};
+use itertools::Itertools;
 use sea_orm::{
     ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
-    QueryTrait, Select, prelude::Expr,
+    QueryTrait, Select, prelude::Expr, sea_query::Func,
 };
+use uuid::Uuid;
 
-use crate::database::{
-    ConnectionPool,
-    entity::{book_authors, book_checkouts, books, users},
-    log_db_error,
-    row::book::{aggregate::*, rows::*},
+use crate::{
+    book::loader::{load_book_details, load_co_owners},
+    database::{
+        ConnectionPool,
+        entity::{
+            book_authors, book_checkouts, book_co_owners, books, group_members, groups, users,
+        },
+        log_db_error,
+        row::book::{aggregate::*, rows::*},
+    },
 };
 
 #[derive(new)]
@@ -26,69 +43,45 @@ pub struct BookQueryServiceImpl {
 impl BookQueryService for BookQueryServiceImpl {
     async fn get_book_details(
         &self,
+        tenant: &TenantId,
         actor: Option<&Actor>,
         identity: BookIdentity,
     ) -> Result<Option<BookDetailsDTO>, PersistenceError> {
-        let rows = books::Entity::find_by_id(identity.book_id)
-            .inner_join(book_authors::Entity)
-            .inner_join(users::Entity)
-            .left_join(book_checkouts::Entity)
-            .order_by_asc(book_authors::Column::OrderIndex)
-            .into_partial_model::<BookDetailsRow>()
-            .all(self.db.inner_ref())
+        let details = load_book_details(self.db.inner_ref(), identity.book_id)
             .await
             .map_err(log_db_error)?;
 
-        Ok(AggregatedBookDetails::from_rows(rows).map(|agg| {
-            let permission = EntityPermission::new(actor, agg.row.created_by_id.into());
-            agg.to_dto(permission)
-        }))
+        // Books of other tenants and private team libraries are hidden as if they did not exist
+        let Some(agg) = details
+            .filter(|agg| agg.row.tenant_id == tenant.raw())
+            .filter(|agg| match &agg.row.group {
+                Some(group) if group.private_library => can_view_group(actor, group.id),
+                _ => true,
+            })
+        else {
+            return Ok(None);
+        };
+
+        let permission = EntityPermission::new(actor, agg.row.user.id.into())
+            .with_co_owners(agg.co_owners.iter().map(|c| c.user.id.into()))
+            .with_group(agg.row.group_id.map(GroupId::from));
+        // So are books the actor may not see; only those who manage a private book see it
+        let visible = match agg.row.visibility()? {
+            BookVisibility::Public => true,
+            BookVisibility::Internal => actor.is_some(),
+            BookVisibility::Private => permission.can_update(),
+        };
+
+        visible.then(|| agg.to_dto(permission)).transpose()
     }
 
     async fn get_book_list(
         &self,
+        tenant: &TenantId,
         actor: Option<&Actor>,
         query: &BookListQueryDTO,
     ) -> Result<BookListResponseDTO, PersistenceError> {
-        let id_db_query = books::Entity::find()
-            .select_only()
-            .column(books::Column::Id)
-            .apply_if(query.owner_id, |q, owner_id| {
-                q.filter(books::Column::OwnerId.eq(owner_id))
-            })
-            .apply_if(query.checked_out, |q, checked_out| match checked_out {
-                true => q.filter(
-                    books::Column::Id.in_subquery(active_checkout_ids_query().into_query()),
-                ),
-                false => q.filter(
-                    books::Column::Id.not_in_subquery(active_checkout_ids_query().into_query()),
-                ),
-            })
-            .apply_if(query.checked_out_to_id, |q, user_id| {
-                q.filter(
-                    books::Column::Id.in_subquery(
-                        active_checkout_ids_query()
-                            .filter(book_checkouts::Column::CheckedOutById.eq(user_id))
-                            .into_query(),
-                    ),
-                )
-            })
-            .apply_if(query.title.as_ref(), |q, title| {
-                q.filter(books::Column::Title.ilike(format!("%{}%", title)))
-            })
-            .apply_if(query.author_name.as_ref(), |q, author_name| {
-                q.filter(find_by_author_name_expression(author_name))
-            })
-            .apply_if(query.search.as_ref(), |q, search| {
-                let pattern = format!("%{}%", search);
-                q.filter(
-                    Condition::any()
-                        .add(books::Column::Title.ilike(&pattern))
-                        .add(books::Column::Isbn.ilike(&pattern))
-                        .add(books::Column::Description.ilike(&pattern))
-                        .add(find_by_author_name_expression(search)),
-                )
-            });
+        let id_db_query = filtered_book_ids_query(tenant, &query.filter, actor);
 
         let total_count = id_db_query
             .clone()
@@ -117,6 +110,13 @@ impl BookQueryService for BookQueryServiceImpl {
             .await
             .map_err(log_db_error)?;
 
+        let mut co_owner_ids = load_co_owners(self.db.inner_ref(), rows.iter().map(|r| r.id))
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .map(|c| (c.book_id, UserId::from(c.user.id)))
+            .into_group_map();
+
         Ok(BookListResponseDTO {
             page_size: query.page_size,
             page: query.page,
@@ -124,20 +124,157 @@ impl BookQueryService for BookQueryServiceImpl {
             items: AggregatedBookListItem::from_rows(rows)
                 .into_iter()
                 .map(|book| {
-                    let permission = EntityPermission::new(actor, book.row.user.id.into());
+                    let permission = EntityPermission::new(actor, book.row.user.id.into())
+                        .with_co_owners(co_owner_ids.remove(&book.row.id).unwrap_or_default())
+                        .with_group(book.row.group_id.map(GroupId::from));
                     book.to_dto(permission)
                 })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    async fn get_author_list(
+        &self,
+        tenant: &TenantId,
+        query: &AuthorListQueryDTO,
+    ) -> Result<AuthorListResponseDTO, PersistenceError> {
+        // Authors are listed anonymously, so only books anyone may see are counted
+        let total_count = book_authors::Entity::find()
+            .inner_join(books::Entity)
+            .filter(books::Column::TenantId.eq(tenant.raw()))
+            .filter(visible_books_condition(None))
+            .select_only()
+            .column(book_authors::Column::Name)
+            .distinct()
+            .count(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        let rows: Vec<(String, i64)> = book_authors::Entity::find()
+            .inner_join(books::Entity)
+            .filter(books::Column::TenantId.eq(tenant.raw()))
+            .filter(visible_books_condition(None))
+            .select_only()
+            .column(book_authors::Column::Name)
+            .column_as(
+                Expr::expr(Func::count(Expr::col(book_authors::Column::BookId))),
+                "book_count",
+            )
+            .group_by(book_authors::Column::Name)
+            .order_by_asc(book_authors::Column::Name)
+            .offset((query.page - 1) * query.page_size)
+            .limit(query.page_size)
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(AuthorListResponseDTO {
+            page_size: query.page_size,
+            page: query.page,
+            total_count,
+            items: rows
+                .into_iter()
+                .map(|(name, book_count)| AuthorSummaryDTO {
+                    name,
+                    book_count: book_count as u64,
+                })
                 .collect(),
         })
     }
 
+    async fn get_export_chunk(
+        &self,
+        tenant: &TenantId,
+        filter: &BookListFilterDTO,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<BookExportItemDTO>, PersistenceError> {
+        // Exports are anonymous, so only public books outside private team libraries are included
+        let ids: Vec<Uuid> = filtered_book_ids_query(tenant, filter, None)
+            .apply_if(after, |q, after| q.filter(books::Column::Id.gt(after)))
+            .order_by_asc(books::Column::Id)
+            .limit(limit)
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let rows = books::Entity::find()
+            .inner_join(users::Entity)
+            .filter(books::Column::Id.is_in(ids.iter().copied()))
+            .order_by_asc(books::Column::Id)
+            .into_partial_model::<BookExportRow>()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        let mut authors = book_authors::Entity::find()
+            .filter(book_authors::Column::BookId.is_in(ids.iter().copied()))
+            .order_by_asc(book_authors::Column::OrderIndex)
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .map(|author| (author.book_id, author.name))
+            .into_group_map();
+
+        let checked_out: HashSet<Uuid> = active_checkout_ids_query()
+            .filter(book_checkouts::Column::BookId.is_in(ids.iter().copied()))
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .collect();
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let authors = authors.remove(&row.id).unwrap_or_default();
+                let checked_out = checked_out.contains(&row.id);
+                row.to_export_dto(authors, checked_out)
+            })
+            .collect())
+    }
+
+    async fn get_visible_book_ids(
+        &self,
+        actor: &Actor,
+        book_ids: &[BookId],
+    ) -> Result<HashSet<BookId>, PersistenceError> {
+        if book_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let ids: Vec<Uuid> = books::Entity::find()
+            .select_only()
+            .column(books::Column::Id)
+            .filter(books::Column::Id.is_in(book_ids.iter().map(|id| id.raw())))
+            .filter(books::Column::TenantId.eq(actor.tenant().raw()))
+            .filter(visible_books_condition(Some(actor)))
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(ids.into_iter().map(BookId::from).collect())
+    }
+
     async fn get_checkout_history(
         &self,
+        tenant: &TenantId,
         identity: BookIdentity,
         query: &CheckoutHistoryQueryDTO,
     ) -> Result<CheckoutHistoryListDTO, PersistenceError> {
         let db_query = book_checkouts::Entity::find()
-            .filter(book_checkouts::Column::BookId.eq(identity.book_id.raw()));
+            .inner_join(books::Entity)
+            .filter(book_checkouts::Column::BookId.eq(identity.book_id.raw()))
+            .filter(books::Column::TenantId.eq(tenant.raw()));
 
         let total_count = db_query
             .clone()
@@ -163,16 +300,163 @@ impl BookQueryService for BookQueryServiceImpl {
                     checkout_id: row.checkout_id,
                     checked_out_at: row.checked_out_at.into(),
                     checked_out_to: UserReferenceDTO {
+                        id: row.checked_out_to_id,
+                        name: row.checked_out_to_name,
+                    },
+                    checked_out_by: UserReferenceDTO {
                         id: row.checked_out_by_id,
                         name: row.checked_out_by_name,
                     },
                     returned_at: row.returned_at.map(|dt| dt.into()),
+                    returned_by: row.returned_by_id.map(|id| UserReferenceDTO {
+                        id,
+                        name: row.returned_by_name.unwrap_or_default(),
+                    }),
                 })
                 .collect(),
         })
     }
 }
 
+fn filtered_book_ids_query(
+    tenant: &TenantId,
+    filter: &BookListFilterDTO,
+    actor: Option<&Actor>,
+) -> Select<books::Entity> {
+    books::Entity::find()
+        .select_only()
+        .column(books::Column::Id)
+        .filter(books::Column::TenantId.eq(tenant.raw()))
+        .filter(visible_books_condition(actor))
+        .apply_if(filter.owner_id, |q, owner_id| {
+            q.filter(books::Column::OwnerId.eq(owner_id))
+        })
+        .apply_if(filter.group_id, |q, group_id| {
+            q.filter(books::Column::GroupId.eq(group_id))
+        })
+        .apply_if(filter.managed_by_id, |q, user_id| {
+            q.filter(
+                Condition::any()
+                    .add(books::Column::OwnerId.eq(user_id))
+                    .add(
+                        books::Column::Id.in_subquery(
+                            book_co_owners::Entity::find()
+                                .select_only()
+                                .column(book_co_owners::Column::BookId)
+                                .filter(book_co_owners::Column::UserId.eq(user_id))
+                                .into_query(),
+                        ),
+                    )
+                    .add(
+                        books::Column::GroupId.in_subquery(
+                            group_members::Entity::find()
+                                .select_only()
+                                .column(group_members::Column::GroupId)
+                                .filter(group_members::Column::UserId.eq(user_id))
+                                .into_query(),
+                        ),
+                    ),
+            )
+        })
+        .apply_if(filter.checked_out, |q, checked_out| match checked_out {
+            true => {
+                q.filter(books::Column::Id.in_subquery(active_checkout_ids_query().into_query()))
+            }
+            false => q.filter(
+                books::Column::Id.not_in_subquery(active_checkout_ids_query().into_query()),
+            ),
+        })
+        .apply_if(filter.checked_out_to_id, |q, user_id| {
+            q.filter(
+                books::Column::Id.in_subquery(
+                    active_checkout_ids_query()
+                        .filter(book_checkouts::Column::CheckedOutToId.eq(user_id))
+                        .into_query(),
+                ),
+            )
+        })
+        .apply_if(filter.title.as_ref(), |q, title| {
+            q.filter(books::Column::Title.ilike(format!("%{}%", title)))
+        })
+        .apply_if(filter.author_name.as_ref(), |q, author_name| {
+            q.filter(find_by_author_name_expression(author_name))
+        })
+        .apply_if(filter.search.as_ref(), |q, search| {
+            let pattern = format!("%{}%", search);
+            q.filter(
+                Condition::any()
+                    .add(books::Column::Title.ilike(&pattern))
+                    .add(books::Column::Isbn.ilike(&pattern))
+                    .add(books::Column::Description.ilike(&pattern))
+                    .add(find_by_author_name_expression(search)),
+            )
+        })
+}
+
+/// Books whose visibility and team library both let the actor see them. Admins see everything.
+fn visible_books_condition(actor: Option<&Actor>) -> Condition {
+    if actor.is_some_and(|actor| actor.is_admin()) {
+        return Condition::all();
+    }
+
+    Condition::all()
+        .add(visibility_condition(actor))
+        .add(visible_groups_condition(actor))
+}
+
+/// Public books for anonymous visitors. Signed-in users also see internal books, and private
+/// ones they manage as owner, co-owner or member of the book's group.
+fn visibility_condition(actor: Option<&Actor>) -> Condition {
+    let Some(actor) = actor else {
+        return Condition::all().add(books::Column::Visibility.eq(BookVisibility::Public.as_ref()));
+    };
+
+    Condition::any()
+        .add(books::Column::Visibility.ne(BookVisibility::Private.as_ref()))
+        .add(books::Column::OwnerId.eq(actor.raw_id()))
+        .add(
+            books::Column::Id.in_subquery(
+                book_co_owners::Entity::find()
+                    .select_only()
+                    .column(book_co_owners::Column::BookId)
+                    .filter(book_co_owners::Column::UserId.eq(actor.raw_id()))
+                    .into_query(),
+            ),
+        )
+        .add_option(
+            (!actor.groups().is_empty()).then(|| {
+                books::Column::GroupId.is_in(actor.groups().iter().map(|group| group.raw()))
+            }),
+        )
+}
+
+/// Books outside any group or in a group whose library is not private, plus those in the
+/// actor's own groups.
+fn visible_groups_condition(actor: Option<&Actor>) -> Condition {
+    Condition::any()
+        .add(books::Column::GroupId.is_null())
+        .add(
+            books::Column::GroupId.in_subquery(
+                groups::Entity::find()
+                    .select_only()
+                    .column(groups::Column::Id)
+                    .filter(groups::Column::PrivateLibrary.eq(false))
+                    .into_query(),
+            ),
+        )
+        .add_option(
+            actor
+                .filter(|actor| !actor.groups().is_empty())
+                .map(|actor| {
+                    books::Column::GroupId.is_in(actor.groups().iter().map(|group| group.raw()))
+                }),
+        )
+}
+
+fn can_view_group(actor: Option<&Actor>, group_id: Uuid) -> bool {
+    actor.is_some_and(|actor| actor.is_admin() || actor.is_member_of(group_id.into()))
+}
+
 fn active_checkout_ids_query() -> Select<book_checkouts::Entity> {
     book_checkouts::Entity::find()
         .select_only()
@@ -5,17 +5,19 @@ use domain::{
     shared::error::PersistenceError,
 };
 use sea_orm::{
-    ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+    ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, TransactionTrait,
+    sea_query::OnConflict,
 };
 
 use crate::{
+    book::loader::load_book_details,
     database::{
         ConnectionPool,
-        entity::{book_authors, book_checkouts, books, users},
-        log_db_error,
-        row::book::{aggregate::AggregatedBookDetails, rows::BookDetailsRow},
+        entity::{book_authors, book_checkouts, book_co_owners, books},
+        log_db_conflict, log_db_error,
     },
     macros::{audit_defaults, update_on_conflict},
+    outbox::append_events,
 };
 
 #[derive(new)]
@@ -26,20 +28,14 @@ pub struct BookRepositoryImpl {
 #[async_trait]
 impl BookRepository for BookRepositoryImpl {
     async fn find_by_id(&self, id: BookId) -> Result<Option<Book>, PersistenceError> {
-        let rows = books::Entity::find_by_id(id)
-            .inner_join(book_authors::Entity)
-            .inner_join(users::Entity)
-            .left_join(book_checkouts::Entity)
-            .order_by_asc(book_authors::Column::OrderIndex)
-            .into_partial_model::<BookDetailsRow>()
-            .all(self.db.inner_ref())
+        let details = load_book_details(self.db.inner_ref(), id)
             .await
             .map_err(log_db_error)?;
 
-        Ok(AggregatedBookDetails::from_rows(rows).map(|agg| agg.to_entity()))
+        details.map(|agg| agg.to_entity()).transpose()
     }
 
-    async fn save(&self, book: &Book) -> Result<(), PersistenceError> {
+    async fn save(&self, book: &mut Book) -> Result<(), PersistenceError> {
         // Begin transaction
         let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
 
@@ -49,75 +45,134 @@ impl BookRepository for BookRepositoryImpl {
             isbn: Set(book.isbn().map(|v| v.into())),
             description: Set(book.description().map(|v| v.into())),
             owner_id: Set(book.owner().raw_id()),
+            group_id: Set(book.group_id().map(|g| g.raw())),
+            visibility: Set(book.visibility().as_ref().into()),
             ..audit_defaults!(books::ActiveModel, book.audit())
         };
 
-        books::Entity::insert(book_active_model)
-            .on_conflict(update_on_conflict!(books::Column))
-            .exec(&txn)
+        let rows_affected = books::Entity::insert(book_active_model)
+            .on_conflict(update_on_conflict!(books::Column, book.audit()))
+            .exec_without_returning(&txn)
             .await
             .map_err(log_db_error)?;
 
-        // Upsert book authors
-        let book_authors = book
-            .authors()
-            .iter()
-            .map(|author_ref| book_authors::ActiveModel {
-                book_id: Set(book.audit().raw_id()),
-                order_index: Set(author_ref.order_index() as i32),
-                name: Set(author_ref.name().raw().into()),
-            });
+        if rows_affected == 0 {
+            return Err(PersistenceError::Conflict);
+        }
 
-        book_authors::Entity::delete_many()
-            .filter(book_authors::Column::BookId.eq(book.audit().raw_id()))
-            .exec(&txn)
-            .await
-            .map_err(log_db_error)?;
-        book_authors::Entity::insert_many(book_authors)
-            .exec(&txn)
-            .await
-            .map_err(log_db_error)?;
+        // Rewrite book authors only when the list changed
+        let mut authors_written = 0;
+        if book.changes().authors_changed() {
+            let book_authors = book
+                .authors()
+                .iter()
+                .map(|author_ref| book_authors::ActiveModel {
+                    book_id: Set(book.audit().raw_id()),
+                    order_index: Set(author_ref.order_index() as i32),
+                    name: Set(author_ref.name().raw().into()),
+                })
+                .collect::<Vec<_>>();
+            authors_written = book_authors.len();
+
+            book_authors::Entity::delete_many()
+                .filter(book_authors::Column::BookId.eq(book.audit().raw_id()))
+                .exec(&txn)
+                .await
+                .map_err(log_db_error)?;
+            book_authors::Entity::insert_many(book_authors)
+                .exec(&txn)
+                .await
+                .map_err(log_db_error)?;
+        }
 
-        // Upsert book checkouts
+        // Rewrite co-owners only when they changed
+        if book.changes().co_owners_changed() {
+            let book_co_owners = book
+                .co_owners()
+                .iter()
+                .map(|co_owner| book_co_owners::ActiveModel {
+                    book_id: Set(book.audit().raw_id()),
+                    user_id: Set(co_owner.raw_id()),
+                })
+                .collect::<Vec<_>>();
+
+            book_co_owners::Entity::delete_many()
+                .filter(book_co_owners::Column::BookId.eq(book.audit().raw_id()))
+                .exec(&txn)
+                .await
+                .map_err(log_db_error)?;
+            if !book_co_owners.is_empty() {
+                book_co_owners::Entity::insert_many(book_co_owners)
+                    .exec(&txn)
+                    .await
+                    .map_err(log_db_error)?;
+            }
+        }
+
+        // Upsert only the checkouts started or returned since the book was loaded
         let book_checkouts = book
-            .checkouts()
-            .iter()
+            .changed_checkouts()
             .map(|checkout| book_checkouts::ActiveModel {
                 checkout_id: Set(checkout.id()),
                 book_id: Set(book.audit().raw_id()),
                 checked_out_at: Set(checkout.checked_out_at().into()),
+                checked_out_to_id: Set(checkout.checked_out_to().raw_id()),
+                checked_out_to_name: Set(checkout.checked_out_to().name().to_string()),
                 checked_out_by_id: Set(checkout.checked_out_by().raw_id()),
                 checked_out_by_name: Set(checkout.checked_out_by().name().to_string()),
                 returned_at: Set(checkout.returned_at().map(|dt| dt.into())),
+                returned_by_id: Set(checkout.returned_by().map(|u| u.raw_id())),
+                returned_by_name: Set(checkout.returned_by().map(|u| u.name().to_string())),
             })
             .collect::<Vec<_>>();
+        let checkouts_written = book_checkouts.len();
 
         if !book_checkouts.is_empty() {
-            book_checkouts::Entity::delete_many()
-                .filter(book_checkouts::Column::BookId.eq(book.audit().raw_id()))
-                .exec(&txn)
-                .await
-                .map_err(log_db_error)?;
+            // A second active checkout violates the partial unique index
             book_checkouts::Entity::insert_many(book_checkouts)
+                .on_conflict(
+                    OnConflict::column(book_checkouts::Column::CheckoutId)
+                        .update_columns([
+                            book_checkouts::Column::ReturnedAt,
+                            book_checkouts::Column::ReturnedById,
+                            book_checkouts::Column::ReturnedByName,
+                        ])
+                        .to_owned(),
+                )
                 .exec(&txn)
                 .await
-                .map_err(log_db_error)?;
+                .map_err(log_db_conflict)?;
         }
 
+        tracing::debug!(
+            book_id = %book.audit().raw_id(),
+            authors_written,
+            checkouts_written,
+            "Saved book"
+        );
+
+        // Record domain events
+        append_events(&txn, book.events()).await?;
+
         // Commit transaction
         txn.commit().await.map_err(log_db_error)?;
 
+        book.mark_saved();
+
         Ok(())
     }
 
-    async fn delete(&self, id: BookId) -> Result<(), PersistenceError> {
-        let result = books::Entity::delete_by_id(id)
+    async fn delete(&self, book: &Book) -> Result<(), PersistenceError> {
+        // Matching the version keeps a delete checked against If-Match from racing an update
+        let result = books::Entity::delete_many()
+            .filter(books::Column::Id.eq(book.audit().raw_id()))
+            .filter(books::Column::Version.eq(book.audit().version() as i32))
             .exec(self.db.inner_ref())
             .await
             .map_err(log_db_error)?;
 
         if result.rows_affected == 0 {
-            Err(PersistenceError::NotFound)
+            Err(PersistenceError::Conflict)
         } else {
             Ok(())
         }
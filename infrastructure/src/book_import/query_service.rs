@@ -0,0 +1,61 @@
+use application::book_import::{
+    dto::{BookImportDTO, BookImportIdentity},
+    interface::BookImportQueryService,
+};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{shared::error::PersistenceError, tenant::values::TenantId};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+
+use crate::database::{
+    ConnectionPool,
+    entity::{book_imports, books, users},
+    log_db_error,
+    row::BookImportDTORow,
+};
+
+#[derive(new)]
+pub struct BookImportQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl BookImportQueryService for BookImportQueryServiceImpl {
+    async fn get_import(
+        &self,
+        tenant: &TenantId,
+        identity: BookImportIdentity,
+    ) -> Result<Option<BookImportDTO>, PersistenceError> {
+        book_imports::Entity::find_by_id(identity.import_id)
+            .inner_join(users::Entity)
+            .filter(users::Column::TenantId.eq(tenant.raw()))
+            .into_partial_model::<BookImportDTORow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+            .map(|row| row.to_dto())
+            .transpose()
+    }
+
+    async fn find_existing_isbns(
+        &self,
+        tenant: &TenantId,
+        isbns: &[String],
+    ) -> Result<Vec<String>, PersistenceError> {
+        if isbns.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let existing: Vec<Option<String>> = books::Entity::find()
+            .select_only()
+            .column(books::Column::Isbn)
+            .filter(books::Column::TenantId.eq(tenant.raw()))
+            .filter(books::Column::Isbn.is_in(isbns.iter().cloned()))
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(existing.into_iter().flatten().collect())
+    }
+}
@@ -0,0 +1,170 @@
+use application::book_import::{
+    dto::BookImportStatusDTO,
+    interface::{BookImportProgress, BookImportRepository, ClaimedBookImport, NewBookImport},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::shared::error::PersistenceError;
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+    UpdateMany,
+    prelude::Expr,
+    sea_query::{LockBehavior, LockType},
+};
+use uuid::Uuid;
+
+use crate::database::{ConnectionPool, entity::book_imports, log_db_error};
+
+#[derive(new)]
+pub struct BookImportRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl BookImportRepository for BookImportRepositoryImpl {
+    async fn create(&self, import: &NewBookImport) -> Result<(), PersistenceError> {
+        let active_model = book_imports::ActiveModel {
+            id: Set(import.id),
+            actor_id: Set(import.actor_id.raw()),
+            status: Set(BookImportStatusDTO::Pending.as_ref().into()),
+            rows: Set(to_json(&import.rows)?),
+            total_rows: Set(import.total_rows as i32),
+            accepted_rows: Set(import.rows.len() as i32),
+            processed_rows: Set(0),
+            created_count: Set(0),
+            errors: Set(to_json(&import.errors)?),
+            locked_until: Set(None),
+            created_at: Set(import.created_at.into()),
+            finished_at: Set(None),
+        };
+
+        book_imports::Entity::insert(active_model)
+            .exec_without_returning(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn claim_next(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Option<ClaimedBookImport>, PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        let row = book_imports::Entity::find()
+            .filter(book_imports::Column::Status.is_in([
+                BookImportStatusDTO::Pending.as_ref(),
+                BookImportStatusDTO::Running.as_ref(),
+            ]))
+            .filter(
+                Condition::any()
+                    .add(book_imports::Column::LockedUntil.is_null())
+                    .add(book_imports::Column::LockedUntil.lte(now)),
+            )
+            .order_by_asc(book_imports::Column::CreatedAt)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .one(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        let Some(row) = row else {
+            txn.commit().await.map_err(log_db_error)?;
+            return Ok(None);
+        };
+
+        book_imports::Entity::update_many()
+            .col_expr(
+                book_imports::Column::Status,
+                Expr::value(BookImportStatusDTO::Running.as_ref()),
+            )
+            .col_expr(book_imports::Column::LockedUntil, Expr::value(lease_until))
+            .filter(book_imports::Column::Id.eq(row.id))
+            .exec(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        Ok(Some(ClaimedBookImport {
+            id: row.id,
+            actor_id: row.actor_id.into(),
+            rows: from_json(row.rows)?,
+            processed_rows: row.processed_rows as u32,
+            created_count: row.created_count as u32,
+            errors: from_json(row.errors)?,
+        }))
+    }
+
+    async fn record_progress(
+        &self,
+        id: Uuid,
+        progress: &BookImportProgress,
+    ) -> Result<(), PersistenceError> {
+        update_progress(progress)?
+            .filter(book_imports::Column::Id.eq(id))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn save_progress(
+        &self,
+        id: Uuid,
+        progress: &BookImportProgress,
+        finished_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError> {
+        let status = match finished_at {
+            Some(_) => BookImportStatusDTO::Completed,
+            None => BookImportStatusDTO::Running,
+        };
+
+        update_progress(progress)?
+            .col_expr(book_imports::Column::Status, Expr::value(status.as_ref()))
+            .col_expr(
+                book_imports::Column::LockedUntil,
+                Expr::value(None::<DateTime<Utc>>),
+            )
+            .col_expr(book_imports::Column::FinishedAt, Expr::value(finished_at))
+            .filter(book_imports::Column::Id.eq(id))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+}
+
+fn update_progress(
+    progress: &BookImportProgress,
+) -> Result<UpdateMany<book_imports::Entity>, PersistenceError> {
+    Ok(book_imports::Entity::update_many()
+        .col_expr(
+            book_imports::Column::ProcessedRows,
+            Expr::value(progress.processed_rows as i32),
+        )
+        .col_expr(
+            book_imports::Column::CreatedCount,
+            Expr::value(progress.created_count as i32),
+        )
+        .col_expr(
+            book_imports::Column::Errors,
+            Expr::value(to_json(&progress.errors)?),
+        ))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<serde_json::Value, PersistenceError> {
+    serde_json::to_value(value).map_err(|e| PersistenceError::EntityConversionError(e.to_string()))
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<T, PersistenceError> {
+    serde_json::from_value(value)
+        .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))
+}
@@ -7,6 +7,13 @@ pub struct AppConfig {
     pub server: ServerConfig,
     pub database: DatabaseConfig,
     pub oidc: OidcConfig,
+    pub outbox: RelayConfig,
+    pub webhook: RelayConfig,
+    pub book_import: RelayConfig,
+    pub notification: NotificationConfig,
+    pub job: JobConfig,
+    pub idempotency: IdempotencyConfig,
+    pub tenant: TenantConfig,
 }
 
 impl AppConfig {
@@ -16,6 +23,13 @@ impl AppConfig {
             server: ServerConfig::new()?,
             database: DatabaseConfig::new()?,
             oidc: OidcConfig::new()?,
+            outbox: RelayConfig::new("OUTBOX")?,
+            webhook: RelayConfig::new("WEBHOOK")?,
+            book_import: RelayConfig::new("BOOK_IMPORT")?,
+            notification: NotificationConfig::new()?,
+            job: JobConfig::new()?,
+            idempotency: IdempotencyConfig::new()?,
+            tenant: TenantConfig::new()?,
         })
     }
 }
@@ -75,17 +89,240 @@ impl DatabaseConfig {
 }
 
 pub struct OidcConfig {
+    /// Trusted token issuers; the first one is the primary issuer configured by `OIDC_*`.
+    pub issuers: Vec<OidcIssuerConfig>,
+}
+
+impl OidcConfig {
+    /// Reads the primary issuer and one issuer per name in `OIDC_ADDITIONAL_ISSUERS`, each
+    /// configured by `OIDC_{NAME}_AUTHORITY`, `OIDC_{NAME}_CLIENT_ID` and so on.
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut issuers = vec![OidcIssuerConfig::new("OIDC")?];
+        if let Ok(names) = env::var("OIDC_ADDITIONAL_ISSUERS") {
+            for name in names
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+            {
+                let mut issuer = OidcIssuerConfig::new(&format!("OIDC_{}", name.to_uppercase()))?;
+                issuer.namespace_user_ids = true;
+                issuers.push(issuer);
+            }
+        }
+
+        Ok(OidcConfig { issuers })
+    }
+
+    pub fn primary(&self) -> &OidcIssuerConfig {
+        &self.issuers[0]
+    }
+}
+
+pub struct OidcIssuerConfig {
     pub authority: String,
     pub client_id: String,
     pub audience: Option<String>,
+    pub provider: OidcProvider,
+    /// Dotted path to the roles claim, e.g. `groups` or `resource_access.<client>.roles`.
+    pub roles_claim: Option<String>,
+    /// Token roles that make the user an admin, compared case-insensitively.
+    pub admin_roles: Vec<String>,
+    /// Token roles that make a non-admin user a librarian.
+    pub librarian_roles: Vec<String>,
+    /// Dotted path to the claim naming the user's tenant; when set, tokens without it are rejected.
+    pub tenant_claim: Option<String>,
+    /// Derives user ids from the authority and the provider's id, so that the issuer cannot
+    /// sign in as another issuer's users. Set for every issuer but the primary one, whose users
+    /// keep the provider's ids.
+    pub namespace_user_ids: bool,
+    /// JWS algorithm names accepted from this issuer, e.g. `RS256`, `ES256` or `EdDSA`.
+    pub algorithms: Vec<String>,
+    pub jwks_ttl_secs: u64,
+    /// Minimum time between key set fetches triggered by tokens with an unknown `kid`.
+    pub jwks_refetch_interval_secs: u64,
 }
 
-impl OidcConfig {
+impl OidcIssuerConfig {
+    pub fn new(prefix: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let key = |name: &str| format!("{}_{}", prefix, name);
+
+        Ok(OidcIssuerConfig {
+            authority: env::var(key("AUTHORITY"))?,
+            client_id: env::var(key("CLIENT_ID"))?,
+            audience: env::var(key("AUDIENCE")).ok(),
+            provider: env_or(&key("PROVIDER"), OidcProvider::Keycloak)?,
+            roles_claim: env::var(key("ROLES_CLAIM")).ok(),
+            admin_roles: env_list(&key("ADMIN_ROLES"), &["admin"]),
+            librarian_roles: env_list(&key("LIBRARIAN_ROLES"), &["librarian"]),
+            tenant_claim: env::var(key("TENANT_CLAIM")).ok(),
+            namespace_user_ids: false,
+            algorithms: env_list(&key("ALGORITHMS"), &["RS256"]),
+            jwks_ttl_secs: env_or(&key("JWKS_TTL_SECS"), 300)?,
+            jwks_refetch_interval_secs: env_or(&key("JWKS_REFETCH_INTERVAL_SECS"), 30)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum OidcProvider {
+    Keycloak,
+    #[strum(serialize = "azure")]
+    AzureAd,
+    Generic,
+}
+
+pub struct RelayConfig {
+    pub poll_interval_ms: u64,
+    pub batch_size: u64,
+    pub max_attempts: u32,
+    pub base_backoff_secs: i64,
+    pub max_backoff_secs: i64,
+    pub lease_secs: i64,
+}
+
+impl RelayConfig {
+    /// Reads `{prefix}_POLL_INTERVAL_MS`, `{prefix}_BATCH_SIZE` and so on, falling back to defaults.
+    pub fn new(prefix: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let key = |name: &str| format!("{}_{}", prefix, name);
+
+        Ok(RelayConfig {
+            poll_interval_ms: env_or(&key("POLL_INTERVAL_MS"), 1000)?,
+            batch_size: env_or(&key("BATCH_SIZE"), 50)?,
+            max_attempts: env_or(&key("MAX_ATTEMPTS"), 10)?,
+            base_backoff_secs: env_or(&key("BASE_BACKOFF_SECS"), 5)?,
+            max_backoff_secs: env_or(&key("MAX_BACKOFF_SECS"), 3600)?,
+            lease_secs: env_or(&key("LEASE_SECS"), 60)?,
+        })
+    }
+}
+
+pub struct NotificationConfig {
+    pub enabled: bool,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_tls: bool,
+    pub from: String,
+    pub loan_period_days: i64,
+    pub due_soon_hours: i64,
+}
+
+impl NotificationConfig {
+    /// Defaults target a local SMTP sink such as Mailpit, so nothing is sent unless enabled.
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        Ok(OidcConfig {
-            authority: env::var("OIDC_AUTHORITY")?,
-            client_id: env::var("OIDC_CLIENT_ID")?,
-            audience: env::var("OIDC_AUDIENCE").ok(),
+        Ok(NotificationConfig {
+            enabled: env_or("NOTIFICATION_ENABLED", false)?,
+            smtp_host: env_or("SMTP_HOST", "localhost".to_string())?,
+            smtp_port: env_or("SMTP_PORT", 1025)?,
+            smtp_username: env::var("SMTP_USERNAME").ok(),
+            smtp_password: env::var("SMTP_PASSWORD").ok(),
+            smtp_tls: env_or("SMTP_TLS", false)?,
+            from: env_or(
+                "NOTIFICATION_FROM",
+                "Book Manager <noreply@localhost>".to_string(),
+            )?,
+            loan_period_days: env_or("NOTIFICATION_LOAN_PERIOD_DAYS", 14)?,
+            due_soon_hours: env_or("NOTIFICATION_DUE_SOON_HOURS", 48)?,
         })
     }
 }
+
+pub struct JobConfig {
+    pub poll_interval_ms: u64,
+    pub lease_secs: i64,
+    pub retention_days: i64,
+    pub due_reminders_schedule: String,
+    pub jwks_prewarm_schedule: String,
+    pub outbox_cleanup_schedule: String,
+    pub webhook_delivery_cleanup_schedule: String,
+    pub idempotency_key_cleanup_schedule: String,
+}
+
+impl JobConfig {
+    /// Schedules are cron expressions with a leading seconds field.
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(JobConfig {
+            poll_interval_ms: env_or("JOB_POLL_INTERVAL_MS", 10000)?,
+            lease_secs: env_or("JOB_LEASE_SECS", 600)?,
+            retention_days: env_or("JOB_RETENTION_DAYS", 30)?,
+            due_reminders_schedule: env_or(
+                "JOB_DUE_REMINDERS_SCHEDULE",
+                "0 0 * * * *".to_string(),
+            )?,
+            jwks_prewarm_schedule: env_or(
+                "JOB_JWKS_PREWARM_SCHEDULE",
+                "0 */4 * * * *".to_string(),
+            )?,
+            outbox_cleanup_schedule: env_or(
+                "JOB_OUTBOX_CLEANUP_SCHEDULE",
+                "0 0 3 * * *".to_string(),
+            )?,
+            webhook_delivery_cleanup_schedule: env_or(
+                "JOB_WEBHOOK_DELIVERY_CLEANUP_SCHEDULE",
+                "0 30 3 * * *".to_string(),
+            )?,
+            idempotency_key_cleanup_schedule: env_or(
+                "JOB_IDEMPOTENCY_KEY_CLEANUP_SCHEDULE",
+                "0 45 * * * *".to_string(),
+            )?,
+        })
+    }
+}
+
+pub struct IdempotencyConfig {
+    pub ttl_hours: i64,
+    pub lock_timeout_secs: i64,
+    pub max_body_bytes: usize,
+}
+
+impl IdempotencyConfig {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(IdempotencyConfig {
+            ttl_hours: env_or("IDEMPOTENCY_TTL_HOURS", 24)?,
+            lock_timeout_secs: env_or("IDEMPOTENCY_LOCK_TIMEOUT_SECS", 60)?,
+            max_body_bytes: env_or("IDEMPOTENCY_MAX_BODY_BYTES", 1024 * 1024)?,
+        })
+    }
+}
+
+pub struct TenantConfig {
+    /// Domain under which each tenant has its own subdomain, e.g. `books.example.com` for
+    /// `acme.books.example.com`. Unset, every request without a tenant claim is in the default tenant.
+    pub base_domain: Option<String>,
+}
+
+impl TenantConfig {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(TenantConfig {
+            base_domain: env::var("TENANT_BASE_DOMAIN")
+                .ok()
+                .map(|domain| domain.trim_matches('.').to_lowercase()),
+        })
+    }
+}
+
+fn env_or<T>(key: &str, default: T) -> Result<T, Box<dyn std::error::Error>>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + 'static,
+{
+    match env::var(key) {
+        Ok(value) => Ok(value.parse()?),
+        Err(_) => Ok(default),
+    }
+}
+
+/// Reads a comma separated list, falling back to `default` when the variable is unset.
+fn env_list(key: &str, default: &[&str]) -> Vec<String> {
+    match env::var(key) {
+        Ok(value) => value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Err(_) => default.iter().map(|item| item.to_string()).collect(),
+    }
+}
@@ -1,5 +1,5 @@
 use domain::shared::error::PersistenceError;
-use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr, SqlErr};
 
 use crate::config::DatabaseConfig;
 
@@ -24,6 +24,12 @@ impl From<&DatabaseConfig> for ConnectOptions {
 #[derive(Clone)]
 pub struct ConnectionPool(DatabaseConnection);
 
+impl From<DatabaseConnection> for ConnectionPool {
+    fn from(conn: DatabaseConnection) -> Self {
+        Self(conn)
+    }
+}
+
 impl ConnectionPool {
     pub fn inner_ref(&self) -> &DatabaseConnection {
         &self.0
@@ -39,3 +45,14 @@ pub fn log_db_error(err: DbErr) -> PersistenceError {
     tracing::error!(error = ?err, "Database operation failed");
     PersistenceError::OperationError
 }
+
+/// Like `log_db_error`, but reports a unique constraint violation as a concurrent write.
+pub fn log_db_conflict(err: DbErr) -> PersistenceError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(detail)) => {
+            tracing::warn!(detail = %detail, "Unique constraint violated by a concurrent write");
+            PersistenceError::Conflict
+        }
+        _ => log_db_error(err),
+    }
+}
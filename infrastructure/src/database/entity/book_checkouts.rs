@@ -10,9 +10,13 @@ pub struct Model {
     pub checkout_id: Uuid,
     pub book_id: Uuid,
     pub checked_out_at: DateTimeWithTimeZone,
+    pub checked_out_to_id: Uuid,
+    pub checked_out_to_name: String,
+    pub returned_at: Option<DateTimeWithTimeZone>,
     pub checked_out_by_id: Uuid,
     pub checked_out_by_name: String,
-    pub returned_at: Option<DateTimeWithTimeZone>,
+    pub returned_by_id: Option<Uuid>,
+    pub returned_by_name: Option<String>,
     #[sea_orm(
         belongs_to,
         from = "book_id",
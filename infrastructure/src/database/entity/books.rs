@@ -10,6 +10,8 @@ pub struct Model {
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub owner_id: Uuid,
+    pub group_id: Option<Uuid>,
+    pub visibility: String,
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub created_at: DateTimeWithTimeZone,
@@ -18,10 +20,22 @@ pub struct Model {
     pub updated_at: Option<DateTimeWithTimeZone>,
     pub updated_by_id: Option<Uuid>,
     pub updated_by_name: Option<String>,
+    pub version: i32,
+    pub tenant_id: String,
     #[sea_orm(has_many)]
     pub book_authors: HasMany<super::book_authors::Entity>,
     #[sea_orm(has_many)]
     pub book_checkouts: HasMany<super::book_checkouts::Entity>,
+    #[sea_orm(has_many)]
+    pub book_co_owners: HasMany<super::book_co_owners::Entity>,
+    #[sea_orm(
+        belongs_to,
+        from = "group_id",
+        to = "id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    pub groups: HasOne<super::groups::Entity>,
     #[sea_orm(
         belongs_to,
         from = "owner_id",
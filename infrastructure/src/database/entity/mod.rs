@@ -4,5 +4,17 @@ pub mod prelude;
 
 pub mod book_authors;
 pub mod book_checkouts;
+pub mod book_co_owners;
+pub mod book_imports;
 pub mod books;
+pub mod group_members;
+pub mod groups;
+pub mod idempotency_keys;
+pub mod jobs;
+pub mod notification_log;
+pub mod outbox;
+pub mod personal_access_tokens;
+pub mod role_permissions;
 pub mod users;
+pub mod webhook_deliveries;
+pub mod webhooks;
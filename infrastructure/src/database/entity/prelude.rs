@@ -2,5 +2,17 @@
 
 pub use super::book_authors::Entity as BookAuthors;
 pub use super::book_checkouts::Entity as BookCheckouts;
+pub use super::book_co_owners::Entity as BookCoOwners;
+pub use super::book_imports::Entity as BookImports;
 pub use super::books::Entity as Books;
+pub use super::group_members::Entity as GroupMembers;
+pub use super::groups::Entity as Groups;
+pub use super::idempotency_keys::Entity as IdempotencyKeys;
+pub use super::jobs::Entity as Jobs;
+pub use super::notification_log::Entity as NotificationLog;
+pub use super::outbox::Entity as Outbox;
+pub use super::personal_access_tokens::Entity as PersonalAccessTokens;
+pub use super::role_permissions::Entity as RolePermissions;
 pub use super::users::Entity as Users;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
+pub use super::webhooks::Entity as Webhooks;
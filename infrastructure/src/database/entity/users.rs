@@ -7,7 +7,6 @@ use sea_orm::entity::prelude::*;
 #[sea_orm(table_name = "users")]
 pub struct Model {
     pub name: String,
-    #[sea_orm(unique)]
     pub email: String,
     pub role: String,
     #[sea_orm(primary_key, auto_increment = false)]
@@ -18,8 +17,27 @@ pub struct Model {
     pub updated_at: Option<DateTimeWithTimeZone>,
     pub updated_by_id: Option<Uuid>,
     pub updated_by_name: Option<String>,
+    pub version: i32,
+    pub tenant_id: String,
+    pub notify_due_soon: bool,
+    pub notify_overdue: bool,
+    pub notify_book_checked_out: bool,
+    pub notify_book_returned: bool,
+    pub default_book_visibility: String,
+    #[sea_orm(has_many)]
+    pub book_co_owners: HasMany<super::book_co_owners::Entity>,
+    #[sea_orm(has_many)]
+    pub book_imports: HasMany<super::book_imports::Entity>,
     #[sea_orm(has_many)]
     pub books: HasMany<super::books::Entity>,
+    #[sea_orm(has_many)]
+    pub group_members: HasMany<super::group_members::Entity>,
+    #[sea_orm(has_many)]
+    pub idempotency_keys: HasMany<super::idempotency_keys::Entity>,
+    #[sea_orm(has_many)]
+    pub notification_log: HasMany<super::notification_log::Entity>,
+    #[sea_orm(has_many)]
+    pub personal_access_tokens: HasMany<super::personal_access_tokens::Entity>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
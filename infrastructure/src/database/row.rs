@@ -1,5 +1,17 @@
 pub mod book;
+pub mod book_import;
+pub mod group;
+pub mod job;
+pub mod outbox;
+pub mod personal_access_token;
 pub mod user;
+pub mod webhook;
 
 pub use book::*;
+pub use book_import::*;
+pub use group::*;
+pub use job::*;
+pub use outbox::*;
+pub use personal_access_token::*;
 pub use user::*;
+pub use webhook::*;
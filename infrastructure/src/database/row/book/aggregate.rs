@@ -2,15 +2,17 @@ use application::book::dto::{BookDetailsDTO, BookListItemDTO};
 use domain::{
     auth::permission::Permission,
     book::{
-        entity::Book,
+        entity::{Book, PersistedBook},
         values::{BookAuthorName, BookId},
     },
+    group::values::GroupId,
+    shared::error::PersistenceError,
 };
 use itertools::Itertools;
 
 use crate::{
     database::row::{
-        book::rows::{BookAuthorReferenceRow, BookDetailsRow, BookListItemRow},
+        book::rows::{BookAuthorReferenceRow, BookCoOwnerRow, BookDetailsRow, BookListItemRow},
         rows::BookCheckoutRow,
     },
     macros::{hydrate_audit, hydrate_audit_dto, hydrate_audit_summary_dto},
@@ -19,39 +21,28 @@ use crate::{
 pub struct AggregatedBookDetails {
     pub row: BookDetailsRow,
     pub authors: Vec<BookAuthorReferenceRow>,
+    pub co_owners: Vec<BookCoOwnerRow>,
     pub checkouts: Vec<BookCheckoutRow>,
 }
 
 impl AggregatedBookDetails {
-    pub fn from_rows(rows: Vec<BookDetailsRow>) -> Option<Self> {
-        Some(Self {
-            row: rows.first()?.clone(),
-            authors: rows
-                .iter()
-                .map(|r| r.author.clone())
-                .unique_by(|a| a.order_index)
-                .collect(),
-            checkouts: rows
-                .iter()
-                .filter_map(|r| r.checkout.clone())
-                .unique_by(|c| c.checkout_id)
-                .collect(),
-        })
-    }
+    pub fn to_dto<T: Permission>(self, permission: T) -> Result<BookDetailsDTO, PersistenceError> {
+        let visibility = self.row.visibility()?.into();
 
-    pub fn to_dto<T: Permission>(self, permission: T) -> BookDetailsDTO {
-        BookDetailsDTO {
+        Ok(BookDetailsDTO {
             id: self.row.id,
             title: self.row.title,
-            authors: self
-                .authors
-                .into_iter()
-                .unique_by(|a| a.order_index)
-                .map(|a| a.name)
-                .collect(),
+            authors: self.authors.into_iter().map(|a| a.name).collect(),
             isbn: self.row.isbn,
             description: self.row.description,
             owner: self.row.user.to_dto(),
+            co_owners: self
+                .co_owners
+                .into_iter()
+                .map(|c| c.user.to_dto())
+                .collect(),
+            group: self.row.group.map(|g| g.to_dto()),
+            visibility,
             checkout: self
                 .checkouts
                 .into_iter()
@@ -59,25 +50,33 @@ impl AggregatedBookDetails {
                 .max_by_key(|c| c.checked_out_at)
                 .map(|c| c.to_dto()),
             audit: hydrate_audit_dto!(self.row, permission),
-        }
+        })
     }
 
-    pub fn to_entity(self) -> Book {
+    pub fn to_entity(self) -> Result<Book, PersistenceError> {
+        let visibility = self.row.visibility()?;
         let authors_with_index: Vec<(BookAuthorName, usize)> = self
             .authors
             .into_iter()
             .map(|a| (a.to_domain(), a.order_index as usize))
             .collect();
 
-        Book::hydrate(
-            hydrate_audit!(self.row, BookId),
-            self.row.title,
-            authors_with_index,
-            self.row.isbn,
-            self.row.description,
-            self.row.user.to_domain(),
-            self.checkouts.into_iter().map(|c| c.to_domain()).collect(),
-        )
+        Ok(Book::hydrate(PersistedBook {
+            audit: hydrate_audit!(self.row, BookId),
+            title: self.row.title,
+            authors: authors_with_index,
+            isbn: self.row.isbn,
+            description: self.row.description,
+            owner: self.row.user.to_domain(),
+            co_owners: self
+                .co_owners
+                .into_iter()
+                .map(|c| c.user.to_domain())
+                .collect(),
+            group_id: self.row.group_id.map(GroupId::from),
+            visibility,
+            checkouts: self.checkouts.into_iter().map(|c| c.to_domain()).collect(),
+        }))
     }
 }
 
@@ -111,14 +110,17 @@ impl AggregatedBookListItem {
             .collect()
     }
 
-    pub fn to_dto<T: Permission>(self, permission: T) -> BookListItemDTO {
-        BookListItemDTO {
+    pub fn to_dto<T: Permission>(self, permission: T) -> Result<BookListItemDTO, PersistenceError> {
+        let visibility = self.row.visibility()?.into();
+
+        Ok(BookListItemDTO {
             id: self.row.id,
             title: self.row.title,
             authors: self.authors.into_iter().map(|a| a.name).collect(),
+            visibility,
             owner: self.row.user.to_dto(),
             checked_out: self.checkouts.into_iter().any(|c| c.returned_at.is_none()),
             audit: hydrate_audit_summary_dto!(self.row, permission),
-        }
+        })
     }
 }
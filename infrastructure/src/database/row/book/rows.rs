@@ -1,20 +1,27 @@
-use application::{book::dto::BookCheckoutDTO, shared::UserReferenceDTO};
+use std::str::FromStr;
+
+use application::{
+    book::dto::{BookCheckoutDTO, BookExportItemDTO},
+    shared::UserReferenceDTO,
+};
 use domain::{
-    book::values::{BookAuthorName, BookCheckout},
+    book::{
+        enums::BookVisibility,
+        values::{BookAuthorName, BookCheckout},
+    },
+    shared::error::PersistenceError,
     user::values::UserReference,
 };
 use sea_orm::{DerivePartialModel, prelude::DateTimeWithTimeZone};
 use uuid::Uuid;
 
-use crate::database::row::user::UserReferenceRow;
+use crate::database::row::{group::GroupReferenceRow, user::UserReferenceRow};
 
 #[derive(DerivePartialModel, Clone)]
 #[sea_orm(entity = "crate::database::entity::books::Entity")]
 pub struct BookDetailsRow {
     pub id: Uuid,
     pub title: String,
-    #[sea_orm(nested, alias = "book_authors")]
-    pub author: BookAuthorReferenceRow,
     pub isbn: Option<String>,
     pub description: Option<String>,
     pub created_at: DateTimeWithTimeZone,
@@ -23,10 +30,20 @@ pub struct BookDetailsRow {
     pub updated_at: Option<DateTimeWithTimeZone>,
     pub updated_by_id: Option<Uuid>,
     pub updated_by_name: Option<String>,
+    pub version: i32,
+    pub tenant_id: String,
+    pub group_id: Option<Uuid>,
+    pub visibility: String,
     #[sea_orm(nested, alias = "users")]
     pub user: UserReferenceRow,
-    #[sea_orm(nested, alias = "book_checkouts")]
-    pub checkout: Option<BookCheckoutRow>,
+    #[sea_orm(nested, alias = "groups")]
+    pub group: Option<GroupReferenceRow>,
+}
+
+impl BookDetailsRow {
+    pub fn visibility(&self) -> Result<BookVisibility, PersistenceError> {
+        parse_visibility(&self.visibility)
+    }
 }
 
 #[derive(DerivePartialModel, Clone)]
@@ -39,30 +56,70 @@ pub struct BookListItemRow {
     pub description: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub group_id: Option<Uuid>,
+    pub visibility: String,
     #[sea_orm(nested, alias = "users")]
     pub user: UserReferenceRow,
     #[sea_orm(nested, alias = "book_checkouts")]
     pub checkout: Option<BookCheckoutRow>,
 }
 
+impl BookListItemRow {
+    pub fn visibility(&self) -> Result<BookVisibility, PersistenceError> {
+        parse_visibility(&self.visibility)
+    }
+}
+
+#[derive(DerivePartialModel, Clone)]
+#[sea_orm(entity = "crate::database::entity::books::Entity")]
+pub struct BookExportRow {
+    pub id: Uuid,
+    pub title: String,
+    pub isbn: Option<String>,
+    pub description: Option<String>,
+    #[sea_orm(nested, alias = "users")]
+    pub user: UserReferenceRow,
+}
+
+impl BookExportRow {
+    pub fn to_export_dto(self, authors: Vec<String>, checked_out: bool) -> BookExportItemDTO {
+        BookExportItemDTO {
+            id: self.id,
+            title: self.title,
+            authors,
+            isbn: self.isbn,
+            description: self.description,
+            owner: self.user.to_dto(),
+            checked_out,
+        }
+    }
+}
+
 #[derive(DerivePartialModel, Clone)]
 #[sea_orm(entity = "crate::database::entity::book_checkouts::Entity")]
 pub struct BookCheckoutRow {
     pub checkout_id: Uuid,
     pub book_id: Uuid,
     pub checked_out_at: DateTimeWithTimeZone,
+    pub checked_out_to_id: Uuid,
+    pub checked_out_to_name: String,
     pub checked_out_by_id: Uuid,
     pub checked_out_by_name: String,
     pub returned_at: Option<DateTimeWithTimeZone>,
+    pub returned_by_id: Option<Uuid>,
+    pub returned_by_name: Option<String>,
 }
 
 impl BookCheckoutRow {
     pub fn to_domain(self) -> BookCheckout {
         BookCheckout::hydrate(
             self.checkout_id,
+            UserReference::hydrate(self.checked_out_to_id, self.checked_out_to_name),
             UserReference::hydrate(self.checked_out_by_id, self.checked_out_by_name),
             self.checked_out_at.into(),
             self.returned_at.map(|dt| dt.into()),
+            self.returned_by_id
+                .map(|id| UserReference::hydrate(id, self.returned_by_name.unwrap_or_default())),
         )
     }
 
@@ -71,6 +128,10 @@ impl BookCheckoutRow {
             checkout_id: self.checkout_id,
             checked_out_at: self.checked_out_at.into(),
             checked_out_to: UserReferenceDTO {
+                id: self.checked_out_to_id,
+                name: self.checked_out_to_name,
+            },
+            checked_out_by: UserReferenceDTO {
                 id: self.checked_out_by_id,
                 name: self.checked_out_by_name,
             },
@@ -78,6 +139,14 @@ impl BookCheckoutRow {
     }
 }
 
+#[derive(DerivePartialModel, Clone)]
+#[sea_orm(entity = "crate::database::entity::book_co_owners::Entity")]
+pub struct BookCoOwnerRow {
+    pub book_id: Uuid,
+    #[sea_orm(nested, alias = "users")]
+    pub user: UserReferenceRow,
+}
+
 #[derive(DerivePartialModel, Clone)]
 #[sea_orm(entity = "crate::database::entity::book_authors::Entity")]
 pub struct BookAuthorReferenceRow {
@@ -90,3 +159,8 @@ impl BookAuthorReferenceRow {
         BookAuthorName::hydrate(self.name.clone())
     }
 }
+
+fn parse_visibility(value: &str) -> Result<BookVisibility, PersistenceError> {
+    BookVisibility::from_str(value)
+        .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))
+}
@@ -0,0 +1,43 @@
+use std::str::FromStr;
+
+use application::book_import::dto::{BookImportDTO, BookImportStatusDTO};
+use domain::shared::error::PersistenceError;
+use sea_orm::{
+    DerivePartialModel,
+    prelude::{DateTimeWithTimeZone, Json, Uuid},
+};
+
+/// Everything but the queued rows, which are only read by the import worker.
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::book_imports::Entity")]
+pub struct BookImportDTORow {
+    pub id: Uuid,
+    pub actor_id: Uuid,
+    pub status: String,
+    pub total_rows: i32,
+    pub accepted_rows: i32,
+    pub processed_rows: i32,
+    pub created_count: i32,
+    pub errors: Json,
+    pub created_at: DateTimeWithTimeZone,
+    pub finished_at: Option<DateTimeWithTimeZone>,
+}
+
+impl BookImportDTORow {
+    pub fn to_dto(self) -> Result<BookImportDTO, PersistenceError> {
+        Ok(BookImportDTO {
+            id: Some(self.id),
+            status: BookImportStatusDTO::from_str(&self.status)
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            requested_by_id: self.actor_id,
+            total_rows: self.total_rows as u32,
+            accepted_rows: self.accepted_rows as u32,
+            processed_rows: self.processed_rows as u32,
+            created_count: self.created_count as u32,
+            errors: serde_json::from_value(self.errors)
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            created_at: Some(self.created_at.into()),
+            finished_at: self.finished_at.map(|dt| dt.into()),
+        })
+    }
+}
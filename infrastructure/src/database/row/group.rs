@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use application::group::dto::{
+    GroupDetailsDTO, GroupMemberDTO, GroupMemberRoleDTO, GroupReferenceDTO, GroupSummaryDTO,
+};
+use domain::{
+    auth::permission::Permission,
+    group::{enums::GroupMemberRole, values::GroupMember},
+    shared::error::PersistenceError,
+};
+use sea_orm::{DerivePartialModel, prelude::DateTimeWithTimeZone};
+use uuid::Uuid;
+
+use crate::{database::row::user::UserReferenceRow, macros::hydrate_audit_dto};
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::groups::Entity")]
+pub struct GroupRow {
+    pub id: Uuid,
+    pub name: String,
+    pub private_library: bool,
+    pub created_at: DateTimeWithTimeZone,
+    pub created_by_id: Uuid,
+    pub created_by_name: String,
+    pub updated_at: Option<DateTimeWithTimeZone>,
+    pub updated_by_id: Option<Uuid>,
+    pub updated_by_name: Option<String>,
+    pub version: i32,
+}
+
+impl GroupRow {
+    pub fn to_dto<T: Permission>(
+        self,
+        members: Vec<GroupMemberRow>,
+        permission: T,
+    ) -> Result<GroupDetailsDTO, PersistenceError> {
+        Ok(GroupDetailsDTO {
+            id: self.id,
+            name: self.name.clone(),
+            private_library: self.private_library,
+            members: members
+                .into_iter()
+                .map(|member| member.to_dto())
+                .collect::<Result<_, _>>()?,
+            audit: hydrate_audit_dto!(self, permission),
+        })
+    }
+
+    pub fn to_summary_dto(self, member_count: u64) -> GroupSummaryDTO {
+        GroupSummaryDTO {
+            id: self.id,
+            name: self.name,
+            private_library: self.private_library,
+            member_count,
+        }
+    }
+}
+
+#[derive(DerivePartialModel, Clone)]
+#[sea_orm(entity = "crate::database::entity::group_members::Entity")]
+pub struct GroupMemberRow {
+    pub group_id: Uuid,
+    pub role: String,
+    #[sea_orm(nested, alias = "users")]
+    pub user: UserReferenceRow,
+}
+
+impl GroupMemberRow {
+    pub fn is_admin(&self) -> bool {
+        GroupMemberRole::from_str(&self.role).is_ok_and(|role| role == GroupMemberRole::Admin)
+    }
+
+    pub fn to_domain(self) -> Result<GroupMember, PersistenceError> {
+        let role = GroupMemberRole::from_str(&self.role)
+            .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?;
+        Ok(GroupMember::new(self.user.to_domain(), role))
+    }
+
+    pub fn to_dto(self) -> Result<GroupMemberDTO, PersistenceError> {
+        Ok(GroupMemberDTO {
+            role: GroupMemberRoleDTO::from_str(&self.role)
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            user: self.user.to_dto(),
+        })
+    }
+}
+
+#[derive(DerivePartialModel, Clone)]
+#[sea_orm(entity = "crate::database::entity::groups::Entity")]
+pub struct GroupReferenceRow {
+    pub id: Uuid,
+    pub name: String,
+    pub private_library: bool,
+}
+
+impl GroupReferenceRow {
+    pub fn to_dto(self) -> GroupReferenceDTO {
+        GroupReferenceDTO {
+            id: self.id,
+            name: self.name,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use application::job::dto::{JobDTO, JobStatusDTO};
+use domain::shared::error::PersistenceError;
+use sea_orm::{DerivePartialModel, prelude::DateTimeWithTimeZone};
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::jobs::Entity")]
+pub struct JobRow {
+    pub name: String,
+    pub schedule: String,
+    pub next_run_at: DateTimeWithTimeZone,
+    pub run_requested: bool,
+    pub last_status: Option<String>,
+    pub last_started_at: Option<DateTimeWithTimeZone>,
+    pub last_finished_at: Option<DateTimeWithTimeZone>,
+    pub last_error: Option<String>,
+}
+
+impl JobRow {
+    pub fn to_dto(self) -> Result<JobDTO, PersistenceError> {
+        Ok(JobDTO {
+            name: self.name,
+            schedule: self.schedule,
+            next_run_at: self.next_run_at.into(),
+            run_requested: self.run_requested,
+            last_status: self
+                .last_status
+                .map(|status| JobStatusDTO::from_str(&status))
+                .transpose()
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            last_started_at: self.last_started_at.map(|dt| dt.into()),
+            last_finished_at: self.last_finished_at.map(|dt| dt.into()),
+            last_error: self.last_error,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+use application::outbox::dto::{OutboxMessageDTO, OutboxStatusDTO};
+use domain::shared::error::PersistenceError;
+use sea_orm::{DerivePartialModel, prelude::DateTimeWithTimeZone};
+use uuid::Uuid;
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::outbox::Entity")]
+pub struct OutboxMessageRow {
+    pub id: Uuid,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: i32,
+    pub occurred_at: DateTimeWithTimeZone,
+    pub next_attempt_at: DateTimeWithTimeZone,
+    pub delivered_at: Option<DateTimeWithTimeZone>,
+    pub last_error: Option<String>,
+}
+
+impl OutboxMessageRow {
+    pub fn to_dto(self) -> Result<OutboxMessageDTO, PersistenceError> {
+        Ok(OutboxMessageDTO {
+            id: self.id,
+            event_type: self.event_type,
+            status: OutboxStatusDTO::from_str(&self.status)
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            attempts: self.attempts as u32,
+            occurred_at: self.occurred_at.into(),
+            next_attempt_at: self.next_attempt_at.into(),
+            delivered_at: self.delivered_at.map(|dt| dt.into()),
+            last_error: self.last_error,
+        })
+    }
+}
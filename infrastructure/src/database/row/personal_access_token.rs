@@ -0,0 +1,69 @@
+use application::{
+    personal_access_token::dto::{
+        PersonalAccessTokenCredentialDTO, PersonalAccessTokenDTO, TokenScopeDTO,
+    },
+    user::dto::UserDetailsDTO,
+};
+use domain::{shared::error::PersistenceError, tenant::values::TenantId};
+use sea_orm::{
+    DerivePartialModel,
+    prelude::{DateTimeWithTimeZone, Json},
+};
+use uuid::Uuid;
+
+/// Everything but the token hash, which never leaves the credential lookup.
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::personal_access_tokens::Entity")]
+pub struct PersonalAccessTokenRow {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Json,
+    pub created_at: DateTimeWithTimeZone,
+    pub expires_at: DateTimeWithTimeZone,
+    pub last_used_at: Option<DateTimeWithTimeZone>,
+}
+
+impl PersonalAccessTokenRow {
+    pub fn to_dto(self) -> Result<PersonalAccessTokenDTO, PersistenceError> {
+        Ok(PersonalAccessTokenDTO {
+            id: self.id,
+            name: self.name,
+            scopes: scopes_from_json(self.scopes)?,
+            created_at: self.created_at.into(),
+            expires_at: self.expires_at.into(),
+            last_used_at: self.last_used_at.map(|dt| dt.into()),
+        })
+    }
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::personal_access_tokens::Entity")]
+pub struct PersonalAccessTokenCredentialRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub tenant_id: String,
+    pub scopes: Json,
+    pub expires_at: DateTimeWithTimeZone,
+    pub last_used_at: Option<DateTimeWithTimeZone>,
+}
+
+impl PersonalAccessTokenCredentialRow {
+    pub fn to_dto(
+        self,
+        user: UserDetailsDTO,
+    ) -> Result<PersonalAccessTokenCredentialDTO, PersistenceError> {
+        Ok(PersonalAccessTokenCredentialDTO {
+            id: self.id,
+            user,
+            tenant: TenantId::hydrate(self.tenant_id),
+            scopes: scopes_from_json(self.scopes)?,
+            expires_at: self.expires_at.into(),
+            last_used_at: self.last_used_at.map(|dt| dt.into()),
+        })
+    }
+}
+
+fn scopes_from_json(scopes: Json) -> Result<Vec<TokenScopeDTO>, PersistenceError> {
+    serde_json::from_value(scopes)
+        .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))
+}
@@ -1,13 +1,21 @@
 use std::str::FromStr;
 
 use application::{
+    notification::interface::NotificationRecipient,
     shared::UserReferenceDTO,
-    user::dto::{UserDetailsDTO, UserRoleDTO},
+    user::dto::{BookSettingsDTO, NotificationSettingsDTO, UserDetailsDTO, UserRoleDTO},
 };
 use domain::{
     audit::Actor,
+    auth::capability::CapabilitySet,
+    book::enums::BookVisibility,
+    group::values::GroupId,
     shared::error::PersistenceError,
-    user::{enums::UserRole, values::UserReference},
+    tenant::values::TenantId,
+    user::{
+        enums::UserRole,
+        values::{NotificationPreferences, UserReference},
+    },
 };
 use sea_orm::DerivePartialModel;
 use uuid::Uuid;
@@ -39,6 +47,7 @@ pub struct UserDetailsDTORow {
     pub name: String,
     pub email: String,
     pub role: String,
+    pub version: i32,
 }
 
 impl UserDetailsDTORow {
@@ -49,6 +58,7 @@ impl UserDetailsDTORow {
             email: self.email,
             role: UserRoleDTO::from_str(&self.role)
                 .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            version: self.version as u32,
         })
     }
 }
@@ -59,15 +69,95 @@ pub struct ActorRow {
     pub id: Uuid,
     pub name: String,
     pub role: String,
+    pub tenant_id: String,
 }
 
 impl ActorRow {
-    pub fn to_actor(self) -> Result<Actor, PersistenceError> {
-        Ok(Actor::hydrate(
+    pub fn role(&self) -> Result<UserRole, PersistenceError> {
+        UserRole::from_str(&self.role)
+            .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))
+    }
+
+    pub fn to_actor(
+        self,
+        role: UserRole,
+        capabilities: CapabilitySet,
+        groups: Vec<GroupId>,
+    ) -> Actor {
+        Actor::hydrate(
             self.id,
             self.name,
-            UserRole::from_str(&self.role)
-                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
-        ))
+            role,
+            capabilities,
+            groups,
+            TenantId::hydrate(self.tenant_id),
+        )
+    }
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::users::Entity")]
+pub struct NotificationSettingsRow {
+    pub notify_due_soon: bool,
+    pub notify_overdue: bool,
+    pub notify_book_checked_out: bool,
+    pub notify_book_returned: bool,
+}
+
+impl NotificationSettingsRow {
+    pub fn to_dto(self) -> NotificationSettingsDTO {
+        NotificationSettingsDTO {
+            due_soon: self.notify_due_soon,
+            overdue: self.notify_overdue,
+            book_checked_out: self.notify_book_checked_out,
+            book_returned: self.notify_book_returned,
+        }
+    }
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::users::Entity")]
+pub struct BookSettingsRow {
+    pub default_book_visibility: String,
+}
+
+impl BookSettingsRow {
+    pub fn default_visibility(&self) -> Result<BookVisibility, PersistenceError> {
+        BookVisibility::from_str(&self.default_book_visibility)
+            .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))
+    }
+
+    pub fn to_dto(self) -> Result<BookSettingsDTO, PersistenceError> {
+        Ok(BookSettingsDTO {
+            default_visibility: self.default_visibility()?.into(),
+        })
+    }
+}
+
+#[derive(DerivePartialModel, Clone)]
+#[sea_orm(entity = "crate::database::entity::users::Entity")]
+pub struct NotificationRecipientRow {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub notify_due_soon: bool,
+    pub notify_overdue: bool,
+    pub notify_book_checked_out: bool,
+    pub notify_book_returned: bool,
+}
+
+impl NotificationRecipientRow {
+    pub fn to_recipient(self) -> NotificationRecipient {
+        NotificationRecipient {
+            id: self.id.into(),
+            name: self.name,
+            email: self.email,
+            preferences: NotificationPreferences::new(
+                self.notify_due_soon,
+                self.notify_overdue,
+                self.notify_book_checked_out,
+                self.notify_book_returned,
+            ),
+        }
     }
 }
@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use application::webhook::dto::{WebhookDTO, WebhookDeliveryDTO, WebhookDeliveryStatusDTO};
+use domain::{auth::permission::Permission, shared::error::PersistenceError};
+use sea_orm::{
+    DerivePartialModel,
+    prelude::{DateTimeWithTimeZone, Json},
+};
+use uuid::Uuid;
+
+use crate::macros::hydrate_audit_dto;
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::webhooks::Entity")]
+pub struct WebhookRow {
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Json,
+    pub created_at: DateTimeWithTimeZone,
+    pub created_by_id: Uuid,
+    pub created_by_name: String,
+    pub updated_at: Option<DateTimeWithTimeZone>,
+    pub updated_by_id: Option<Uuid>,
+    pub updated_by_name: Option<String>,
+    pub version: i32,
+}
+
+impl WebhookRow {
+    pub fn to_dto<T: Permission>(self, permission: T) -> Result<WebhookDTO, PersistenceError> {
+        Ok(WebhookDTO {
+            id: self.id,
+            url: self.url.clone(),
+            event_types: serde_json::from_value(self.event_types.clone())
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            audit: hydrate_audit_dto!(self, permission),
+        })
+    }
+}
+
+#[derive(DerivePartialModel)]
+#[sea_orm(entity = "crate::database::entity::webhook_deliveries::Entity")]
+pub struct WebhookDeliveryRow {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub next_attempt_at: DateTimeWithTimeZone,
+    pub delivered_at: Option<DateTimeWithTimeZone>,
+}
+
+impl WebhookDeliveryRow {
+    pub fn to_dto(self) -> Result<WebhookDeliveryDTO, PersistenceError> {
+        Ok(WebhookDeliveryDTO {
+            id: self.id,
+            event_id: self.event_id,
+            event_type: self.event_type,
+            status: WebhookDeliveryStatusDTO::from_str(&self.status)
+                .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+            attempts: self.attempts as u32,
+            response_status: self.response_status.map(|s| s as u16),
+            last_error: self.last_error,
+            created_at: self.created_at.into(),
+            next_attempt_at: self.next_attempt_at.into(),
+            delivered_at: self.delivered_at.map(|dt| dt.into()),
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod listener;
+mod query_service;
+
+pub(crate) use listener::notify_events;
+pub use listener::{EventListenerImpl, EventSubscription};
+pub use query_service::EventQueryServiceImpl;
@@ -0,0 +1,98 @@
+use derive_new::new;
+use domain::{event::DomainEvent, shared::error::PersistenceError};
+use sea_orm::{ConnectionTrait, DbBackend, EntityTrait, Statement, sqlx::postgres::PgListener};
+use uuid::Uuid;
+
+use crate::database::{ConnectionPool, entity::outbox, log_db_error};
+
+/// Postgres channel notified with the id of every event written to the outbox.
+const EVENT_CHANNEL: &str = "domain_events";
+
+/// Notifies every listener of the events once the caller's transaction commits.
+pub(crate) async fn notify_events<C: ConnectionTrait>(
+    conn: &C,
+    events: &[DomainEvent],
+) -> Result<(), PersistenceError> {
+    for event in events {
+        conn.execute_raw(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_notify($1, $2)",
+            [EVENT_CHANNEL.into(), event.id().to_string().into()],
+        ))
+        .await
+        .map_err(log_db_error)?;
+    }
+
+    Ok(())
+}
+
+/// Receives the events committed on any replica, unlike the outbox relay which hands each
+/// event to a single replica.
+#[derive(new)]
+pub struct EventListenerImpl {
+    db: ConnectionPool,
+}
+
+impl EventListenerImpl {
+    pub async fn listen(&self) -> Result<EventSubscription, PersistenceError> {
+        let pool = self.db.inner_ref().get_postgres_connection_pool();
+        let mut listener = PgListener::connect_with(pool)
+            .await
+            .map_err(listener_error)?;
+        listener
+            .listen(EVENT_CHANNEL)
+            .await
+            .map_err(listener_error)?;
+
+        Ok(EventSubscription {
+            db: self.db.clone(),
+            listener,
+        })
+    }
+}
+
+pub struct EventSubscription {
+    db: ConnectionPool,
+    listener: PgListener,
+}
+
+impl EventSubscription {
+    /// Waits for the next committed event. `None` means the connection was lost and any event
+    /// committed in the meantime was missed; the next call reconnects.
+    pub async fn recv(&mut self) -> Result<Option<DomainEvent>, PersistenceError> {
+        loop {
+            let Some(notification) = self.listener.try_recv().await.map_err(listener_error)? else {
+                return Ok(None);
+            };
+
+            let Ok(id) = Uuid::parse_str(notification.payload()) else {
+                tracing::warn!(
+                    payload = notification.payload(),
+                    "Ignoring malformed event notification"
+                );
+                continue;
+            };
+
+            // The row may already be purged if the listener fell far behind
+            let Some(row) = outbox::Entity::find_by_id(id)
+                .one(self.db.inner_ref())
+                .await
+                .map_err(log_db_error)?
+            else {
+                continue;
+            };
+
+            match serde_json::from_value::<DomainEvent>(row.payload) {
+                Ok(event) => return Ok(Some(event)),
+                Err(err) => {
+                    tracing::warn!(error = ?err, message_id = %row.id, "Skipping malformed outbox payload");
+                }
+            }
+        }
+    }
+}
+
+fn listener_error(err: sea_orm::sqlx::Error) -> PersistenceError {
+    tracing::error!(error = ?err, "Event listener failed");
+    PersistenceError::OperationError
+}
@@ -0,0 +1,61 @@
+use application::event::interface::EventQueryService;
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{event::DomainEvent, shared::error::PersistenceError};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+use crate::database::{ConnectionPool, entity::outbox, log_db_error};
+
+/// Reads past events back from the outbox, which keeps every recorded event.
+#[derive(new)]
+pub struct EventQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl EventQueryService for EventQueryServiceImpl {
+    async fn get_events_after(
+        &self,
+        last_event_id: Uuid,
+        limit: u64,
+    ) -> Result<Option<Vec<DomainEvent>>, PersistenceError> {
+        let Some(last_event) = outbox::Entity::find_by_id(last_event_id)
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+        else {
+            return Ok(None);
+        };
+
+        let rows = outbox::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(outbox::Column::OccurredAt.gt(last_event.occurred_at))
+                    .add(
+                        Condition::all()
+                            .add(outbox::Column::OccurredAt.eq(last_event.occurred_at))
+                            .add(outbox::Column::Id.gt(last_event.id)),
+                    ),
+            )
+            .order_by_asc(outbox::Column::OccurredAt)
+            .order_by_asc(outbox::Column::Id)
+            .limit(limit)
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        let events = rows
+            .into_iter()
+            .filter_map(|row| match serde_json::from_value::<DomainEvent>(row.payload) {
+                Ok(event) => Some(event),
+                Err(err) => {
+                    tracing::warn!(error = ?err, message_id = %row.id, "Skipping malformed outbox payload");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(Some(events))
+    }
+}
@@ -0,0 +1,6 @@
+mod loader;
+mod query_service;
+mod repository;
+
+pub use query_service::GroupQueryServiceImpl;
+pub use repository::GroupRepositoryImpl;
@@ -0,0 +1,22 @@
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::database::{
+    entity::{group_members, users},
+    row::group::GroupMemberRow,
+};
+
+/// Members of the given groups, admins first and then by name.
+pub(super) async fn load_members<C: ConnectionTrait>(
+    db: &C,
+    group_ids: impl IntoIterator<Item = Uuid>,
+) -> Result<Vec<GroupMemberRow>, DbErr> {
+    group_members::Entity::find()
+        .inner_join(users::Entity)
+        .filter(group_members::Column::GroupId.is_in(group_ids))
+        .order_by_asc(group_members::Column::Role)
+        .order_by_asc(users::Column::Name)
+        .into_partial_model::<GroupMemberRow>()
+        .all(db)
+        .await
+}
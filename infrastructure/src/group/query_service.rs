@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use application::group::{dto::*, interface::GroupQueryService};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{audit::Actor, auth::permission::GroupPermission, shared::error::PersistenceError};
+use sea_orm::{
+    ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, prelude::Expr,
+    sea_query::Func,
+};
+use uuid::Uuid;
+
+use crate::{
+    database::{
+        ConnectionPool,
+        entity::{group_members, groups},
+        log_db_error,
+        row::group::GroupRow,
+    },
+    group::loader::load_members,
+};
+
+#[derive(new)]
+pub struct GroupQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl GroupQueryService for GroupQueryServiceImpl {
+    async fn get_group_details(
+        &self,
+        actor: &Actor,
+        identity: GroupIdentity,
+    ) -> Result<Option<GroupDetailsDTO>, PersistenceError> {
+        let Some(row) = groups::Entity::find_by_id(identity.group_id)
+            .filter(groups::Column::TenantId.eq(actor.tenant().raw()))
+            .into_partial_model::<GroupRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+        else {
+            return Ok(None);
+        };
+
+        let members = load_members(self.db.inner_ref(), [row.id])
+            .await
+            .map_err(log_db_error)?;
+        let admin_ids = members
+            .iter()
+            .filter(|member| member.is_admin())
+            .map(|member| member.user.id.into())
+            .collect();
+
+        row.to_dto(members, GroupPermission::new(actor, admin_ids))
+            .map(Some)
+    }
+
+    async fn get_group_list(
+        &self,
+        actor: &Actor,
+    ) -> Result<Vec<GroupSummaryDTO>, PersistenceError> {
+        let rows = groups::Entity::find()
+            .filter(groups::Column::TenantId.eq(actor.tenant().raw()))
+            .apply_if(
+                (!actor.is_admin()).then_some(actor.raw_id()),
+                |q, user_id| {
+                    q.filter(
+                        groups::Column::Id.in_subquery(
+                            group_members::Entity::find()
+                                .select_only()
+                                .column(group_members::Column::GroupId)
+                                .filter(group_members::Column::UserId.eq(user_id))
+                                .into_query(),
+                        ),
+                    )
+                },
+            )
+            .order_by_asc(groups::Column::Name)
+            .into_partial_model::<GroupRow>()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        let member_counts: HashMap<Uuid, i64> = group_members::Entity::find()
+            .select_only()
+            .column(group_members::Column::GroupId)
+            .column_as(
+                Expr::expr(Func::count(Expr::col(group_members::Column::UserId))),
+                "member_count",
+            )
+            .filter(group_members::Column::GroupId.is_in(rows.iter().map(|row| row.id)))
+            .group_by(group_members::Column::GroupId)
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .collect();
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let member_count = member_counts.get(&row.id).copied().unwrap_or_default();
+                row.to_summary_dto(member_count as u64)
+            })
+            .collect())
+    }
+}
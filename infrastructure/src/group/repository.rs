@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    group::{entity::Group, interface::GroupRepository, values::GroupId},
+    shared::error::PersistenceError,
+};
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
+
+use crate::{
+    database::{
+        ConnectionPool,
+        entity::{group_members, groups},
+        log_db_error,
+    },
+    group::loader::load_members,
+    macros::{audit_defaults, hydrate_audit, update_on_conflict},
+};
+
+#[derive(new)]
+pub struct GroupRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl GroupRepository for GroupRepositoryImpl {
+    async fn find_by_id(&self, id: GroupId) -> Result<Option<Group>, PersistenceError> {
+        let Some(group) = groups::Entity::find_by_id(id)
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+        else {
+            return Ok(None);
+        };
+
+        let members = load_members(self.db.inner_ref(), [group.id])
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .map(|member| member.to_domain())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(Group::hydrate(
+            hydrate_audit!(group, GroupId),
+            group.name,
+            group.private_library,
+            members,
+        )))
+    }
+
+    async fn save(&self, group: &mut Group) -> Result<(), PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        let active_model = groups::ActiveModel {
+            name: Set(group.name().into()),
+            private_library: Set(group.private_library()),
+            ..audit_defaults!(groups::ActiveModel, group.audit())
+        };
+
+        let rows_affected = groups::Entity::insert(active_model)
+            .on_conflict(update_on_conflict!(groups::Column, group.audit()))
+            .exec_without_returning(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        if rows_affected == 0 {
+            return Err(PersistenceError::Conflict);
+        }
+
+        // Groups are small, so the member list is rewritten on every save
+        group_members::Entity::delete_many()
+            .filter(group_members::Column::GroupId.eq(group.audit().raw_id()))
+            .exec(&txn)
+            .await
+            .map_err(log_db_error)?;
+        group_members::Entity::insert_many(
+            group
+                .members()
+                .iter()
+                .map(|member| group_members::ActiveModel {
+                    group_id: Set(group.audit().raw_id()),
+                    user_id: Set(member.user().raw_id()),
+                    role: Set(member.role().as_ref().into()),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .exec(&txn)
+        .await
+        .map_err(log_db_error)?;
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        group.mark_saved();
+
+        Ok(())
+    }
+
+    async fn delete(&self, group: &Group) -> Result<(), PersistenceError> {
+        // Matching the version keeps a delete checked against If-Match from racing an update
+        let result = groups::Entity::delete_many()
+            .filter(groups::Column::Id.eq(group.audit().raw_id()))
+            .filter(groups::Column::Version.eq(group.audit().version() as i32))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if result.rows_affected == 0 {
+            Err(PersistenceError::Conflict)
+        } else {
+            Ok(())
+        }
+    }
+}
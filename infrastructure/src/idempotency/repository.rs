@@ -0,0 +1,151 @@
+use application::idempotency::{
+    dto::IdempotentResponseDTO,
+    interface::{IdempotencyRecord, IdempotencyRepository},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::{shared::error::PersistenceError, user::values::UserId};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, EntityTrait, QueryFilter,
+    sea_query::{Expr, ExprTrait, OnConflict},
+};
+use uuid::Uuid;
+
+use crate::database::{ConnectionPool, entity::idempotency_keys, log_db_error};
+
+#[derive(new)]
+pub struct IdempotencyRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl IdempotencyRepository for IdempotencyRepositoryImpl {
+    async fn try_begin(
+        &self,
+        actor_id: UserId,
+        key: &str,
+        request_hash: &str,
+        now: DateTime<Utc>,
+        locked_before: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, PersistenceError> {
+        let active_model = idempotency_keys::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            actor_id: Set(actor_id.raw()),
+            key: Set(key.to_string()),
+            request_hash: Set(request_hash.to_string()),
+            response_status: Set(None),
+            response_content_type: Set(None),
+            response_body: Set(None),
+            created_at: Set(now.into()),
+            expires_at: Set(expires_at.into()),
+        };
+
+        // An existing row is only taken over once it has expired, or once its request has
+        // held it past the lock timeout without storing a response
+        let rows_affected = idempotency_keys::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    idempotency_keys::Column::ActorId,
+                    idempotency_keys::Column::Key,
+                ])
+                .update_columns([
+                    idempotency_keys::Column::RequestHash,
+                    idempotency_keys::Column::ResponseStatus,
+                    idempotency_keys::Column::ResponseContentType,
+                    idempotency_keys::Column::ResponseBody,
+                    idempotency_keys::Column::CreatedAt,
+                    idempotency_keys::Column::ExpiresAt,
+                ])
+                .action_and_where(
+                    idempotency_keys::Column::ExpiresAt.lte(now).or(
+                        idempotency_keys::Column::ResponseStatus
+                            .is_null()
+                            .and(idempotency_keys::Column::CreatedAt.lte(locked_before)),
+                    ),
+                )
+                .to_owned(),
+            )
+            .exec_without_returning(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn find(
+        &self,
+        actor_id: UserId,
+        key: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, PersistenceError> {
+        let model = idempotency_keys::Entity::find()
+            .filter(idempotency_keys::Column::ActorId.eq(actor_id.raw()))
+            .filter(idempotency_keys::Column::Key.eq(key))
+            .filter(idempotency_keys::Column::ExpiresAt.gt(now))
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(model.map(|model| IdempotencyRecord {
+            request_hash: model.request_hash,
+            response: model.response_status.map(|status| IdempotentResponseDTO {
+                status: status as u16,
+                content_type: model.response_content_type,
+                body: model.response_body.unwrap_or_default(),
+            }),
+        }))
+    }
+
+    async fn complete(
+        &self,
+        actor_id: UserId,
+        key: &str,
+        response: &IdempotentResponseDTO,
+    ) -> Result<(), PersistenceError> {
+        idempotency_keys::Entity::update_many()
+            .col_expr(
+                idempotency_keys::Column::ResponseStatus,
+                Expr::value(response.status as i32),
+            )
+            .col_expr(
+                idempotency_keys::Column::ResponseContentType,
+                Expr::value(response.content_type.clone()),
+            )
+            .col_expr(
+                idempotency_keys::Column::ResponseBody,
+                Expr::value(response.body.clone()),
+            )
+            .filter(idempotency_keys::Column::ActorId.eq(actor_id.raw()))
+            .filter(idempotency_keys::Column::Key.eq(key))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn release(&self, actor_id: UserId, key: &str) -> Result<(), PersistenceError> {
+        idempotency_keys::Entity::delete_many()
+            .filter(idempotency_keys::Column::ActorId.eq(actor_id.raw()))
+            .filter(idempotency_keys::Column::Key.eq(key))
+            .filter(idempotency_keys::Column::ResponseStatus.is_null())
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Result<u64, PersistenceError> {
+        let result = idempotency_keys::Entity::delete_many()
+            .filter(idempotency_keys::Column::ExpiresAt.lte(now))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(result.rows_affected)
+    }
+}
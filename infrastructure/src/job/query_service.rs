@@ -0,0 +1,26 @@
+use application::job::{dto::JobDTO, interface::JobQueryService};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::shared::error::PersistenceError;
+use sea_orm::{EntityTrait, QueryOrder};
+
+use crate::database::{ConnectionPool, entity::jobs, log_db_error, row::job::JobRow};
+
+#[derive(new)]
+pub struct JobQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl JobQueryService for JobQueryServiceImpl {
+    async fn get_jobs(&self) -> Result<Vec<JobDTO>, PersistenceError> {
+        let rows = jobs::Entity::find()
+            .order_by_asc(jobs::Column::Name)
+            .into_partial_model::<JobRow>()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        rows.into_iter().map(|row| row.to_dto()).collect()
+    }
+}
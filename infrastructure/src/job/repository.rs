@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use application::job::{
+    dto::JobStatusDTO,
+    interface::{JobClaim, JobDefinition, JobRepository},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::shared::error::PersistenceError;
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, Condition, EntityTrait, QueryFilter, QuerySelect, TransactionTrait,
+    prelude::Expr,
+    sea_query::{LockBehavior, LockType},
+};
+
+use crate::database::{ConnectionPool, entity::jobs, log_db_error};
+
+#[derive(new)]
+pub struct JobRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl JobRepository for JobRepositoryImpl {
+    async fn sync(&self, definitions: &[JobDefinition]) -> Result<(), PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        let existing: HashMap<String, jobs::Model> = jobs::Entity::find()
+            .filter(jobs::Column::Name.is_in(definitions.iter().map(|d| d.name.clone())))
+            .lock(LockType::Update)
+            .all(&txn)
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .map(|job| (job.name.clone(), job))
+            .collect();
+
+        for definition in definitions {
+            match existing.get(&definition.name) {
+                Some(job) if job.schedule == definition.schedule => {}
+                Some(_) => {
+                    jobs::Entity::update_many()
+                        .col_expr(
+                            jobs::Column::Schedule,
+                            Expr::value(definition.schedule.clone()),
+                        )
+                        .col_expr(jobs::Column::NextRunAt, Expr::value(definition.next_run_at))
+                        .filter(jobs::Column::Name.eq(definition.name.as_str()))
+                        .exec(&txn)
+                        .await
+                        .map_err(log_db_error)?;
+                }
+                None => {
+                    // Another replica may be registering the same job concurrently
+                    jobs::Entity::insert(jobs::ActiveModel {
+                        name: Set(definition.name.clone()),
+                        schedule: Set(definition.schedule.clone()),
+                        next_run_at: Set(definition.next_run_at.into()),
+                        run_requested: Set(false),
+                        ..Default::default()
+                    })
+                    .on_conflict_do_nothing_on([jobs::Column::Name])
+                    .exec(&txn)
+                    .await
+                    .map_err(log_db_error)?;
+                }
+            }
+        }
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        candidates: &[JobClaim],
+    ) -> Result<Vec<String>, PersistenceError> {
+        if candidates.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        // SKIP LOCKED keeps replicas polling at the same moment from claiming the same job
+        let rows = jobs::Entity::find()
+            .filter(jobs::Column::Name.is_in(candidates.iter().map(|c| c.name.clone())))
+            .filter(
+                Condition::any()
+                    .add(jobs::Column::NextRunAt.lte(now))
+                    .add(jobs::Column::RunRequested.eq(true)),
+            )
+            .filter(
+                Condition::any()
+                    .add(jobs::Column::LockedUntil.is_null())
+                    .add(jobs::Column::LockedUntil.lte(now)),
+            )
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        let mut claimed = vec![];
+        for row in rows {
+            let Some(candidate) = candidates.iter().find(|c| c.name == row.name) else {
+                continue;
+            };
+
+            // The next run is scheduled before this one starts, so a crash never runs it twice
+            jobs::Entity::update_many()
+                .col_expr(jobs::Column::NextRunAt, Expr::value(candidate.next_run_at))
+                .col_expr(jobs::Column::RunRequested, Expr::value(false))
+                .col_expr(jobs::Column::LockedUntil, Expr::value(lease_until))
+                .col_expr(
+                    jobs::Column::LastStatus,
+                    Expr::value(JobStatusDTO::Running.as_ref()),
+                )
+                .col_expr(jobs::Column::LastStartedAt, Expr::value(now))
+                .filter(jobs::Column::Name.eq(row.name.as_str()))
+                .exec(&txn)
+                .await
+                .map_err(log_db_error)?;
+
+            claimed.push(row.name);
+        }
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        Ok(claimed)
+    }
+
+    async fn mark_finished(
+        &self,
+        name: &str,
+        finished_at: DateTime<Utc>,
+        error: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        let status = match error {
+            Some(_) => JobStatusDTO::Failed,
+            None => JobStatusDTO::Succeeded,
+        };
+
+        jobs::Entity::update_many()
+            .col_expr(
+                jobs::Column::LockedUntil,
+                Expr::value(None::<DateTime<Utc>>),
+            )
+            .col_expr(jobs::Column::LastStatus, Expr::value(status.as_ref()))
+            .col_expr(jobs::Column::LastFinishedAt, Expr::value(finished_at))
+            .col_expr(
+                jobs::Column::LastError,
+                Expr::value(error.map(str::to_string)),
+            )
+            .filter(jobs::Column::Name.eq(name))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn request_run(&self, name: &str) -> Result<(), PersistenceError> {
+        let result = jobs::Entity::update_many()
+            .col_expr(jobs::Column::RunRequested, Expr::value(true))
+            .filter(jobs::Column::Name.eq(name))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if result.rows_affected == 0 {
+            Err(PersistenceError::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -1,5 +1,14 @@
 pub mod book;
+pub mod book_import;
 pub mod config;
 pub mod database;
+pub mod event;
+pub mod group;
+pub mod idempotency;
+pub mod job;
 pub mod macros;
+pub mod notification;
+pub mod outbox;
+pub mod personal_access_token;
 pub mod user;
+pub mod webhook;
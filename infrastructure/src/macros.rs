@@ -1,14 +1,16 @@
 macro_rules! hydrate_audit {
     ($model:expr, $id_type:ty) => {
-        domain::audit::EntityAudit::<$id_type>::hydrate(
-            $model.id,
-            $model.created_at.into(),
-            $model.created_by_id,
-            $model.created_by_name.clone(),
-            $model.updated_at.map(|dt| dt.into()),
-            $model.updated_by_id,
-            $model.updated_by_name.clone(),
-        )
+        domain::audit::EntityAudit::<$id_type>::hydrate(domain::audit::PersistedAudit {
+            id: $model.id,
+            tenant: $model.tenant_id.clone(),
+            created_at: $model.created_at.into(),
+            created_by_id: $model.created_by_id,
+            created_by_name: $model.created_by_name.clone(),
+            updated_at: $model.updated_at.map(|dt| dt.into()),
+            updated_by_id: $model.updated_by_id,
+            updated_by_name: $model.updated_by_name.clone(),
+            version: $model.version as u32,
+        })
     };
 }
 
@@ -28,6 +30,7 @@ macro_rules! hydrate_audit_dto {
                     name: $model.updated_by_name.clone().unwrap_or_default(),
                 }),
             permission: $permission.into(),
+            version: $model.version as u32,
         }
     };
 }
@@ -47,26 +50,34 @@ macro_rules! audit_defaults {
         ({
             $active_model {
                 id: Set($audit.raw_id()),
+                tenant_id: Set($audit.tenant().raw().into()),
                 created_at: Set($audit.created_at().into()),
                 created_by_id: Set($audit.created_by().raw_id()),
                 created_by_name: Set($audit.created_by().name().into()),
                 updated_at: Set($audit.updated_at().map(|v| v.into())),
                 updated_by_id: Set($audit.updated_by().map(|u| u.raw_id())),
                 updated_by_name: Set($audit.updated_by().map(|u| u.name().into())),
+                version: Set($audit.version() as i32 + 1),
                 ..Default::default()
             }
         })
     };
 }
 
+// Only overwrites the row while it still has the version the entity was loaded with and
+// belongs to the same tenant; zero affected rows means someone else saved it in between.
 macro_rules! update_on_conflict {
-    ($column:ty) => {
+    ($column:ty, $audit:expr) => {
         sea_orm::sea_query::OnConflict::column(<$column>::Id)
             .update_columns(
                 <$column as sea_orm::Iterable>::iter()
                     .filter(|col| !matches!(col, <$column>::Id))
                     .collect::<Vec<_>>(),
             )
+            .action_and_where(sea_orm::sea_query::ExprTrait::and(
+                sea_orm::ColumnTrait::eq(&<$column>::Version, $audit.version() as i32),
+                sea_orm::ColumnTrait::eq(&<$column>::TenantId, $audit.tenant().raw()),
+            ))
             .to_owned()
     };
 }
@@ -0,0 +1,7 @@
+mod log_repository;
+mod notifier;
+mod query_service;
+
+pub use log_repository::NotificationLogRepositoryImpl;
+pub use notifier::SmtpNotifier;
+pub use query_service::NotificationQueryServiceImpl;
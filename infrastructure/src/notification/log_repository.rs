@@ -0,0 +1,50 @@
+use application::notification::interface::{NotificationLogRepository, NotificationRecord};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::shared::error::PersistenceError;
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, TryInsertResult};
+use uuid::Uuid;
+
+use crate::database::{ConnectionPool, entity::notification_log, log_db_error};
+
+#[derive(new)]
+pub struct NotificationLogRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl NotificationLogRepository for NotificationLogRepositoryImpl {
+    async fn try_record(&self, record: &NotificationRecord) -> Result<bool, PersistenceError> {
+        let active_model = notification_log::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(record.user_id.raw()),
+            kind: Set(record.kind.as_ref().into()),
+            reference_id: Set(record.reference_id),
+            sent_at: Set(record.sent_at.into()),
+        };
+
+        let result = notification_log::Entity::insert(active_model)
+            .on_conflict_do_nothing_on([
+                notification_log::Column::UserId,
+                notification_log::Column::Kind,
+                notification_log::Column::ReferenceId,
+            ])
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(matches!(result, TryInsertResult::Inserted(_)))
+    }
+
+    async fn remove(&self, record: &NotificationRecord) -> Result<(), PersistenceError> {
+        notification_log::Entity::delete_many()
+            .filter(notification_log::Column::UserId.eq(record.user_id.raw()))
+            .filter(notification_log::Column::Kind.eq(record.kind.as_ref()))
+            .filter(notification_log::Column::ReferenceId.eq(record.reference_id))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use application::notification::interface::{NotificationMessage, Notifier};
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, MultiPart},
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::config::NotificationConfig;
+
+pub struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpNotifier {
+    pub fn new(config: &NotificationConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        let builder = match config.smtp_tls {
+            true => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)?,
+            // Plain connection for local sinks that do not speak TLS
+            false => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.smtp_host),
+        };
+
+        let builder = match (&config.smtp_username, &config.smtp_password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+
+        Ok(SmtpNotifier {
+            transport: builder.port(config.smtp_port).build(),
+            from: config.from.parse()?,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for SmtpNotifier {
+    async fn send(&self, message: &NotificationMessage) -> Result<(), String> {
+        let to = Mailbox::new(
+            Some(message.to_name.clone()),
+            message.to_email.parse().map_err(|e| format!("{e}"))?,
+        );
+
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(&message.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                message.text_body.clone(),
+                message.html_body.clone(),
+            ))
+            .map_err(|e| e.to_string())?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use application::notification::interface::{
+    DueCheckout, NotificationQueryService, NotificationRecipient,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::{
+    book::values::BookId,
+    shared::error::PersistenceError,
+    user::{enums::NotificationKind, values::UserId},
+};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use uuid::Uuid;
+
+use crate::database::{
+    ConnectionPool,
+    entity::{book_checkouts, books, notification_log, users},
+    log_db_error,
+    row::user::NotificationRecipientRow,
+};
+
+#[derive(new)]
+pub struct NotificationQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl NotificationQueryService for NotificationQueryServiceImpl {
+    async fn find_recipient(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationRecipient>, PersistenceError> {
+        let result = users::Entity::find_by_id(user_id)
+            .into_partial_model::<NotificationRecipientRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(result.map(|row| row.to_recipient()))
+    }
+
+    async fn find_book_title(&self, book_id: BookId) -> Result<Option<String>, PersistenceError> {
+        let result = books::Entity::find_by_id(book_id)
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(result.map(|book| book.title))
+    }
+
+    async fn find_due_checkouts(
+        &self,
+        checked_out_before: DateTime<Utc>,
+    ) -> Result<Vec<DueCheckout>, PersistenceError> {
+        let overdue_notified_ids = notification_log::Entity::find()
+            .select_only()
+            .column(notification_log::Column::ReferenceId)
+            .filter(notification_log::Column::Kind.eq(NotificationKind::Overdue.as_ref()));
+
+        let rows = book_checkouts::Entity::find()
+            .find_also_related(books::Entity)
+            .filter(book_checkouts::Column::ReturnedAt.is_null())
+            .filter(book_checkouts::Column::CheckedOutAt.lte(checked_out_before))
+            .filter(
+                book_checkouts::Column::CheckoutId
+                    .not_in_subquery(overdue_notified_ids.into_query()),
+            )
+            .order_by_asc(book_checkouts::Column::CheckedOutAt)
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if rows.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let borrowers: HashMap<Uuid, NotificationRecipientRow> = users::Entity::find()
+            .filter(users::Column::Id.is_in(rows.iter().map(|(c, _)| c.checked_out_to_id)))
+            .into_partial_model::<NotificationRecipientRow>()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .map(|row| (row.id, row))
+            .collect();
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(checkout, book)| {
+                let borrower = borrowers.get(&checkout.checked_out_to_id)?;
+                Some(DueCheckout {
+                    checkout_id: checkout.checkout_id,
+                    book_title: book?.title,
+                    checked_out_at: checkout.checked_out_at.into(),
+                    borrower: borrower.clone().to_recipient(),
+                })
+            })
+            .collect())
+    }
+}
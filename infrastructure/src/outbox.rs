@@ -0,0 +1,6 @@
+mod query_service;
+mod repository;
+
+pub use query_service::OutboxQueryServiceImpl;
+pub use repository::OutboxRepositoryImpl;
+pub(crate) use repository::append_events;
@@ -0,0 +1,80 @@
+use application::outbox::{dto::*, interface::OutboxQueryService};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::shared::error::PersistenceError;
+use sea_orm::{
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+
+use crate::database::{
+    ConnectionPool, entity::outbox, log_db_error, row::outbox::OutboxMessageRow,
+};
+
+#[derive(new)]
+pub struct OutboxQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl OutboxQueryService for OutboxQueryServiceImpl {
+    async fn get_messages(
+        &self,
+        query: &OutboxListQueryDTO,
+    ) -> Result<OutboxMessageListDTO, PersistenceError> {
+        let db_query = outbox::Entity::find().apply_if(query.status, |q, status| {
+            q.filter(outbox::Column::Status.eq(status.as_ref()))
+        });
+
+        let total_count = db_query
+            .clone()
+            .select_only()
+            .count(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        let rows = db_query
+            .order_by_desc(outbox::Column::OccurredAt)
+            .into_partial_model::<OutboxMessageRow>()
+            .paginate(self.db.inner_ref(), query.page_size)
+            .fetch_page(query.page - 1)
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(OutboxMessageListDTO {
+            page_size: query.page_size,
+            page: query.page,
+            total_count,
+            items: rows
+                .into_iter()
+                .map(|row| row.to_dto())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    async fn get_stats(&self) -> Result<OutboxStatsDTO, PersistenceError> {
+        let oldest_pending = outbox::Entity::find()
+            .filter(outbox::Column::Status.eq(OutboxStatusDTO::Pending.as_ref()))
+            .order_by_asc(outbox::Column::OccurredAt)
+            .into_partial_model::<OutboxMessageRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(OutboxStatsDTO {
+            pending: self.count_by_status(OutboxStatusDTO::Pending).await?,
+            delivered: self.count_by_status(OutboxStatusDTO::Delivered).await?,
+            dead: self.count_by_status(OutboxStatusDTO::Dead).await?,
+            oldest_pending_at: oldest_pending.map(|row| row.occurred_at.into()),
+        })
+    }
+}
+
+impl OutboxQueryServiceImpl {
+    async fn count_by_status(&self, status: OutboxStatusDTO) -> Result<u64, PersistenceError> {
+        outbox::Entity::find()
+            .filter(outbox::Column::Status.eq(status.as_ref()))
+            .count(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)
+    }
+}
@@ -0,0 +1,204 @@
+use application::outbox::{
+    dto::OutboxStatusDTO,
+    interface::{OutboxMessage, OutboxRepository},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::{event::DomainEvent, shared::error::PersistenceError};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+    prelude::Expr,
+    sea_query::{LockBehavior, LockType},
+};
+use uuid::Uuid;
+
+use crate::{
+    database::{ConnectionPool, entity::outbox, log_db_error},
+    event::notify_events,
+};
+
+#[derive(new)]
+pub struct OutboxRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxRepositoryImpl {
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<OutboxMessage>, PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        // SKIP LOCKED lets several api replicas relay concurrently without double delivery
+        let rows = outbox::Entity::find()
+            .filter(outbox::Column::Status.eq(OutboxStatusDTO::Pending.as_ref()))
+            .filter(outbox::Column::NextAttemptAt.lte(now))
+            .order_by_asc(outbox::Column::OccurredAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        if rows.is_empty() {
+            txn.commit().await.map_err(log_db_error)?;
+            return Ok(vec![]);
+        }
+
+        outbox::Entity::update_many()
+            .col_expr(outbox::Column::NextAttemptAt, Expr::value(lease_until))
+            .filter(outbox::Column::Id.is_in(rows.iter().map(|r| r.id)))
+            .exec(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        let mut messages = vec![];
+        for row in rows {
+            match serde_json::from_value::<DomainEvent>(row.payload) {
+                Ok(event) => messages.push(OutboxMessage {
+                    id: row.id,
+                    attempts: row.attempts as u32,
+                    event,
+                }),
+                Err(err) => {
+                    // An unreadable payload will never succeed, so dead-letter it right away
+                    tracing::error!(error = ?err, message_id = %row.id, "Malformed outbox payload");
+                    outbox::Entity::update_many()
+                        .col_expr(
+                            outbox::Column::Status,
+                            Expr::value(OutboxStatusDTO::Dead.as_ref()),
+                        )
+                        .col_expr(outbox::Column::LastError, Expr::value(err.to_string()))
+                        .filter(outbox::Column::Id.eq(row.id))
+                        .exec(&txn)
+                        .await
+                        .map_err(log_db_error)?;
+                }
+            }
+        }
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        Ok(messages)
+    }
+
+    async fn mark_delivered(
+        &self,
+        id: Uuid,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        outbox::Entity::update_many()
+            .col_expr(
+                outbox::Column::Status,
+                Expr::value(OutboxStatusDTO::Delivered.as_ref()),
+            )
+            .col_expr(outbox::Column::DeliveredAt, Expr::value(delivered_at))
+            .col_expr(outbox::Column::LastError, Expr::value(None::<String>))
+            .filter(outbox::Column::Id.eq(id))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        attempts: u32,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError> {
+        let update = outbox::Entity::update_many()
+            .col_expr(outbox::Column::Attempts, Expr::value(attempts as i32))
+            .col_expr(outbox::Column::LastError, Expr::value(error))
+            .filter(outbox::Column::Id.eq(id));
+
+        let update = match next_attempt_at {
+            Some(at) => update.col_expr(outbox::Column::NextAttemptAt, Expr::value(at)),
+            None => update.col_expr(
+                outbox::Column::Status,
+                Expr::value(OutboxStatusDTO::Dead.as_ref()),
+            ),
+        };
+
+        update
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn requeue(&self, id: Uuid, now: DateTime<Utc>) -> Result<(), PersistenceError> {
+        let result = outbox::Entity::update_many()
+            .col_expr(
+                outbox::Column::Status,
+                Expr::value(OutboxStatusDTO::Pending.as_ref()),
+            )
+            .col_expr(outbox::Column::Attempts, Expr::value(0))
+            .col_expr(outbox::Column::NextAttemptAt, Expr::value(now))
+            .filter(outbox::Column::Id.eq(id))
+            .filter(outbox::Column::Status.ne(OutboxStatusDTO::Pending.as_ref()))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if result.rows_affected == 0 {
+            Err(PersistenceError::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn purge_delivered(&self, before: DateTime<Utc>) -> Result<u64, PersistenceError> {
+        let result = outbox::Entity::delete_many()
+            .filter(outbox::Column::Status.eq(OutboxStatusDTO::Delivered.as_ref()))
+            .filter(outbox::Column::DeliveredAt.lt(before))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+/// Writes the aggregate's events to the outbox as part of the caller's transaction.
+pub(crate) async fn append_events<C: ConnectionTrait>(
+    conn: &C,
+    events: &[DomainEvent],
+) -> Result<(), PersistenceError> {
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    let mut active_models = vec![];
+    for event in events {
+        let payload = serde_json::to_value(event)
+            .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?;
+
+        active_models.push(outbox::ActiveModel {
+            id: Set(event.id()),
+            event_type: Set(event.event_type().into()),
+            payload: Set(payload),
+            occurred_at: Set(event.occurred_at().into()),
+            status: Set(OutboxStatusDTO::Pending.as_ref().into()),
+            attempts: Set(0),
+            next_attempt_at: Set(event.occurred_at().into()),
+            ..Default::default()
+        });
+    }
+
+    outbox::Entity::insert_many(active_models)
+        .exec(conn)
+        .await
+        .map_err(log_db_error)?;
+
+    notify_events(conn, events).await
+}
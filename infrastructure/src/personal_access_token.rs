@@ -0,0 +1,5 @@
+mod query_service;
+mod repository;
+
+pub use query_service::PersonalAccessTokenQueryServiceImpl;
+pub use repository::PersonalAccessTokenRepositoryImpl;
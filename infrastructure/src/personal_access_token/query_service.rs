@@ -0,0 +1,66 @@
+use application::personal_access_token::{dto::*, interface::PersonalAccessTokenQueryService};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{shared::error::PersistenceError, tenant::values::TenantId, user::values::UserId};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+use crate::database::{
+    ConnectionPool,
+    entity::{personal_access_tokens, users},
+    log_db_error,
+    row::{
+        personal_access_token::{PersonalAccessTokenCredentialRow, PersonalAccessTokenRow},
+        user::UserDetailsDTORow,
+    },
+};
+
+#[derive(new)]
+pub struct PersonalAccessTokenQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl PersonalAccessTokenQueryService for PersonalAccessTokenQueryServiceImpl {
+    async fn get_tokens(
+        &self,
+        tenant: &TenantId,
+        user_id: UserId,
+    ) -> Result<Vec<PersonalAccessTokenDTO>, PersistenceError> {
+        let rows = personal_access_tokens::Entity::find()
+            .filter(personal_access_tokens::Column::UserId.eq(user_id.raw()))
+            .filter(personal_access_tokens::Column::TenantId.eq(tenant.raw()))
+            .order_by_desc(personal_access_tokens::Column::CreatedAt)
+            .into_partial_model::<PersonalAccessTokenRow>()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        rows.into_iter().map(|row| row.to_dto()).collect()
+    }
+
+    async fn find_credential(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalAccessTokenCredentialDTO>, PersistenceError> {
+        let Some(token) = personal_access_tokens::Entity::find()
+            .filter(personal_access_tokens::Column::TokenHash.eq(token_hash))
+            .into_partial_model::<PersonalAccessTokenCredentialRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+        else {
+            return Ok(None);
+        };
+
+        let Some(user) = users::Entity::find_by_id(token.user_id)
+            .into_partial_model::<UserDetailsDTORow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?
+        else {
+            return Ok(None);
+        };
+
+        token.to_dto(user.to_dto()?).map(Some)
+    }
+}
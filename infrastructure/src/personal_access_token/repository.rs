@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::{
+    personal_access_token::{
+        entity::PersonalAccessToken, enums::TokenScope, interface::PersonalAccessTokenRepository,
+        values::PersonalAccessTokenId,
+    },
+    shared::error::PersistenceError,
+    user::values::UserReference,
+};
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, prelude::Expr};
+
+use crate::{
+    database::{ConnectionPool, entity::personal_access_tokens, log_db_error},
+    macros::{audit_defaults, hydrate_audit, update_on_conflict},
+};
+
+#[derive(new)]
+pub struct PersonalAccessTokenRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl PersonalAccessTokenRepository for PersonalAccessTokenRepositoryImpl {
+    async fn find_by_id(
+        &self,
+        id: PersonalAccessTokenId,
+    ) -> Result<Option<PersonalAccessToken>, PersistenceError> {
+        let result = personal_access_tokens::Entity::find_by_id(id)
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        match result {
+            Some(token) => {
+                let audit = hydrate_audit!(token, PersonalAccessTokenId);
+                // Tokens are only ever issued by their owner, so the creator's name is the owner's.
+                let owner = UserReference::hydrate(token.user_id, token.created_by_name.clone());
+                let scopes = serde_json::from_value::<Vec<String>>(token.scopes)
+                    .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?
+                    .iter()
+                    .map(|scope| TokenScope::from_str(scope))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?;
+
+                Ok(Some(PersonalAccessToken::hydrate(
+                    audit,
+                    owner,
+                    token.name,
+                    scopes,
+                    token.token_hash,
+                    token.expires_at.into(),
+                    token.last_used_at.map(|dt| dt.into()),
+                )))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, token: &mut PersonalAccessToken) -> Result<(), PersistenceError> {
+        let scopes = token
+            .scopes()
+            .iter()
+            .map(|scope| scope.as_ref())
+            .collect::<Vec<_>>();
+
+        let active_model = personal_access_tokens::ActiveModel {
+            user_id: Set(token.owner().raw_id()),
+            name: Set(token.name().into()),
+            token_hash: Set(token.token_hash().into()),
+            scopes: Set(serde_json::json!(scopes)),
+            expires_at: Set(token.expires_at().into()),
+            last_used_at: Set(token.last_used_at().map(|dt| dt.into())),
+            ..audit_defaults!(personal_access_tokens::ActiveModel, token.audit())
+        };
+
+        let rows_affected = personal_access_tokens::Entity::insert(active_model)
+            .on_conflict(update_on_conflict!(
+                personal_access_tokens::Column,
+                token.audit()
+            ))
+            .exec_without_returning(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if rows_affected == 0 {
+            return Err(PersistenceError::Conflict);
+        }
+
+        token.mark_saved();
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: PersonalAccessTokenId) -> Result<(), PersistenceError> {
+        let result = personal_access_tokens::Entity::delete_by_id(id)
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if result.rows_affected == 0 {
+            Err(PersistenceError::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn record_usage(
+        &self,
+        id: PersonalAccessTokenId,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        personal_access_tokens::Entity::update_many()
+            .col_expr(
+                personal_access_tokens::Column::LastUsedAt,
+                Expr::value(used_at),
+            )
+            .filter(personal_access_tokens::Column::Id.eq(id.raw()))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 mod domain_query_service;
 mod query_service;
 mod repository;
+mod role_permission_repository;
 
 pub use domain_query_service::UserDomainQueryServiceImpl;
 pub use query_service::UserQueryServiceImpl;
 pub use repository::UserRepositoryImpl;
+pub use role_permission_repository::RolePermissionRepositoryImpl;
@@ -2,12 +2,23 @@ use async_trait::async_trait;
 use derive_new::new;
 use domain::{
     audit::Actor,
+    book::enums::BookVisibility,
+    group::values::GroupId,
     shared::error::PersistenceError,
     user::{interface::UserDomainQueryService, values::UserId},
 };
-use sea_orm::EntityTrait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
 
-use crate::database::{ConnectionPool, entity::users, log_db_error, row::user::ActorRow};
+use crate::{
+    database::{
+        ConnectionPool,
+        entity::{group_members, users},
+        log_db_error,
+        row::user::{ActorRow, BookSettingsRow},
+    },
+    user::role_permission_repository::load_role_capabilities,
+};
 
 #[derive(new)]
 pub struct UserDomainQueryServiceImpl {
@@ -24,8 +35,39 @@ impl UserDomainQueryService for UserDomainQueryServiceImpl {
             .map_err(log_db_error)?;
 
         match result {
-            Some(actor_row) => Ok(Some(actor_row.to_actor()?)),
+            Some(actor_row) => {
+                let role = actor_row.role()?;
+                let capabilities = load_role_capabilities(self.db.inner_ref(), role).await?;
+                let groups: Vec<Uuid> = group_members::Entity::find()
+                    .select_only()
+                    .column(group_members::Column::GroupId)
+                    .filter(group_members::Column::UserId.eq(actor_row.id))
+                    .into_tuple()
+                    .all(self.db.inner_ref())
+                    .await
+                    .map_err(log_db_error)?;
+                Ok(Some(actor_row.to_actor(
+                    role,
+                    capabilities,
+                    groups.into_iter().map(GroupId::from).collect(),
+                )))
+            }
             None => Ok(None),
         }
     }
+
+    async fn find_default_book_visibility(
+        &self,
+        id: UserId,
+    ) -> Result<Option<BookVisibility>, PersistenceError> {
+        let result = users::Entity::find_by_id(id)
+            .into_partial_model::<BookSettingsRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        result
+            .map(|settings| settings.default_visibility())
+            .transpose()
+    }
 }
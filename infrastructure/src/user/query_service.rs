@@ -1,10 +1,18 @@
-use application::user::{dto::UserDetailsDTO, interface::UserQueryService};
+use application::user::{
+    dto::{BookSettingsDTO, NotificationSettingsDTO, UserDetailsDTO},
+    interface::UserQueryService,
+};
 use async_trait::async_trait;
 use derive_new::new;
 use domain::{shared::error::PersistenceError, user::values::UserId};
 use sea_orm::EntityTrait;
 
-use crate::database::{ConnectionPool, entity::users, log_db_error, row::user::UserDetailsDTORow};
+use crate::database::{
+    ConnectionPool,
+    entity::users,
+    log_db_error,
+    row::user::{BookSettingsRow, NotificationSettingsRow, UserDetailsDTORow},
+};
 
 #[derive(new)]
 pub struct UserQueryServiceImpl {
@@ -28,4 +36,30 @@ impl UserQueryService for UserQueryServiceImpl {
             None => Ok(None),
         }
     }
+
+    async fn get_notification_settings(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationSettingsDTO>, PersistenceError> {
+        let result = users::Entity::find_by_id(user_id)
+            .into_partial_model::<NotificationSettingsRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(result.map(|settings| settings.to_dto()))
+    }
+
+    async fn get_book_settings(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<BookSettingsDTO>, PersistenceError> {
+        let result = users::Entity::find_by_id(user_id)
+            .into_partial_model::<BookSettingsRow>()
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        result.map(|settings| settings.to_dto()).transpose()
+    }
 }
@@ -3,14 +3,16 @@ use std::str::FromStr;
 use async_trait::async_trait;
 use derive_new::new;
 use domain::{
+    book::enums::BookVisibility,
     shared::error::PersistenceError,
     user::{entity::User, enums::UserRole, interface::UserRepository, values::*},
 };
-use sea_orm::{ActiveValue::Set, EntityTrait};
+use sea_orm::{ActiveValue::Set, EntityTrait, TransactionTrait};
 
 use crate::{
     database::{ConnectionPool, entity::users, log_db_error},
     macros::{audit_defaults, hydrate_audit, update_on_conflict},
+    outbox::append_events,
 };
 
 #[derive(new)]
@@ -35,26 +37,52 @@ impl UserRepository for UserRepositoryImpl {
                     user.email,
                     UserRole::from_str(&user.role)
                         .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+                    NotificationPreferences::new(
+                        user.notify_due_soon,
+                        user.notify_overdue,
+                        user.notify_book_checked_out,
+                        user.notify_book_returned,
+                    ),
+                    BookVisibility::from_str(&user.default_book_visibility)
+                        .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
                 )))
             }
             None => Ok(None),
         }
     }
 
-    async fn save(&self, user: &User) -> Result<(), PersistenceError> {
+    async fn save(&self, user: &mut User) -> Result<(), PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        let preferences = user.notification_preferences();
         let active_model = users::ActiveModel {
             name: Set(user.name().into()),
             email: Set(user.email().into()),
             role: Set(user.role().as_ref().into()),
+            notify_due_soon: Set(preferences.due_soon()),
+            notify_overdue: Set(preferences.overdue()),
+            notify_book_checked_out: Set(preferences.book_checked_out()),
+            notify_book_returned: Set(preferences.book_returned()),
+            default_book_visibility: Set(user.default_book_visibility().as_ref().into()),
             ..audit_defaults!(users::ActiveModel, user.audit())
         };
 
-        users::Entity::insert(active_model)
-            .on_conflict(update_on_conflict!(users::Column))
-            .exec(self.db.inner_ref())
+        let rows_affected = users::Entity::insert(active_model)
+            .on_conflict(update_on_conflict!(users::Column, user.audit()))
+            .exec_without_returning(&txn)
             .await
             .map_err(log_db_error)?;
 
+        if rows_affected == 0 {
+            return Err(PersistenceError::Conflict);
+        }
+
+        append_events(&txn, user.events()).await?;
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        user.mark_saved();
+
         Ok(())
     }
 
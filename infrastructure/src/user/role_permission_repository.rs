@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    auth::{
+        capability::{Capability, CapabilitySet},
+        interface::RolePermissionRepository,
+    },
+    shared::error::PersistenceError,
+    user::enums::UserRole,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect,
+    TransactionTrait,
+};
+
+use crate::database::{ConnectionPool, entity::role_permissions, log_db_error};
+
+#[derive(new)]
+pub struct RolePermissionRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl RolePermissionRepository for RolePermissionRepositoryImpl {
+    async fn find_by_role(&self, role: UserRole) -> Result<CapabilitySet, PersistenceError> {
+        load_role_capabilities(self.db.inner_ref(), role).await
+    }
+
+    async fn save(
+        &self,
+        role: UserRole,
+        capabilities: &CapabilitySet,
+    ) -> Result<(), PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        role_permissions::Entity::delete_many()
+            .filter(role_permissions::Column::Role.eq(role.as_ref()))
+            .exec(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        let rows = capabilities
+            .iter()
+            .map(|capability| role_permissions::ActiveModel {
+                role: Set(role.as_ref().into()),
+                permission: Set(capability.as_ref().into()),
+            })
+            .collect::<Vec<_>>();
+        if !rows.is_empty() {
+            role_permissions::Entity::insert_many(rows)
+                .exec_without_returning(&txn)
+                .await
+                .map_err(log_db_error)?;
+        }
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        Ok(())
+    }
+}
+
+/// Permissions that no longer name a capability are skipped rather than failing the lookup.
+pub(super) async fn load_role_capabilities<C: ConnectionTrait>(
+    db: &C,
+    role: UserRole,
+) -> Result<CapabilitySet, PersistenceError> {
+    let permissions: Vec<String> = role_permissions::Entity::find()
+        .select_only()
+        .column(role_permissions::Column::Permission)
+        .filter(role_permissions::Column::Role.eq(role.as_ref()))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(log_db_error)?;
+
+    Ok(permissions
+        .iter()
+        .filter_map(|permission| Capability::from_str(permission).ok())
+        .collect())
+}
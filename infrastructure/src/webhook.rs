@@ -0,0 +1,9 @@
+mod delivery_repository;
+mod query_service;
+mod repository;
+mod sender;
+
+pub use delivery_repository::WebhookDeliveryRepositoryImpl;
+pub use query_service::WebhookQueryServiceImpl;
+pub use repository::WebhookRepositoryImpl;
+pub use sender::HttpWebhookSender;
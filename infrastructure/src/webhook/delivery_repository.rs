@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use application::webhook::{
+    dto::WebhookDeliveryStatusDTO,
+    interface::{NewWebhookDelivery, WebhookDelivery, WebhookDeliveryRepository},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::shared::error::PersistenceError;
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+    prelude::Expr,
+    sea_query::{ExprTrait, LockBehavior, LockType},
+};
+use uuid::Uuid;
+
+use crate::database::{
+    ConnectionPool,
+    entity::{webhook_deliveries, webhooks},
+    log_db_error,
+};
+
+#[derive(new)]
+pub struct WebhookDeliveryRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for WebhookDeliveryRepositoryImpl {
+    async fn enqueue(&self, deliveries: &[NewWebhookDelivery]) -> Result<(), PersistenceError> {
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let active_models = deliveries
+            .iter()
+            .map(|delivery| webhook_deliveries::ActiveModel {
+                id: Set(delivery.id),
+                webhook_id: Set(delivery.webhook_id.raw()),
+                event_id: Set(delivery.event_id),
+                event_type: Set(delivery.event_type.clone()),
+                payload: Set(delivery.payload.clone()),
+                status: Set(WebhookDeliveryStatusDTO::Pending.as_ref().into()),
+                attempts: Set(0),
+                created_at: Set(delivery.created_at.into()),
+                next_attempt_at: Set(delivery.created_at.into()),
+                ..Default::default()
+            });
+
+        webhook_deliveries::Entity::insert_many(active_models)
+            .on_conflict_do_nothing_on([
+                webhook_deliveries::Column::WebhookId,
+                webhook_deliveries::Column::EventId,
+            ])
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError> {
+        let txn = self.db.inner_ref().begin().await.map_err(log_db_error)?;
+
+        let rows = webhook_deliveries::Entity::find()
+            .filter(
+                webhook_deliveries::Column::Status.eq(WebhookDeliveryStatusDTO::Pending.as_ref()),
+            )
+            .filter(webhook_deliveries::Column::NextAttemptAt.lte(now))
+            .order_by_asc(webhook_deliveries::Column::CreatedAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        if rows.is_empty() {
+            txn.commit().await.map_err(log_db_error)?;
+            return Ok(vec![]);
+        }
+
+        webhook_deliveries::Entity::update_many()
+            .col_expr(
+                webhook_deliveries::Column::NextAttemptAt,
+                Expr::value(lease_until),
+            )
+            .filter(webhook_deliveries::Column::Id.is_in(rows.iter().map(|r| r.id)))
+            .exec(&txn)
+            .await
+            .map_err(log_db_error)?;
+
+        // The target is read at send time so that url and secret changes apply to retries
+        let targets: HashMap<Uuid, webhooks::Model> = webhooks::Entity::find()
+            .filter(webhooks::Column::Id.is_in(rows.iter().map(|r| r.webhook_id)))
+            .all(&txn)
+            .await
+            .map_err(log_db_error)?
+            .into_iter()
+            .map(|webhook| (webhook.id, webhook))
+            .collect();
+
+        txn.commit().await.map_err(log_db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let target = targets.get(&row.webhook_id)?;
+                Some(WebhookDelivery {
+                    id: row.id,
+                    url: target.url.clone(),
+                    secret: target.secret.clone(),
+                    event_type: row.event_type,
+                    payload: row.payload,
+                    attempts: row.attempts as u32,
+                })
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(
+        &self,
+        id: Uuid,
+        response_status: u16,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        webhook_deliveries::Entity::update_many()
+            .col_expr(
+                webhook_deliveries::Column::Status,
+                Expr::value(WebhookDeliveryStatusDTO::Delivered.as_ref()),
+            )
+            .col_expr(
+                webhook_deliveries::Column::Attempts,
+                Expr::col(webhook_deliveries::Column::Attempts).add(1),
+            )
+            .col_expr(
+                webhook_deliveries::Column::ResponseStatus,
+                Expr::value(response_status as i32),
+            )
+            .col_expr(
+                webhook_deliveries::Column::DeliveredAt,
+                Expr::value(delivered_at),
+            )
+            .col_expr(
+                webhook_deliveries::Column::LastError,
+                Expr::value(None::<String>),
+            )
+            .filter(webhook_deliveries::Column::Id.eq(id))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        attempts: u32,
+        response_status: Option<u16>,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError> {
+        let update = webhook_deliveries::Entity::update_many()
+            .col_expr(
+                webhook_deliveries::Column::Attempts,
+                Expr::value(attempts as i32),
+            )
+            .col_expr(
+                webhook_deliveries::Column::ResponseStatus,
+                Expr::value(response_status.map(|s| s as i32)),
+            )
+            .col_expr(webhook_deliveries::Column::LastError, Expr::value(error))
+            .filter(webhook_deliveries::Column::Id.eq(id));
+
+        let update = match next_attempt_at {
+            Some(at) => update.col_expr(webhook_deliveries::Column::NextAttemptAt, Expr::value(at)),
+            None => update.col_expr(
+                webhook_deliveries::Column::Status,
+                Expr::value(WebhookDeliveryStatusDTO::Failed.as_ref()),
+            ),
+        };
+
+        update
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(())
+    }
+
+    async fn purge_finished(&self, before: DateTime<Utc>) -> Result<u64, PersistenceError> {
+        let result = webhook_deliveries::Entity::delete_many()
+            .filter(webhook_deliveries::Column::Status.is_in([
+                WebhookDeliveryStatusDTO::Delivered.as_ref(),
+                WebhookDeliveryStatusDTO::Failed.as_ref(),
+            ]))
+            .filter(webhook_deliveries::Column::CreatedAt.lt(before))
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,102 @@
+use application::webhook::{dto::*, interface::WebhookQueryService};
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    audit::Actor, auth::permission::AdminPermission, shared::error::PersistenceError,
+    tenant::values::TenantId, webhook::values::WebhookId,
+};
+use sea_orm::{
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    prelude::Expr,
+    sea_query::{BinOper, ExprTrait, extension::postgres::PgBinOper},
+};
+
+use crate::database::{
+    ConnectionPool,
+    entity::{webhook_deliveries, webhooks},
+    log_db_error,
+    row::webhook::{WebhookDeliveryRow, WebhookRow},
+};
+
+#[derive(new)]
+pub struct WebhookQueryServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl WebhookQueryService for WebhookQueryServiceImpl {
+    async fn get_webhooks(&self, actor: &Actor) -> Result<Vec<WebhookDTO>, PersistenceError> {
+        let rows = webhooks::Entity::find()
+            .filter(webhooks::Column::TenantId.eq(actor.tenant().raw()))
+            .order_by_asc(webhooks::Column::CreatedAt)
+            .into_partial_model::<WebhookRow>()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        rows.into_iter()
+            .map(|row| row.to_dto(AdminPermission::new(actor)))
+            .collect()
+    }
+
+    async fn find_subscribed(
+        &self,
+        tenant: &TenantId,
+        event_type: &str,
+    ) -> Result<Vec<WebhookId>, PersistenceError> {
+        let ids: Vec<uuid::Uuid> = webhooks::Entity::find()
+            .select_only()
+            .column(webhooks::Column::Id)
+            .filter(webhooks::Column::TenantId.eq(tenant.raw()))
+            .filter(Expr::col(webhooks::Column::EventTypes).binary(
+                BinOper::PgOperator(PgBinOper::Contains),
+                Expr::val(serde_json::json!([event_type])),
+            ))
+            .into_tuple()
+            .all(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(ids.into_iter().map(WebhookId::from).collect())
+    }
+
+    async fn get_deliveries(
+        &self,
+        tenant: &TenantId,
+        identity: WebhookIdentity,
+        query: &WebhookDeliveryQueryDTO,
+    ) -> Result<WebhookDeliveryListDTO, PersistenceError> {
+        let db_query = webhook_deliveries::Entity::find()
+            .inner_join(webhooks::Entity)
+            .filter(webhook_deliveries::Column::WebhookId.eq(identity.webhook_id.raw()))
+            .filter(webhooks::Column::TenantId.eq(tenant.raw()))
+            .apply_if(query.status, |q, status| {
+                q.filter(webhook_deliveries::Column::Status.eq(status.as_ref()))
+            });
+
+        let total_count = db_query
+            .clone()
+            .select_only()
+            .count(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        let rows = db_query
+            .order_by_desc(webhook_deliveries::Column::CreatedAt)
+            .into_partial_model::<WebhookDeliveryRow>()
+            .paginate(self.db.inner_ref(), query.page_size)
+            .fetch_page(query.page - 1)
+            .await
+            .map_err(log_db_error)?;
+
+        Ok(WebhookDeliveryListDTO {
+            page_size: query.page_size,
+            page: query.page,
+            total_count,
+            items: rows
+                .into_iter()
+                .map(|row| row.to_dto())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
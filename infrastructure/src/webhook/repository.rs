@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    shared::error::PersistenceError,
+    webhook::{entity::Webhook, interface::WebhookRepository, values::WebhookId},
+};
+use sea_orm::{ActiveValue::Set, EntityTrait};
+
+use crate::{
+    database::{ConnectionPool, entity::webhooks, log_db_error},
+    macros::{audit_defaults, hydrate_audit, update_on_conflict},
+};
+
+#[derive(new)]
+pub struct WebhookRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl WebhookRepository for WebhookRepositoryImpl {
+    async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, PersistenceError> {
+        let result = webhooks::Entity::find_by_id(id)
+            .one(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        match result {
+            Some(webhook) => {
+                let audit = hydrate_audit!(webhook, WebhookId);
+                Ok(Some(Webhook::hydrate(
+                    audit,
+                    webhook.url,
+                    serde_json::from_value(webhook.event_types)
+                        .map_err(|e| PersistenceError::EntityConversionError(e.to_string()))?,
+                    webhook.secret,
+                )))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, webhook: &mut Webhook) -> Result<(), PersistenceError> {
+        let active_model = webhooks::ActiveModel {
+            url: Set(webhook.url().into()),
+            event_types: Set(serde_json::json!(webhook.event_types())),
+            secret: Set(webhook.secret().into()),
+            ..audit_defaults!(webhooks::ActiveModel, webhook.audit())
+        };
+
+        let rows_affected = webhooks::Entity::insert(active_model)
+            .on_conflict(update_on_conflict!(webhooks::Column, webhook.audit()))
+            .exec_without_returning(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if rows_affected == 0 {
+            return Err(PersistenceError::Conflict);
+        }
+
+        webhook.mark_saved();
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: WebhookId) -> Result<(), PersistenceError> {
+        let result = webhooks::Entity::delete_by_id(id)
+            .exec(self.db.inner_ref())
+            .await
+            .map_err(log_db_error)?;
+
+        if result.rows_affected == 0 {
+            Err(PersistenceError::NotFound)
+        } else {
+            Ok(())
+        }
+    }
+}
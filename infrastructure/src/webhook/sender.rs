@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use application::webhook::interface::{WebhookDelivery, WebhookResponse, WebhookSender};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{Client, header::CONTENT_TYPE};
+use sha2::Sha256;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct HttpWebhookSender {
+    client: Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Result<Self, reqwest::Error> {
+        let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(HttpWebhookSender { client })
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(
+        &self,
+        delivery: &WebhookDelivery,
+        timestamp: DateTime<Utc>,
+    ) -> Result<WebhookResponse, String> {
+        let timestamp = timestamp.timestamp().to_string();
+        let signature = sign(&delivery.secret, &timestamp, &delivery.payload)?;
+
+        let response = self
+            .client
+            .post(&delivery.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header("X-Webhook-Id", delivery.id.to_string())
+            .header("X-Webhook-Event", &delivery.event_type)
+            .header("X-Webhook-Timestamp", &timestamp)
+            .header("X-Webhook-Signature", format!("sha256={signature}"))
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(WebhookResponse {
+            status: response.status().as_u16(),
+        })
+    }
+}
+
+/// Signs `{timestamp}.{body}` so receivers can reject replayed requests.
+fn sign(secret: &str, timestamp: &str, payload: &str) -> Result<String, String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|e| e.to_string())?;
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
@@ -0,0 +1,249 @@
+mod common;
+
+use std::{collections::HashMap, sync::Arc};
+
+use application::{
+    book::{
+        command::CheckoutBookService,
+        dto::{BookIdentity, CheckoutBookRequestDTO},
+    },
+    shared::error::ApplicationError,
+};
+use domain::{
+    audit::{Actor, AuditContext, clock::SystemClock},
+    book::{interface::BookRepository, values::BookId},
+    shared::error::DomainError,
+    tenant::values::TenantId,
+    user::{enums::UserRole, values::UserId},
+};
+use infrastructure::{
+    book::BookRepositoryImpl,
+    database::{ConnectionPool, entity::book_checkouts},
+    group::GroupRepositoryImpl,
+    user::UserDomainQueryServiceImpl,
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, PaginatorTrait, QueryFilter, Statement,
+};
+use uuid::Uuid;
+
+fn checkout_service(db: &ConnectionPool) -> CheckoutBookService {
+    CheckoutBookService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+        Arc::new(UserDomainQueryServiceImpl::new(db.clone())),
+        Arc::new(GroupRepositoryImpl::new(db.clone())),
+    )
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn only_one_of_two_concurrent_checkouts_succeeds() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let owner = common::create_user(&db, "owner").await;
+    let alice = common::create_user(&db, "alice").await;
+    let bob = common::create_user(&db, "bob").await;
+    let book_id = common::create_book(&db, &owner, "Shared copy").await;
+
+    let service = Arc::new(checkout_service(&db));
+    let identity = BookIdentity { book_id };
+
+    let (first, second) = tokio::join!(
+        tokio::spawn({
+            let service = service.clone();
+            async move {
+                service
+                    .execute(&alice, identity, &CheckoutBookRequestDTO::default(), None)
+                    .await
+            }
+        }),
+        tokio::spawn({
+            let service = service.clone();
+            async move {
+                service
+                    .execute(&bob, identity, &CheckoutBookRequestDTO::default(), None)
+                    .await
+            }
+        }),
+    );
+    let results = [first.unwrap(), second.unwrap()];
+
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(results.iter().any(|r| matches!(
+        r,
+        Err(ApplicationError::DomainError(
+            DomainError::AlreadyCheckedOut
+        ))
+    )));
+
+    let active_checkouts = book_checkouts::Entity::find()
+        .filter(book_checkouts::Column::BookId.eq(book_id.raw()))
+        .filter(book_checkouts::Column::ReturnedAt.is_null())
+        .count(db.inner_ref())
+        .await
+        .unwrap();
+    assert_eq!(active_checkouts, 1);
+}
+
+#[tokio::test]
+async fn only_members_can_check_out_from_a_private_library() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let owner = common::create_user(&db, "owner").await;
+    let outsider = common::create_user(&db, "outsider").await;
+    let group_id = common::create_group(&db, &owner, "Research team", true).await;
+    let owner = common::find_actor(&db, owner.id()).await;
+    let book_id = common::create_book(&db, &owner, "Team copy").await;
+    common::move_book_to_group(&db, &owner, book_id, group_id).await;
+
+    let service = checkout_service(&db);
+    let identity = BookIdentity { book_id };
+    let request = CheckoutBookRequestDTO::default();
+
+    assert!(matches!(
+        service.execute(&outsider, identity, &request, None).await,
+        Err(ApplicationError::NotFound)
+    ));
+    service
+        .execute(&owner, identity, &request, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn borrowers_are_only_looked_up_for_those_who_may_lend_to_them() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let owner = common::create_user(&db, "owner").await;
+    let reader = common::create_user(&db, "reader").await;
+    let librarian =
+        common::create_user_in(&db, &TenantId::default(), "librarian", UserRole::Librarian).await;
+    let book_id = common::create_book(&db, &owner, "Shared copy").await;
+
+    let service = checkout_service(&db);
+    let identity = BookIdentity { book_id };
+    let lend_to = |borrower_id: UserId| CheckoutBookRequestDTO {
+        borrower_id: Some(borrower_id),
+    };
+    let unknown = UserId::from(Uuid::new_v4());
+
+    // Existing and unknown borrowers are refused alike
+    for borrower_id in [owner.id(), unknown] {
+        assert!(matches!(
+            service
+                .execute(&reader, identity, &lend_to(borrower_id), None)
+                .await,
+            Err(ApplicationError::DomainError(DomainError::Forbidden))
+        ));
+    }
+
+    assert!(matches!(
+        service
+            .execute(&librarian, identity, &lend_to(unknown), None)
+            .await,
+        Err(ApplicationError::DomainError(DomainError::ValidationError(
+            _
+        )))
+    ));
+    service
+        .execute(&librarian, identity, &lend_to(reader.id()), None)
+        .await
+        .unwrap();
+}
+
+/// Returns the transaction id that last wrote each row of `table` for the book;
+/// it only changes when a row is inserted or updated.
+async fn row_versions(
+    db: &ConnectionPool,
+    table: &str,
+    key: &str,
+    book_id: BookId,
+) -> HashMap<String, String> {
+    let rows = db
+        .inner_ref()
+        .query_all_raw(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT {key}::text AS key, xmin::text AS xmin FROM {table} WHERE book_id = $1"
+            ),
+            [book_id.raw().into()],
+        ))
+        .await
+        .unwrap();
+
+    rows.iter()
+        .map(|row| {
+            (
+                row.try_get::<String>("", "key").unwrap(),
+                row.try_get::<String>("", "xmin").unwrap(),
+            )
+        })
+        .collect()
+}
+
+async fn checkout_and_return(repository: &BookRepositoryImpl, actor: &Actor, book_id: BookId) {
+    let context = AuditContext::new(actor, &SystemClock);
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    book.do_checkout(&context, actor.into()).unwrap();
+    book.do_return(&context).unwrap();
+    repository.save(&mut book).await.unwrap();
+}
+
+#[tokio::test]
+async fn saving_a_checkout_writes_only_that_checkout_row() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let owner = common::create_user(&db, "owner").await;
+    let alice = common::create_user(&db, "alice").await;
+    let book_id = common::create_book(&db, &owner, "Well-read copy").await;
+    let repository = BookRepositoryImpl::new(db.clone());
+
+    checkout_and_return(&repository, &alice, book_id).await;
+    checkout_and_return(&repository, &alice, book_id).await;
+
+    let checkouts_before = row_versions(&db, "book_checkouts", "checkout_id", book_id).await;
+    let authors_before = row_versions(&db, "book_authors", "order_index", book_id).await;
+    assert_eq!(checkouts_before.len(), 2);
+
+    // Check out once more; the history and the authors are untouched
+    let context = AuditContext::new(&alice, &SystemClock);
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    book.do_checkout(&context, (&alice).into()).unwrap();
+    repository.save(&mut book).await.unwrap();
+
+    let checkouts_after = row_versions(&db, "book_checkouts", "checkout_id", book_id).await;
+    assert_eq!(checkouts_after.len(), 3);
+    for (checkout_id, xmin) in &checkouts_before {
+        assert_eq!(&checkouts_after[checkout_id], xmin);
+    }
+    assert_eq!(
+        row_versions(&db, "book_authors", "order_index", book_id).await,
+        authors_before
+    );
+
+    // Returning it rewrites only the active checkout
+    let active_id = checkouts_after
+        .keys()
+        .find(|id| !checkouts_before.contains_key(*id))
+        .unwrap()
+        .clone();
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    book.do_return(&context).unwrap();
+    repository.save(&mut book).await.unwrap();
+
+    let checkouts_returned = row_versions(&db, "book_checkouts", "checkout_id", book_id).await;
+    for (checkout_id, xmin) in &checkouts_after {
+        match checkout_id == &active_id {
+            true => assert_ne!(&checkouts_returned[checkout_id], xmin),
+            false => assert_eq!(&checkouts_returned[checkout_id], xmin),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+mod common;
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use application::book_import::{
+    command::{ProcessBookImportsService, StartBookImportService},
+    dto::{
+        BookImportDTO, BookImportFormatDTO, BookImportIdentity, BookImportQueryDTO,
+        BookImportStatusDTO,
+    },
+    interface::BookImportQueryService,
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::{
+    audit::{Actor, AuditContext, clock::SystemClock},
+    book::{
+        entity::{Book, NewBook},
+        enums::BookVisibility,
+        interface::BookRepository,
+        values::BookId,
+    },
+    shared::error::PersistenceError,
+};
+use infrastructure::{
+    book::BookRepositoryImpl,
+    book_import::{BookImportQueryServiceImpl, BookImportRepositoryImpl},
+    database::{
+        ConnectionPool,
+        entity::{book_imports, books},
+    },
+    user::UserDomainQueryServiceImpl,
+};
+use sea_orm::{EntityTrait, PaginatorTrait, QueryOrder};
+
+const LEASE: Duration = Duration::minutes(5);
+
+const DUNE_ISBN: &str = "9780441172719";
+
+/// Dies like a crashed worker once `saves_left` books have been saved.
+struct DyingBookRepository {
+    inner: BookRepositoryImpl,
+    saves_left: AtomicUsize,
+}
+
+#[async_trait]
+impl BookRepository for DyingBookRepository {
+    async fn find_by_id(&self, id: BookId) -> Result<Option<Book>, PersistenceError> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn save(&self, book: &mut Book) -> Result<(), PersistenceError> {
+        if self.saves_left.load(Ordering::SeqCst) == 0 {
+            panic!("the worker died");
+        }
+        self.saves_left.fetch_sub(1, Ordering::SeqCst);
+        self.inner.save(book).await
+    }
+
+    async fn delete(&self, book: &Book) -> Result<(), PersistenceError> {
+        self.inner.delete(book).await
+    }
+}
+
+fn csv_query(dry_run: bool) -> BookImportQueryDTO {
+    BookImportQueryDTO {
+        format: BookImportFormatDTO::Csv,
+        dry_run,
+        title_column: "title".into(),
+        authors_column: "authors".into(),
+        isbn_column: "isbn".into(),
+        description_column: "description".into(),
+        author_separator: ";".into(),
+    }
+}
+
+async fn start_import(
+    db: &ConnectionPool,
+    actor: &Actor,
+    body: &str,
+    dry_run: bool,
+) -> BookImportDTO {
+    StartBookImportService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookImportRepositoryImpl::new(db.clone())),
+        Arc::new(BookImportQueryServiceImpl::new(db.clone())),
+    )
+    .execute(actor, &csv_query(dry_run), body)
+    .await
+    .unwrap()
+}
+
+fn worker(
+    db: &ConnectionPool,
+    clock: Arc<common::ManualClock>,
+    book_repository: Arc<dyn BookRepository>,
+) -> ProcessBookImportsService {
+    ProcessBookImportsService::new(
+        clock,
+        Arc::new(BookImportRepositoryImpl::new(db.clone())),
+        Arc::new(BookImportQueryServiceImpl::new(db.clone())),
+        book_repository,
+        Arc::new(UserDomainQueryServiceImpl::new(db.clone())),
+        2,
+        LEASE,
+    )
+}
+
+async fn run_to_completion(worker: &ProcessBookImportsService) {
+    while worker.execute().await.unwrap() > 0 {}
+}
+
+async fn find_import(db: &ConnectionPool, actor: &Actor, import: &BookImportDTO) -> BookImportDTO {
+    BookImportQueryServiceImpl::new(db.clone())
+        .get_import(
+            actor.tenant(),
+            BookImportIdentity {
+                import_id: import.id.unwrap(),
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn book_titles(db: &ConnectionPool) -> Vec<String> {
+    books::Entity::find()
+        .order_by_asc(books::Column::Title)
+        .all(db.inner_ref())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|book| book.title)
+        .collect()
+}
+
+async fn create_dune(db: &ConnectionPool, owner: &Actor) {
+    let mut book = Book::create_new(
+        &AuditContext::new(owner, &SystemClock),
+        NewBook {
+            title: "Dune".to_string().try_into().unwrap(),
+            authors: vec!["Frank Herbert".to_string()].try_into().unwrap(),
+            isbn: Some(DUNE_ISBN.to_string()).try_into().unwrap(),
+            description: None.try_into().unwrap(),
+            owner: owner.into(),
+            group_id: None,
+            visibility: BookVisibility::Public,
+        },
+    )
+    .unwrap();
+    BookRepositoryImpl::new(db.clone())
+        .save(&mut book)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn dry_run_reports_every_rejected_row_without_queueing() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let actor = common::create_user(&db, "librarian").await;
+    create_dune(&db, &actor).await;
+
+    let body = format!(
+        "title,authors,isbn\n\
+         Dune,Frank Herbert,{DUNE_ISBN}\n\
+         Emma,Jane Austen,9780141439587\n\
+         Persuasion,Jane Austen,9780141439587\n\
+         ,Nobody,\n\
+         Middlemarch,George Eliot,\n"
+    );
+    let import = start_import(&db, &actor, &body, true).await;
+
+    assert_eq!(import.id, None);
+    assert_eq!(import.status, BookImportStatusDTO::Validated);
+    assert_eq!((import.total_rows, import.accepted_rows), (5, 2));
+    assert_eq!(
+        import.errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+        vec![2, 4, 5]
+    );
+    assert_eq!(
+        import.errors[0].message,
+        format!("A book with ISBN {DUNE_ISBN} already exists")
+    );
+
+    let queued = book_imports::Entity::find()
+        .count(db.inner_ref())
+        .await
+        .unwrap();
+    assert_eq!(queued, 0);
+}
+
+#[tokio::test]
+async fn worker_skips_isbns_added_after_validation() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let actor = common::create_user(&db, "librarian").await;
+    let body = format!(
+        "title,authors,isbn\n\
+         Dune,Frank Herbert,{DUNE_ISBN}\n\
+         Emma,Jane Austen,\n\
+         Middlemarch,George Eliot,\n"
+    );
+    let import = start_import(&db, &actor, &body, false).await;
+    assert_eq!(import.status, BookImportStatusDTO::Pending);
+
+    create_dune(&db, &actor).await;
+    let clock = Arc::new(common::ManualClock::new(Utc::now()));
+    run_to_completion(&worker(
+        &db,
+        clock,
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+    ))
+    .await;
+
+    let import = find_import(&db, &actor, &import).await;
+    assert_eq!(import.status, BookImportStatusDTO::Completed);
+    assert_eq!((import.processed_rows, import.created_count), (3, 2));
+    assert_eq!(import.errors.len(), 1);
+    assert_eq!(import.errors[0].line, 2);
+    assert_eq!(book_titles(&db).await, vec!["Dune", "Emma", "Middlemarch"]);
+}
+
+#[tokio::test]
+async fn a_worker_dying_mid_batch_does_not_create_books_twice() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let actor = common::create_user(&db, "librarian").await;
+    let body = "title,authors\n\
+                Emma,Jane Austen\n\
+                Middlemarch,George Eliot\n\
+                Persuasion,Jane Austen\n";
+    let import = start_import(&db, &actor, body, false).await;
+    let clock = Arc::new(common::ManualClock::new(Utc::now()));
+
+    // Dies on the second row of the first batch, after the first book was saved
+    let dying = worker(
+        &db,
+        clock.clone(),
+        Arc::new(DyingBookRepository {
+            inner: BookRepositoryImpl::new(db.clone()),
+            saves_left: AtomicUsize::new(1),
+        }),
+    );
+    assert!(
+        tokio::spawn(async move { dying.execute().await })
+            .await
+            .is_err()
+    );
+
+    // The import is picked up again once the dead worker's lease has expired
+    let next = worker(
+        &db,
+        clock.clone(),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+    );
+    assert_eq!(next.execute().await.unwrap(), 0);
+    clock.advance(LEASE);
+    run_to_completion(&next).await;
+
+    let import = find_import(&db, &actor, &import).await;
+    assert_eq!(import.status, BookImportStatusDTO::Completed);
+    assert_eq!((import.processed_rows, import.created_count), (3, 3));
+    assert_eq!(
+        book_titles(&db).await,
+        vec!["Emma", "Middlemarch", "Persuasion"]
+    );
+}
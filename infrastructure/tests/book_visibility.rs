@@ -0,0 +1,235 @@
+mod common;
+
+use std::{collections::HashSet, sync::Arc};
+
+use application::{
+    book::{
+        command::{CheckoutBookService, UpdateBookCoOwnersService},
+        dto::{
+            BookIdentity, BookListFilterDTO, BookListQueryDTO, CheckoutBookRequestDTO,
+            UpdateBookCoOwnersRequestDTO,
+        },
+        interface::BookQueryService,
+    },
+    shared::error::ApplicationError,
+};
+use domain::{
+    audit::{Actor, AuditContext, clock::SystemClock},
+    book::{enums::BookVisibility, interface::BookRepository, values::BookId},
+    shared::error::DomainError,
+    tenant::values::TenantId,
+};
+use infrastructure::{
+    book::{BookQueryServiceImpl, BookRepositoryImpl},
+    database::ConnectionPool,
+    group::GroupRepositoryImpl,
+    user::UserDomainQueryServiceImpl,
+};
+
+struct Library {
+    owner: Actor,
+    co_owner: Actor,
+    neighbour: Actor,
+    public: BookId,
+    internal: BookId,
+    private: BookId,
+}
+
+impl Library {
+    fn books(&self) -> [BookId; 3] {
+        [self.public, self.internal, self.private]
+    }
+}
+
+// One book of each visibility, owned by `owner` and co-owned by `co_owner`;
+// `neighbour` is a plain user of the same tenant
+async fn create_library(db: &ConnectionPool) -> Library {
+    let owner = common::create_user(db, "owner").await;
+    let co_owner = common::create_user(db, "co-owner").await;
+    let neighbour = common::create_user(db, "neighbour").await;
+
+    let public = common::create_book_with(db, &owner, "Public", BookVisibility::Public).await;
+    let internal = common::create_book_with(db, &owner, "Internal", BookVisibility::Internal).await;
+    let private = common::create_book_with(db, &owner, "Private", BookVisibility::Private).await;
+
+    let update_co_owners = UpdateBookCoOwnersService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+        Arc::new(UserDomainQueryServiceImpl::new(db.clone())),
+    );
+    for book_id in [public, internal, private] {
+        update_co_owners
+            .execute(
+                &owner,
+                BookIdentity { book_id },
+                None,
+                &UpdateBookCoOwnersRequestDTO {
+                    user_ids: vec![co_owner.id()],
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    Library {
+        owner,
+        co_owner,
+        neighbour,
+        public,
+        internal,
+        private,
+    }
+}
+
+async fn listed_books(
+    db: &ConnectionPool,
+    library: &Library,
+    actor: Option<&Actor>,
+) -> HashSet<BookId> {
+    let list = BookQueryServiceImpl::new(db.clone())
+        .get_book_list(
+            &TenantId::default(),
+            actor,
+            &BookListQueryDTO {
+                page_size: 50,
+                page: 1,
+                filter: BookListFilterDTO {
+                    owner_id: Some(library.owner.id().raw()),
+                    ..Default::default()
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    list.items.into_iter().map(|item| item.id.into()).collect()
+}
+
+async fn detailed_books(
+    db: &ConnectionPool,
+    library: &Library,
+    actor: Option<&Actor>,
+) -> HashSet<BookId> {
+    let query_service = BookQueryServiceImpl::new(db.clone());
+    let mut visible = HashSet::new();
+    for book_id in library.books() {
+        let details = query_service
+            .get_book_details(&TenantId::default(), actor, BookIdentity { book_id })
+            .await
+            .unwrap();
+        if details.is_some() {
+            visible.insert(book_id);
+        }
+    }
+
+    visible
+}
+
+// Checks out and returns every book, collecting those the actor could borrow
+async fn borrowable_books(
+    db: &ConnectionPool,
+    library: &Library,
+    actor: &Actor,
+) -> HashSet<BookId> {
+    let service = CheckoutBookService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+        Arc::new(UserDomainQueryServiceImpl::new(db.clone())),
+        Arc::new(GroupRepositoryImpl::new(db.clone())),
+    );
+    let repository = BookRepositoryImpl::new(db.clone());
+
+    let mut borrowable = HashSet::new();
+    for book_id in library.books() {
+        let result = service
+            .execute(
+                actor,
+                BookIdentity { book_id },
+                &CheckoutBookRequestDTO::default(),
+                None,
+            )
+            .await;
+        match result {
+            Ok(_) => {
+                let context = AuditContext::new(actor, &SystemClock);
+                let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+                book.do_return(&context).unwrap();
+                repository.save(&mut book).await.unwrap();
+                borrowable.insert(book_id);
+            }
+            Err(ApplicationError::NotFound)
+            | Err(ApplicationError::DomainError(DomainError::NotFound)) => {}
+            Err(e) => panic!("unexpected checkout error: {e:?}"),
+        }
+    }
+
+    borrowable
+}
+
+#[tokio::test]
+async fn anonymous_users_only_see_public_books() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let library = create_library(&db).await;
+    let expected = HashSet::from([library.public]);
+
+    assert_eq!(listed_books(&db, &library, None).await, expected);
+    assert_eq!(detailed_books(&db, &library, None).await, expected);
+}
+
+#[tokio::test]
+async fn tenant_users_see_public_and_internal_books() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let library = create_library(&db).await;
+    let neighbour = Some(&library.neighbour);
+    let expected = HashSet::from([library.public, library.internal]);
+
+    assert_eq!(listed_books(&db, &library, neighbour).await, expected);
+    assert_eq!(detailed_books(&db, &library, neighbour).await, expected);
+    assert_eq!(
+        borrowable_books(&db, &library, &library.neighbour).await,
+        expected
+    );
+}
+
+#[tokio::test]
+async fn owners_and_co_owners_see_every_book() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let library = create_library(&db).await;
+    let expected = HashSet::from(library.books());
+
+    for actor in [&library.owner, &library.co_owner] {
+        assert_eq!(listed_books(&db, &library, Some(actor)).await, expected);
+        assert_eq!(detailed_books(&db, &library, Some(actor)).await, expected);
+        assert_eq!(borrowable_books(&db, &library, actor).await, expected);
+    }
+}
+
+#[tokio::test]
+async fn visible_book_ids_follow_book_visibility() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let library = create_library(&db).await;
+    let query_service = BookQueryServiceImpl::new(db.clone());
+
+    let for_neighbour = query_service
+        .get_visible_book_ids(&library.neighbour, &library.books())
+        .await
+        .unwrap();
+    assert_eq!(
+        for_neighbour,
+        HashSet::from([library.public, library.internal])
+    );
+
+    let for_co_owner = query_service
+        .get_visible_book_ids(&library.co_owner, &library.books())
+        .await
+        .unwrap();
+    assert_eq!(for_co_owner, HashSet::from(library.books()));
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::user::{
+    command::GetOrCreateActorService,
+    dto::{GetOrCreateUserRequestDTO, UserRoleDTO},
+};
+use domain::{
+    audit::clock::SystemClock,
+    auth::capability::{Capability, CapabilitySet},
+    tenant::values::TenantId,
+    user::enums::UserRole,
+};
+use infrastructure::{
+    database::ConnectionPool,
+    user::{UserDomainQueryServiceImpl, UserRepositoryImpl},
+};
+use uuid::Uuid;
+
+fn capabilities(capabilities: &[Capability]) -> CapabilitySet {
+    capabilities.iter().copied().collect()
+}
+
+async fn sign_in(db: &ConnectionPool, scopes: Option<&[&str]>) -> CapabilitySet {
+    let service = GetOrCreateActorService::new(
+        Arc::new(SystemClock),
+        Arc::new(UserDomainQueryServiceImpl::new(db.clone())),
+        Arc::new(UserRepositoryImpl::new(db.clone())),
+    );
+    let id = Uuid::new_v4();
+    service
+        .execute(&GetOrCreateUserRequestDTO {
+            id: id.into(),
+            name: "Admin".into(),
+            email: format!("{id}@example.com"),
+            role: UserRoleDTO::Admin,
+            scopes: scopes.map(|scopes| scopes.iter().map(|s| s.to_string()).collect()),
+            tenant: TenantId::default(),
+        })
+        .await
+        .unwrap()
+        .capabilities()
+        .clone()
+}
+
+#[tokio::test]
+async fn roles_resolve_to_their_seeded_capabilities() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let tenant = TenantId::default();
+
+    let admin = common::create_user_in(&db, &tenant, "admin", UserRole::Admin).await;
+    let librarian = common::create_user_in(&db, &tenant, "librarian", UserRole::Librarian).await;
+    let regular = common::create_user_in(&db, &tenant, "regular", UserRole::Regular).await;
+
+    assert_eq!(*admin.capabilities(), CapabilitySet::all());
+    assert_eq!(
+        *librarian.capabilities(),
+        capabilities(&[Capability::CheckoutOnBehalf, Capability::ViewAllHistory])
+    );
+    assert!(regular.capabilities().is_empty());
+}
+
+#[tokio::test]
+async fn token_scopes_narrow_the_roles_capabilities() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    assert_eq!(sign_in(&db, None).await, CapabilitySet::all());
+    assert_eq!(
+        sign_in(&db, Some(&["openid", "view-all-history", "manage-tags"])).await,
+        capabilities(&[Capability::ViewAllHistory])
+    );
+    // Personal access tokens carry no scope that names a capability
+    assert!(sign_in(&db, Some(&[])).await.is_empty());
+}
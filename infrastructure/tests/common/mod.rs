@@ -0,0 +1,185 @@
+// Each test crate uses a different subset of these helpers
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use domain::{
+    audit::{Actor, AuditContext, Clock, clock::SystemClock},
+    book::{
+        entity::{Book, NewBook},
+        enums::BookVisibility,
+        interface::BookRepository,
+        values::BookId,
+    },
+    group::{entity::Group, interface::GroupRepository, values::GroupId},
+    tenant::values::TenantId,
+    user::{
+        entity::User,
+        enums::UserRole,
+        interface::{UserDomainQueryService, UserRepository},
+        values::UserId,
+    },
+};
+use infrastructure::{
+    book::BookRepositoryImpl,
+    database::ConnectionPool,
+    group::GroupRepositoryImpl,
+    user::{UserDomainQueryServiceImpl, UserRepositoryImpl},
+};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, Database};
+use uuid::Uuid;
+
+/// Creates a fresh database on the server at `TEST_DATABASE_URL` and applies every migration.
+/// Returns `None` when the variable is unset, so the suite still passes without PostgreSQL.
+pub async fn test_database() -> Option<ConnectionPool> {
+    let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+        eprintln!("TEST_DATABASE_URL is not set; skipping database test");
+        return None;
+    };
+
+    let name = format!("test_{}", Uuid::new_v4().simple());
+    let server = Database::connect(&url).await.unwrap();
+    server
+        .execute_unprepared(&format!("CREATE DATABASE {name}"))
+        .await
+        .unwrap();
+
+    // Swap the database name in `scheme://host:port/database?params`
+    let (path, params) = url.split_once('?').unwrap_or((&url, ""));
+    let (server_url, _) = path.rsplit_once('/').unwrap();
+    let test_url = match params {
+        "" => format!("{server_url}/{name}"),
+        params => format!("{server_url}/{name}?{params}"),
+    };
+
+    let db = Database::connect(test_url).await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+
+    Some(db.into())
+}
+
+/// A clock the test moves forward by hand.
+pub struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        ManualClock(Mutex::new(now))
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+pub async fn create_user(db: &ConnectionPool, name: &str) -> Actor {
+    create_user_in(db, &TenantId::default(), name, UserRole::Regular).await
+}
+
+pub async fn create_user_in(
+    db: &ConnectionPool,
+    tenant: &TenantId,
+    name: &str,
+    role: UserRole,
+) -> Actor {
+    let context = AuditContext::new(&Actor::new_system(tenant.clone()), &SystemClock);
+    let mut user = User::create_new(
+        &context,
+        UserId::from(Uuid::new_v4()),
+        name.to_string().try_into().unwrap(),
+        format!("{name}@example.com").try_into().unwrap(),
+        role,
+    )
+    .unwrap();
+    UserRepositoryImpl::new(db.clone())
+        .save(&mut user)
+        .await
+        .unwrap();
+
+    // Read back so that the actor carries the capabilities of its role
+    find_actor(db, user.audit().id()).await
+}
+
+/// Loads the actor as a request would, with its role capabilities and group memberships.
+pub async fn find_actor(db: &ConnectionPool, id: UserId) -> Actor {
+    UserDomainQueryServiceImpl::new(db.clone())
+        .find_actor_by_id(id)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+/// Creates a group with `creator` as its only member; reload the creator with [`find_actor`]
+/// to see the membership.
+pub async fn create_group(
+    db: &ConnectionPool,
+    creator: &Actor,
+    name: &str,
+    private_library: bool,
+) -> GroupId {
+    let context = AuditContext::new(creator, &SystemClock);
+    let mut group = Group::create_new(
+        &context,
+        name.to_string().try_into().unwrap(),
+        private_library,
+    )
+    .unwrap();
+    GroupRepositoryImpl::new(db.clone())
+        .save(&mut group)
+        .await
+        .unwrap();
+
+    group.audit().id()
+}
+
+pub async fn create_book(db: &ConnectionPool, owner: &Actor, title: &str) -> BookId {
+    create_book_with(db, owner, title, BookVisibility::Public).await
+}
+
+pub async fn create_book_with(
+    db: &ConnectionPool,
+    owner: &Actor,
+    title: &str,
+    visibility: BookVisibility,
+) -> BookId {
+    let context = AuditContext::new(owner, &SystemClock);
+    let mut book = Book::create_new(
+        &context,
+        NewBook {
+            title: title.to_string().try_into().unwrap(),
+            authors: vec!["Author".to_string()].try_into().unwrap(),
+            isbn: None.try_into().unwrap(),
+            description: None.try_into().unwrap(),
+            owner: owner.into(),
+            group_id: None,
+            visibility,
+        },
+    )
+    .unwrap();
+    BookRepositoryImpl::new(db.clone())
+        .save(&mut book)
+        .await
+        .unwrap();
+
+    book.audit().id()
+}
+
+pub async fn move_book_to_group(
+    db: &ConnectionPool,
+    owner: &Actor,
+    book_id: BookId,
+    group_id: GroupId,
+) {
+    let context = AuditContext::new(owner, &SystemClock);
+    let repository = BookRepositoryImpl::new(db.clone());
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    book.change_group(&context, Some(group_id)).unwrap();
+    repository.save(&mut book).await.unwrap();
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use application::event::{
+    dto::EventStreamQueryDTO,
+    query::{FilterVisibleEventsService, ReplayEventsService},
+};
+use domain::{
+    audit::{AuditContext, clock::SystemClock},
+    book::interface::BookRepository,
+    event::DomainEvent,
+};
+use infrastructure::{
+    book::{BookQueryServiceImpl, BookRepositoryImpl},
+    event::{EventListenerImpl, EventQueryServiceImpl, EventSubscription},
+};
+
+async fn next_event(subscription: &mut EventSubscription) -> DomainEvent {
+    tokio::time::timeout(Duration::from_secs(5), subscription.recv())
+        .await
+        .expect("no event was notified")
+        .unwrap()
+        .expect("the listener lost its connection")
+}
+
+#[tokio::test]
+async fn every_listener_receives_committed_events() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    // One listener per replica; the outbox relay would reach only one of them
+    let mut first = EventListenerImpl::new(db.clone()).listen().await.unwrap();
+    let mut second = EventListenerImpl::new(db.clone()).listen().await.unwrap();
+
+    let owner = common::create_user(&db, "owner").await;
+    let book_id = common::create_book(&db, &owner, "Streamed").await;
+
+    for subscription in [&mut first, &mut second] {
+        let event = next_event(subscription).await;
+        assert_eq!(event.event_type(), "book_created");
+        assert_eq!(event.kind().book_id(), Some(book_id));
+    }
+}
+
+#[tokio::test]
+async fn events_missed_while_disconnected_are_replayed_after_last_event_id() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let owner = common::create_user(&db, "owner").await;
+    let borrower = common::create_user(&db, "borrower").await;
+
+    let mut subscription = EventListenerImpl::new(db.clone()).listen().await.unwrap();
+    let book_id = common::create_book(&db, &owner, "Streamed").await;
+    let last_event_id = next_event(&mut subscription).await.id();
+    drop(subscription);
+
+    let repository = BookRepositoryImpl::new(db.clone());
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    book.do_checkout(
+        &AuditContext::new(&borrower, &SystemClock),
+        (&borrower).into(),
+    )
+    .unwrap();
+    repository.save(&mut book).await.unwrap();
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    book.do_return(&AuditContext::new(&borrower, &SystemClock))
+        .unwrap();
+    repository.save(&mut book).await.unwrap();
+
+    let replay = ReplayEventsService::new(
+        Arc::new(EventQueryServiceImpl::new(db.clone())),
+        Arc::new(FilterVisibleEventsService::new(Arc::new(
+            BookQueryServiceImpl::new(db.clone()),
+        ))),
+    );
+    let query = EventStreamQueryDTO {
+        book_id: Some(book_id),
+        owner_id: None,
+    };
+    let replayed = replay.execute(&owner, last_event_id, &query).await.unwrap();
+
+    assert_eq!(
+        replayed.iter().map(|e| e.event_type()).collect::<Vec<_>>(),
+        vec!["book_checked_out", "book_returned"]
+    );
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use application::idempotency::{dto::IdempotentResponseDTO, interface::IdempotencyRepository};
+use chrono::{Duration, Utc};
+use infrastructure::idempotency::IdempotencyRepositoryImpl;
+
+#[tokio::test]
+async fn abandoned_reservation_is_reclaimed_after_the_lock_timeout() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let actor = common::create_user(&db, "alice").await;
+    let repository = IdempotencyRepositoryImpl::new(db.clone());
+    let lock_timeout = Duration::seconds(60);
+    let ttl = Duration::hours(24);
+    let started_at = Utc::now();
+
+    assert!(
+        repository
+            .try_begin(
+                actor.id(),
+                "key",
+                "hash",
+                started_at,
+                started_at - lock_timeout,
+                started_at + ttl
+            )
+            .await
+            .unwrap()
+    );
+
+    // Still within the lock timeout: the first request holds the key
+    let retried_at = started_at + Duration::seconds(30);
+    assert!(
+        !repository
+            .try_begin(
+                actor.id(),
+                "key",
+                "hash",
+                retried_at,
+                retried_at - lock_timeout,
+                retried_at + ttl
+            )
+            .await
+            .unwrap()
+    );
+
+    // Past the lock timeout without a response: the retry takes over
+    let retried_at = started_at + Duration::seconds(90);
+    assert!(
+        repository
+            .try_begin(
+                actor.id(),
+                "key",
+                "hash",
+                retried_at,
+                retried_at - lock_timeout,
+                retried_at + ttl
+            )
+            .await
+            .unwrap()
+    );
+
+    // A completed request keeps its key until the TTL, however long ago it ran
+    repository
+        .complete(
+            actor.id(),
+            "key",
+            &IdempotentResponseDTO {
+                status: 201,
+                content_type: None,
+                body: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    let retried_at = started_at + Duration::hours(1);
+    assert!(
+        !repository
+            .try_begin(
+                actor.id(),
+                "key",
+                "hash",
+                retried_at,
+                retried_at - lock_timeout,
+                retried_at + ttl
+            )
+            .await
+            .unwrap()
+    );
+}
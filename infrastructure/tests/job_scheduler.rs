@@ -0,0 +1,209 @@
+mod common;
+
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use application::{
+    job::{
+        command::{RunDueJobsService, SyncJobsService, TriggerJobService},
+        dto::{JobIdentity, JobStatusDTO},
+        interface::{Job, JobClaim, JobDefinition, JobRepository},
+        schedule::ScheduledJob,
+    },
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use chrono::{Duration, DurationRound, Utc};
+use domain::{tenant::values::TenantId, user::enums::UserRole};
+use infrastructure::{
+    database::{ConnectionPool, entity::jobs},
+    job::JobRepositoryImpl,
+};
+use sea_orm::EntityTrait;
+
+const EVERY_MINUTE: &str = "0 * * * * *";
+const LEASE: Duration = Duration::minutes(5);
+
+struct CountingJob {
+    name: &'static str,
+    fails: bool,
+    runs: AtomicUsize,
+}
+
+impl CountingJob {
+    fn new(name: &'static str, fails: bool) -> Arc<Self> {
+        Arc::new(CountingJob {
+            name,
+            fails,
+            runs: AtomicUsize::new(0),
+        })
+    }
+
+    fn runs(&self) -> usize {
+        self.runs.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Job for CountingJob {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn run(&self) -> Result<(), ApplicationError> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        match self.fails {
+            true => Err(ApplicationError::InternalError("job failed".into())),
+            false => Ok(()),
+        }
+    }
+}
+
+async fn find_job(db: &ConnectionPool, name: &str) -> jobs::Model {
+    jobs::Entity::find_by_id(name)
+        .one(db.inner_ref())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_claims_never_return_the_same_job() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let repository = Arc::new(JobRepositoryImpl::new(db.clone()));
+    let now = Utc::now();
+
+    let names = (0..20).map(|i| format!("job-{i}")).collect::<Vec<_>>();
+    let definitions = names
+        .iter()
+        .map(|name| JobDefinition {
+            name: name.clone(),
+            schedule: EVERY_MINUTE.into(),
+            next_run_at: now - Duration::minutes(1),
+        })
+        .collect::<Vec<_>>();
+    repository.sync(&definitions).await.unwrap();
+
+    let claims = (0..4).map(|_| {
+        let repository = repository.clone();
+        let candidates = names
+            .iter()
+            .map(|name| JobClaim {
+                name: name.clone(),
+                next_run_at: now + Duration::minutes(1),
+            })
+            .collect::<Vec<_>>();
+        tokio::spawn(async move {
+            repository
+                .claim_due(now, now + LEASE, &candidates)
+                .await
+                .unwrap()
+        })
+    });
+
+    let mut claimed = vec![];
+    for claim in claims {
+        claimed.extend(claim.await.unwrap());
+    }
+
+    assert_eq!(claimed.len(), names.len());
+    assert_eq!(claimed.iter().collect::<HashSet<_>>().len(), names.len());
+}
+
+#[tokio::test]
+async fn next_run_advances_after_success_and_failure() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let start = Utc::now().duration_trunc(Duration::minutes(1)).unwrap() + Duration::seconds(30);
+    let clock = Arc::new(common::ManualClock::new(start));
+    let repository = Arc::new(JobRepositoryImpl::new(db.clone()));
+
+    let succeeding = CountingJob::new("succeeding", false);
+    let failing = CountingJob::new("failing", true);
+    let jobs = vec![
+        ScheduledJob::new(succeeding.clone(), EVERY_MINUTE).unwrap(),
+        ScheduledJob::new(failing.clone(), EVERY_MINUTE).unwrap(),
+    ];
+    SyncJobsService::new(clock.clone(), repository.clone(), jobs.clone())
+        .execute()
+        .await
+        .unwrap();
+    let run_due_jobs = RunDueJobsService::new(clock.clone(), repository, jobs, LEASE);
+
+    // Registered jobs first run at the next scheduled time
+    assert_eq!(run_due_jobs.execute().await.unwrap(), 0);
+
+    clock.advance(Duration::minutes(1));
+    assert_eq!(run_due_jobs.execute().await.unwrap(), 2);
+
+    let next_minute = start + Duration::seconds(90);
+    for (name, status) in [
+        ("succeeding", JobStatusDTO::Succeeded),
+        ("failing", JobStatusDTO::Failed),
+    ] {
+        let job = find_job(&db, name).await;
+        assert_eq!(job.next_run_at, next_minute);
+        assert_eq!(job.last_status.as_deref(), Some(status.as_ref()));
+        assert_eq!(job.locked_until, None);
+    }
+    assert_eq!(
+        find_job(&db, "failing").await.last_error.as_deref(),
+        Some("Internal server error: job failed")
+    );
+
+    // Neither job runs again before its next scheduled time
+    assert_eq!(run_due_jobs.execute().await.unwrap(), 0);
+
+    clock.advance(Duration::minutes(1));
+    assert_eq!(run_due_jobs.execute().await.unwrap(), 2);
+    assert_eq!((succeeding.runs(), failing.runs()), (2, 2));
+}
+
+#[tokio::test]
+async fn triggered_jobs_run_once_on_the_next_tick() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let clock = Arc::new(common::ManualClock::new(Utc::now()));
+    let repository = Arc::new(JobRepositoryImpl::new(db.clone()));
+
+    let job = CountingJob::new("triggered", false);
+    let jobs = vec![ScheduledJob::new(job.clone(), EVERY_MINUTE).unwrap()];
+    SyncJobsService::new(clock.clone(), repository.clone(), jobs.clone())
+        .execute()
+        .await
+        .unwrap();
+    let run_due_jobs = RunDueJobsService::new(clock.clone(), repository.clone(), jobs, LEASE);
+    let trigger_job = TriggerJobService::new(repository);
+
+    let admin = common::create_user_in(&db, &TenantId::default(), "admin", UserRole::Admin).await;
+    let identity = JobIdentity {
+        job_name: "triggered".into(),
+    };
+    let scheduled_at = find_job(&db, "triggered").await.next_run_at;
+
+    trigger_job.execute(&admin, &identity).await.unwrap();
+    assert!(find_job(&db, "triggered").await.run_requested);
+
+    assert_eq!(run_due_jobs.execute().await.unwrap(), 1);
+    assert_eq!(run_due_jobs.execute().await.unwrap(), 0);
+    assert_eq!(job.runs(), 1);
+    assert!(!find_job(&db, "triggered").await.run_requested);
+    assert_eq!(find_job(&db, "triggered").await.next_run_at, scheduled_at);
+
+    let unknown = JobIdentity {
+        job_name: "unknown".into(),
+    };
+    assert!(matches!(
+        trigger_job.execute(&admin, &unknown).await,
+        Err(ApplicationError::NotFound)
+    ));
+}
@@ -0,0 +1,199 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use application::notification::{
+    command::SendDueRemindersService,
+    dispatcher::NotificationDispatcher,
+    interface::{
+        DueCheckout, NotificationLogRepository, NotificationQueryService, NotificationRecipient,
+        NotificationRecord,
+    },
+    policy::NotificationPolicy,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::{
+    audit::clock::SystemClock,
+    book::values::BookId,
+    shared::error::PersistenceError,
+    user::values::{NotificationPreferences, UserId},
+};
+use infrastructure::{config::NotificationConfig, notification::SmtpNotifier};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    sync::mpsc,
+};
+use uuid::Uuid;
+
+struct CapturedMail {
+    recipients: Vec<String>,
+    data: String,
+}
+
+/// Accepts SMTP sessions and forwards every message it receives.
+async fn spawn_smtp_sink() -> (u16, mpsc::UnboundedReceiver<CapturedMail>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.into_split();
+                let mut lines = BufReader::new(reader).lines();
+                let mut recipients = Vec::new();
+
+                writer.write_all(b"220 sink ESMTP\r\n").await.unwrap();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    let command = line.to_ascii_uppercase();
+                    let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO")
+                    {
+                        b"250 sink\r\n"
+                    } else if command.starts_with("RCPT TO:") {
+                        recipients.push(line["RCPT TO:".len()..].trim().to_string());
+                        b"250 OK\r\n"
+                    } else if command == "DATA" {
+                        writer.write_all(b"354 Go ahead\r\n").await.unwrap();
+                        let mut data = String::new();
+                        while let Ok(Some(line)) = lines.next_line().await {
+                            if line == "." {
+                                break;
+                            }
+                            data.push_str(&line);
+                            data.push('\n');
+                        }
+                        tx.send(CapturedMail {
+                            recipients: std::mem::take(&mut recipients),
+                            data,
+                        })
+                        .unwrap();
+                        b"250 Queued\r\n"
+                    } else if command == "QUIT" {
+                        writer.write_all(b"221 Bye\r\n").await.unwrap();
+                        break;
+                    } else {
+                        b"250 OK\r\n"
+                    };
+                    writer.write_all(reply).await.unwrap();
+                }
+            });
+        }
+    });
+
+    (port, rx)
+}
+
+struct FixedDueCheckouts {
+    borrower_id: UserId,
+    checked_out_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl NotificationQueryService for FixedDueCheckouts {
+    async fn find_recipient(
+        &self,
+        _user_id: UserId,
+    ) -> Result<Option<NotificationRecipient>, PersistenceError> {
+        unimplemented!()
+    }
+
+    async fn find_book_title(&self, _book_id: BookId) -> Result<Option<String>, PersistenceError> {
+        unimplemented!()
+    }
+
+    async fn find_due_checkouts(
+        &self,
+        _checked_out_before: DateTime<Utc>,
+    ) -> Result<Vec<DueCheckout>, PersistenceError> {
+        Ok(vec![DueCheckout {
+            checkout_id: Uuid::nil(),
+            book_title: "The Rust Programming Language".into(),
+            checked_out_at: self.checked_out_at,
+            borrower: NotificationRecipient {
+                id: self.borrower_id,
+                name: "Alice".into(),
+                email: "alice@example.com".into(),
+                preferences: NotificationPreferences::default(),
+            },
+        }])
+    }
+}
+
+#[derive(Default)]
+struct InMemoryLogRepository {
+    records: Mutex<HashSet<(UserId, Uuid)>>,
+}
+
+#[async_trait]
+impl NotificationLogRepository for InMemoryLogRepository {
+    async fn try_record(&self, record: &NotificationRecord) -> Result<bool, PersistenceError> {
+        let mut records = self.records.lock().unwrap();
+        Ok(records.insert((record.user_id, record.reference_id)))
+    }
+
+    async fn remove(&self, record: &NotificationRecord) -> Result<(), PersistenceError> {
+        let mut records = self.records.lock().unwrap();
+        records.remove(&(record.user_id, record.reference_id));
+        Ok(())
+    }
+}
+
+fn notification_config(port: u16) -> NotificationConfig {
+    NotificationConfig {
+        enabled: true,
+        smtp_host: "127.0.0.1".into(),
+        smtp_port: port,
+        smtp_username: None,
+        smtp_password: None,
+        smtp_tls: false,
+        from: "Book Manager <noreply@localhost>".into(),
+        loan_period_days: 14,
+        due_soon_hours: 48,
+    }
+}
+
+#[tokio::test]
+async fn sends_overdue_reminder_through_smtp() {
+    let (port, mut received) = spawn_smtp_sink().await;
+
+    let policy = NotificationPolicy::new(Duration::days(14), Duration::hours(48));
+    let clock = Arc::new(SystemClock);
+    let dispatcher = NotificationDispatcher::new(
+        clock.clone(),
+        Arc::new(InMemoryLogRepository::default()),
+        Arc::new(SmtpNotifier::new(&notification_config(port)).unwrap()),
+    );
+    let service = SendDueRemindersService::new(
+        clock,
+        Arc::new(FixedDueCheckouts {
+            borrower_id: UserId::from(Uuid::new_v4()),
+            checked_out_at: Utc::now() - Duration::days(20),
+        }),
+        Arc::new(dispatcher),
+        policy,
+    );
+
+    assert_eq!(service.execute().await.unwrap(), 1);
+
+    let mail = received.recv().await.unwrap();
+    assert_eq!(mail.recipients, vec!["<alice@example.com>".to_string()]);
+    assert!(
+        mail.data
+            .lines()
+            .any(|line| line == "Subject: \"The Rust Programming Language\" is overdue"),
+        "unexpected message:\n{}",
+        mail.data
+    );
+    assert!(
+        mail.data
+            .lines()
+            .any(|line| line.starts_with("To: ") && line.ends_with("<alice@example.com>"))
+    );
+
+    // The same reminder is not sent twice
+    assert_eq!(service.execute().await.unwrap(), 0);
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::{
+    book::{
+        command::{DeleteBookService, UpdateBookService},
+        dto::{BookIdentity, UpdateBookRequestDTO},
+        interface::BookQueryService,
+    },
+    shared::error::ApplicationError,
+};
+use domain::{
+    audit::{Actor, AuditContext, clock::SystemClock},
+    book::{entity::Book, enums::BookVisibility, interface::BookRepository, values::BookId},
+    shared::error::{DomainError, PersistenceError},
+    tenant::values::TenantId,
+};
+use infrastructure::{
+    book::{BookQueryServiceImpl, BookRepositoryImpl},
+    database::ConnectionPool,
+};
+
+fn rename(book: &mut Book, actor: &Actor, title: &str) {
+    book.update(
+        &AuditContext::new(actor, &SystemClock),
+        title.to_string().try_into().unwrap(),
+        vec!["Author".to_string()].try_into().unwrap(),
+        None.try_into().unwrap(),
+        None.try_into().unwrap(),
+        BookVisibility::Public,
+    )
+    .unwrap();
+}
+
+fn update_request(title: &str) -> UpdateBookRequestDTO {
+    UpdateBookRequestDTO {
+        title: title.into(),
+        author_names: vec!["Author".into()],
+        isbn: None,
+        description: None,
+        visibility: None,
+    }
+}
+
+/// The version served as the book's `ETag`.
+async fn etag_version(db: &ConnectionPool, actor: &Actor, book_id: BookId) -> u32 {
+    BookQueryServiceImpl::new(db.clone())
+        .get_book_details(&TenantId::default(), Some(actor), BookIdentity { book_id })
+        .await
+        .unwrap()
+        .unwrap()
+        .audit
+        .version
+}
+
+#[tokio::test]
+async fn saving_the_same_book_twice_advances_its_version() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let owner = common::create_user(&db, "owner").await;
+    let book_id = common::create_book(&db, &owner, "First").await;
+    let repository = BookRepositoryImpl::new(db.clone());
+
+    let mut book = repository.find_by_id(book_id).await.unwrap().unwrap();
+    let loaded_version = book.audit().version();
+
+    rename(&mut book, &owner, "Second");
+    repository.save(&mut book).await.unwrap();
+    rename(&mut book, &owner, "Third");
+    repository.save(&mut book).await.unwrap();
+
+    assert_eq!(book.audit().version(), loaded_version + 2);
+    assert_eq!(
+        etag_version(&db, &owner, book_id).await,
+        book.audit().version()
+    );
+}
+
+#[tokio::test]
+async fn a_stale_copy_can_be_neither_saved_nor_deleted() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let owner = common::create_user(&db, "owner").await;
+    let book_id = common::create_book(&db, &owner, "First").await;
+    let repository = BookRepositoryImpl::new(db.clone());
+
+    let mut current = repository.find_by_id(book_id).await.unwrap().unwrap();
+    let mut stale = repository.find_by_id(book_id).await.unwrap().unwrap();
+
+    rename(&mut current, &owner, "Second");
+    repository.save(&mut current).await.unwrap();
+
+    rename(&mut stale, &owner, "Lost update");
+    assert!(matches!(
+        repository.save(&mut stale).await,
+        Err(PersistenceError::Conflict)
+    ));
+    assert!(matches!(
+        repository.delete(&stale).await,
+        Err(PersistenceError::Conflict)
+    ));
+    assert_eq!(
+        repository
+            .find_by_id(book_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .title(),
+        "Second"
+    );
+
+    repository.delete(&current).await.unwrap();
+    assert!(repository.find_by_id(book_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn if_match_with_an_outdated_etag_fails_the_precondition() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let owner = common::create_user(&db, "owner").await;
+    let book_id = common::create_book(&db, &owner, "First").await;
+    let identity = BookIdentity { book_id };
+    let update_book = UpdateBookService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+    );
+    let delete_book = DeleteBookService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+    );
+
+    let etag = etag_version(&db, &owner, book_id).await;
+    update_book
+        .execute(&owner, identity, Some(etag), &update_request("Second"))
+        .await
+        .unwrap();
+    assert_eq!(etag_version(&db, &owner, book_id).await, etag + 1);
+
+    let result = update_book
+        .execute(&owner, identity, Some(etag), &update_request("Lost update"))
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(DomainError::VersionMismatch))
+    ));
+    assert!(matches!(
+        delete_book.execute(&owner, identity, Some(etag)).await,
+        Err(ApplicationError::DomainError(DomainError::VersionMismatch))
+    ));
+
+    delete_book
+        .execute(&owner, identity, Some(etag + 1))
+        .await
+        .unwrap();
+}
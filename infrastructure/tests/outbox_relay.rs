@@ -0,0 +1,163 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::{
+    outbox::{command::RelayOutboxService, dto::OutboxStatusDTO, interface::OutboxRepository},
+    shared::{error::ApplicationError, event::EventPublisher, relay::RelayPolicy},
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::{audit::Clock, event::DomainEvent, shared::error::PersistenceError};
+use infrastructure::{
+    database::{ConnectionPool, entity::outbox},
+    outbox::OutboxRepositoryImpl,
+};
+use sea_orm::EntityTrait;
+use uuid::Uuid;
+
+const LEASE: Duration = Duration::seconds(30);
+const MAX_BACKOFF: Duration = Duration::minutes(5);
+
+struct FailingPublisher;
+
+#[async_trait]
+impl EventPublisher for FailingPublisher {
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+
+    async fn publish(&self, _event: &DomainEvent) -> Result<(), ApplicationError> {
+        Err(ApplicationError::InternalError("unreachable".into()))
+    }
+}
+
+fn policy(max_attempts: u32) -> RelayPolicy {
+    RelayPolicy::new(10, max_attempts, Duration::seconds(1), MAX_BACKOFF, LEASE)
+}
+
+/// Creating a book writes exactly one `BookCreated` message to the outbox.
+async fn enqueue_message(db: &ConnectionPool) -> Uuid {
+    let owner = common::create_user(db, "owner").await;
+    common::create_book(db, &owner, "Relayed").await;
+
+    let rows = outbox::Entity::find().all(db.inner_ref()).await.unwrap();
+    assert_eq!(rows.len(), 1);
+    rows[0].id
+}
+
+async fn find_row(db: &ConnectionPool, id: Uuid) -> outbox::Model {
+    outbox::Entity::find_by_id(id)
+        .one(db.inner_ref())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn claimed_messages_are_reclaimed_once_the_lease_expires() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let id = enqueue_message(&db).await;
+    let repository = OutboxRepositoryImpl::new(db.clone());
+    let now = Utc::now();
+
+    let claimed = repository.claim_due(now, now + LEASE, 10).await.unwrap();
+    assert_eq!(claimed.iter().map(|m| m.id).collect::<Vec<_>>(), vec![id]);
+
+    // A second relay skips the message while the first one holds the lease
+    let during_lease = now + LEASE - Duration::seconds(1);
+    let claimed = repository
+        .claim_due(during_lease, during_lease + LEASE, 10)
+        .await
+        .unwrap();
+    assert!(claimed.is_empty());
+
+    // ...and picks it up when the first relay died without reporting back
+    let after_lease = now + LEASE;
+    let claimed = repository
+        .claim_due(after_lease, after_lease + LEASE, 10)
+        .await
+        .unwrap();
+    assert_eq!(claimed.iter().map(|m| m.id).collect::<Vec<_>>(), vec![id]);
+}
+
+#[tokio::test]
+async fn failed_messages_back_off_and_are_dead_lettered_after_max_attempts() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let id = enqueue_message(&db).await;
+    let clock = Arc::new(common::ManualClock::new(Utc::now()));
+    let service = RelayOutboxService::new(
+        clock.clone(),
+        Arc::new(OutboxRepositoryImpl::new(db.clone())),
+        vec![Arc::new(FailingPublisher)],
+        policy(3),
+    );
+
+    for attempts in 1..=2 {
+        assert_eq!(service.execute().await.unwrap(), 1);
+
+        let row = find_row(&db, id).await;
+        assert_eq!(row.status, OutboxStatusDTO::Pending.as_ref());
+        assert_eq!(row.attempts, attempts);
+        assert!(row.next_attempt_at > clock.now());
+        assert!(row.last_error.unwrap().contains("failing"));
+
+        // Not retried before the backoff has elapsed
+        assert_eq!(service.execute().await.unwrap(), 0);
+        clock.advance(MAX_BACKOFF);
+    }
+
+    assert_eq!(service.execute().await.unwrap(), 1);
+    let row = find_row(&db, id).await;
+    assert_eq!(row.status, OutboxStatusDTO::Dead.as_ref());
+    assert_eq!(row.attempts, 3);
+
+    clock.advance(MAX_BACKOFF);
+    assert_eq!(service.execute().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn requeued_dead_messages_are_relayed_again() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let id = enqueue_message(&db).await;
+    let repository = OutboxRepositoryImpl::new(db.clone());
+    let now = Utc::now();
+
+    // Only dead or delivered messages can be requeued
+    assert!(matches!(
+        repository.requeue(id, now).await,
+        Err(PersistenceError::NotFound)
+    ));
+
+    repository.claim_due(now, now + LEASE, 10).await.unwrap();
+    repository
+        .mark_failed(id, 5, "gave up", None)
+        .await
+        .unwrap();
+    assert!(
+        repository
+            .claim_due(now + LEASE, now + LEASE * 2, 10)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let requeued_at = now + LEASE;
+    repository.requeue(id, requeued_at).await.unwrap();
+    let row = find_row(&db, id).await;
+    assert_eq!(row.status, OutboxStatusDTO::Pending.as_ref());
+    assert_eq!(row.attempts, 0);
+
+    let claimed = repository
+        .claim_due(requeued_at, requeued_at + LEASE, 10)
+        .await
+        .unwrap();
+    assert_eq!(claimed.iter().map(|m| m.id).collect::<Vec<_>>(), vec![id]);
+    assert_eq!(claimed[0].attempts, 0);
+}
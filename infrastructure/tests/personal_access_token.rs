@@ -0,0 +1,176 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::{
+    personal_access_token::{
+        command::{
+            AuthenticatePersonalAccessTokenService, CreatePersonalAccessTokenService,
+            RevokePersonalAccessTokenService,
+        },
+        dto::{
+            CreatePersonalAccessTokenRequestDTO, PersonalAccessTokenCreatedDTO,
+            PersonalAccessTokenIdentity, TokenScopeDTO,
+        },
+    },
+    shared::error::ApplicationError,
+};
+use chrono::{Duration, DurationRound, Utc};
+use domain::{
+    audit::{Actor, Clock},
+    tenant::values::TenantId,
+};
+use infrastructure::{
+    database::{ConnectionPool, entity::personal_access_tokens},
+    personal_access_token::{
+        PersonalAccessTokenQueryServiceImpl, PersonalAccessTokenRepositoryImpl,
+    },
+};
+use sea_orm::EntityTrait;
+
+fn authenticator(
+    db: &ConnectionPool,
+    clock: Arc<common::ManualClock>,
+) -> AuthenticatePersonalAccessTokenService {
+    AuthenticatePersonalAccessTokenService::new(
+        clock,
+        Arc::new(PersonalAccessTokenRepositoryImpl::new(db.clone())),
+        Arc::new(PersonalAccessTokenQueryServiceImpl::new(db.clone())),
+    )
+}
+
+async fn create_token(
+    db: &ConnectionPool,
+    clock: Arc<common::ManualClock>,
+    actor: &Actor,
+    expires_in_days: u32,
+) -> PersonalAccessTokenCreatedDTO {
+    CreatePersonalAccessTokenService::new(
+        clock,
+        Arc::new(PersonalAccessTokenRepositoryImpl::new(db.clone())),
+    )
+    .execute(
+        actor,
+        &CreatePersonalAccessTokenRequestDTO {
+            name: "e-reader".into(),
+            scopes: vec![TokenScopeDTO::BooksRead],
+            expires_in_days,
+        },
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn tokens_are_stored_hashed_and_found_by_their_hash() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    // Whole seconds survive the round trip through Postgres unchanged
+    let now = Utc::now().duration_trunc(Duration::seconds(1)).unwrap();
+    let clock = Arc::new(common::ManualClock::new(now));
+    let actor = common::create_user(&db, "reader").await;
+    let created = create_token(&db, clock.clone(), &actor, 30).await;
+
+    let stored = personal_access_tokens::Entity::find_by_id(created.id)
+        .one(db.inner_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_ne!(stored.token_hash, created.token);
+    assert!(!stored.token_hash.contains(&created.token));
+    assert_eq!(stored.last_used_at, None);
+
+    let credential = authenticator(&db, clock.clone())
+        .execute(&created.token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(credential.id, created.id);
+    assert_eq!(credential.user.id, actor.id().raw());
+    assert_eq!(credential.tenant, TenantId::default());
+    assert_eq!(credential.scopes, vec![TokenScopeDTO::BooksRead]);
+
+    let used = personal_access_tokens::Entity::find_by_id(created.id)
+        .one(db.inner_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(used.last_used_at.map(Into::into), Some(clock.now()));
+
+    let mut tampered = created.token.clone();
+    tampered.pop();
+    tampered.push('x');
+    assert!(
+        authenticator(&db, clock)
+            .execute(&tampered)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn expired_tokens_are_refused() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let clock = Arc::new(common::ManualClock::new(Utc::now()));
+    let actor = common::create_user(&db, "reader").await;
+    let created = create_token(&db, clock.clone(), &actor, 1).await;
+    let authenticate = authenticator(&db, clock.clone());
+
+    clock.advance(Duration::hours(23));
+    assert!(
+        authenticate
+            .execute(&created.token)
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    clock.advance(Duration::hours(1));
+    assert!(
+        authenticate
+            .execute(&created.token)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn revoked_tokens_are_refused() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+    let clock = Arc::new(common::ManualClock::new(Utc::now()));
+    let owner = common::create_user(&db, "reader").await;
+    let other = common::create_user(&db, "other").await;
+    let created = create_token(&db, clock.clone(), &owner, 30).await;
+    let revoke = RevokePersonalAccessTokenService::new(
+        clock.clone(),
+        Arc::new(PersonalAccessTokenRepositoryImpl::new(db.clone())),
+    );
+    let identity = PersonalAccessTokenIdentity {
+        token_id: created.id.into(),
+    };
+
+    assert!(matches!(
+        revoke.execute(&other, identity).await,
+        Err(ApplicationError::DomainError(_))
+    ));
+    revoke.execute(&owner, identity).await.unwrap();
+
+    assert!(
+        authenticator(&db, clock)
+            .execute(&created.token)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(matches!(
+        revoke.execute(&owner, identity).await,
+        Err(ApplicationError::NotFound)
+    ));
+}
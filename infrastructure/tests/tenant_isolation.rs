@@ -0,0 +1,213 @@
+mod common;
+
+use std::sync::Arc;
+
+use application::{
+    book::{
+        command::{DeleteBookService, UpdateBookCoOwnersService},
+        dto::{BookIdentity, UpdateBookCoOwnersRequestDTO},
+        query::GetBookDetailsService,
+    },
+    group::{dto::GroupIdentity, query::GetGroupDetailsService},
+    personal_access_token::{
+        command::{CreatePersonalAccessTokenService, RevokePersonalAccessTokenService},
+        dto::{CreatePersonalAccessTokenRequestDTO, PersonalAccessTokenIdentity, TokenScopeDTO},
+        query::GetPersonalAccessTokensService,
+    },
+    shared::error::ApplicationError,
+    webhook::{
+        command::{CreateWebhookService, DeleteWebhookService},
+        dto::{CreateWebhookRequestDTO, WebhookIdentity},
+        query::GetWebhooksService,
+    },
+};
+use domain::{
+    audit::{Actor, clock::SystemClock},
+    shared::error::DomainError,
+    tenant::values::TenantId,
+    user::enums::UserRole,
+};
+use infrastructure::{
+    book::{BookQueryServiceImpl, BookRepositoryImpl},
+    database::ConnectionPool,
+    group::GroupQueryServiceImpl,
+    personal_access_token::{
+        PersonalAccessTokenQueryServiceImpl, PersonalAccessTokenRepositoryImpl,
+    },
+    user::UserDomainQueryServiceImpl,
+    webhook::{WebhookQueryServiceImpl, WebhookRepositoryImpl},
+};
+
+/// Another tenant's entity is reported exactly like one that does not exist.
+fn is_not_found<T>(result: &Result<T, ApplicationError>) -> bool {
+    matches!(
+        result,
+        Err(ApplicationError::NotFound) | Err(ApplicationError::DomainError(DomainError::NotFound))
+    )
+}
+
+fn globex() -> TenantId {
+    "globex".to_string().try_into().unwrap()
+}
+
+async fn admin_in(db: &ConnectionPool, tenant: &TenantId, name: &str) -> Actor {
+    common::create_user_in(db, tenant, name, UserRole::Admin).await
+}
+
+#[tokio::test]
+async fn books_of_another_tenant_are_not_found() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let owner = common::create_user(&db, "owner").await;
+    let book_id = common::create_book(&db, &owner, "Default tenant copy").await;
+    let outsider = admin_in(&db, &globex(), "outsider").await;
+    let identity = BookIdentity { book_id };
+
+    let details = GetBookDetailsService::new(Arc::new(BookQueryServiceImpl::new(db.clone())));
+    assert!(is_not_found(
+        &details.execute(&globex(), Some(&outsider), identity).await
+    ));
+    // Anonymous requests addressed to the other tenant's host
+    assert!(is_not_found(
+        &details.execute(&globex(), None, identity).await
+    ));
+    assert!(
+        details
+            .execute(&TenantId::default(), None, identity)
+            .await
+            .is_ok()
+    );
+
+    let delete = DeleteBookService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+    );
+    assert!(is_not_found(
+        &delete.execute(&outsider, identity, None).await
+    ));
+}
+
+#[tokio::test]
+async fn users_of_another_tenant_are_not_found() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let owner = common::create_user(&db, "owner").await;
+    let book_id = common::create_book(&db, &owner, "Default tenant copy").await;
+    let outsider = common::create_user_in(&db, &globex(), "outsider", UserRole::Regular).await;
+
+    let update_co_owners = UpdateBookCoOwnersService::new(
+        Arc::new(SystemClock),
+        Arc::new(BookRepositoryImpl::new(db.clone())),
+        Arc::new(UserDomainQueryServiceImpl::new(db.clone())),
+    );
+    let result = update_co_owners
+        .execute(
+            &owner,
+            BookIdentity { book_id },
+            None,
+            &UpdateBookCoOwnersRequestDTO {
+                user_ids: vec![outsider.id()],
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(DomainError::ValidationError(message)))
+            if message == "Co-owner does not exist"
+    ));
+}
+
+#[tokio::test]
+async fn groups_of_another_tenant_are_not_found() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let creator = common::create_user(&db, "creator").await;
+    let group_id = common::create_group(&db, &creator, "Platform", false).await;
+    let outsider = admin_in(&db, &globex(), "outsider").await;
+
+    let details = GetGroupDetailsService::new(Arc::new(GroupQueryServiceImpl::new(db.clone())));
+    assert!(is_not_found(
+        &details.execute(&outsider, GroupIdentity { group_id }).await
+    ));
+    assert!(
+        details
+            .execute(&creator, GroupIdentity { group_id })
+            .await
+            .is_ok()
+    );
+}
+
+#[tokio::test]
+async fn webhooks_of_another_tenant_are_not_found() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let admin = admin_in(&db, &TenantId::default(), "admin").await;
+    let outsider = admin_in(&db, &globex(), "outsider").await;
+    let repository = Arc::new(WebhookRepositoryImpl::new(db.clone()));
+
+    let created = CreateWebhookService::new(Arc::new(SystemClock), repository.clone())
+        .execute(
+            &admin,
+            &CreateWebhookRequestDTO {
+                url: "https://hooks.example.com/books".to_string(),
+                event_types: vec!["book_created".to_string()],
+                secret: "0123456789abcdef".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let list = GetWebhooksService::new(Arc::new(WebhookQueryServiceImpl::new(db.clone())));
+    assert!(list.execute(&outsider).await.unwrap().is_empty());
+    assert_eq!(list.execute(&admin).await.unwrap().len(), 1);
+
+    let delete = DeleteWebhookService::new(Arc::new(SystemClock), repository);
+    let identity = WebhookIdentity {
+        webhook_id: created.id.into(),
+    };
+    assert!(is_not_found(&delete.execute(&outsider, identity).await));
+}
+
+#[tokio::test]
+async fn personal_access_tokens_of_another_tenant_are_not_found() {
+    let Some(db) = common::test_database().await else {
+        return;
+    };
+
+    let user = common::create_user(&db, "reader").await;
+    let outsider = admin_in(&db, &globex(), "outsider").await;
+    let repository = Arc::new(PersonalAccessTokenRepositoryImpl::new(db.clone()));
+
+    let created = CreatePersonalAccessTokenService::new(Arc::new(SystemClock), repository.clone())
+        .execute(
+            &user,
+            &CreatePersonalAccessTokenRequestDTO {
+                name: "e-reader".to_string(),
+                scopes: vec![TokenScopeDTO::BooksRead],
+                expires_in_days: 30,
+            },
+        )
+        .await
+        .unwrap();
+
+    let list = GetPersonalAccessTokensService::new(Arc::new(
+        PersonalAccessTokenQueryServiceImpl::new(db.clone()),
+    ));
+    assert!(list.execute(&outsider, user.id()).await.unwrap().is_empty());
+    assert_eq!(list.execute(&user, user.id()).await.unwrap().len(), 1);
+
+    let revoke = RevokePersonalAccessTokenService::new(Arc::new(SystemClock), repository);
+    let identity = PersonalAccessTokenIdentity {
+        token_id: created.id.into(),
+    };
+    assert!(is_not_found(&revoke.execute(&outsider, identity).await));
+}
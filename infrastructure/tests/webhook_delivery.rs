@@ -0,0 +1,239 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use application::{
+    shared::relay::RelayPolicy,
+    webhook::{
+        command::DeliverWebhooksService,
+        interface::{NewWebhookDelivery, WebhookDelivery, WebhookDeliveryRepository},
+    },
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::{audit::clock::SystemClock, shared::error::PersistenceError};
+use hmac::{Hmac, Mac};
+use infrastructure::webhook::HttpWebhookSender;
+use sha2::Sha256;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+use uuid::Uuid;
+
+const SECRET: &str = "test-secret";
+
+struct ReceivedRequest {
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+/// Answers each request with the next queued status and forwards what it received.
+async fn spawn_receiver(statuses: Vec<u16>) -> (String, mpsc::UnboundedReceiver<ReceivedRequest>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let mut statuses = VecDeque::from(statuses);
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let request = read_request(&mut stream).await;
+            let status = statuses.pop_front().unwrap_or(200);
+            let response = format!(
+                "HTTP/1.1 {status} Status\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            tx.send(request).unwrap();
+        }
+    });
+
+    (url, rx)
+}
+
+async fn read_request(stream: &mut TcpStream) -> ReceivedRequest {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let headers: HashMap<String, String> = head
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim().to_string()))
+        .collect();
+
+    let content_length: usize = headers
+        .get("content-length")
+        .map(|v| v.parse().unwrap())
+        .unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await.unwrap();
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    ReceivedRequest {
+        headers,
+        body: String::from_utf8(buf[header_end..header_end + content_length].to_vec()).unwrap(),
+    }
+}
+
+struct StoredDelivery {
+    delivery: WebhookDelivery,
+    next_attempt_at: Option<DateTime<Utc>>,
+    delivered_status: Option<u16>,
+}
+
+#[derive(Default)]
+struct InMemoryDeliveryRepository {
+    deliveries: Mutex<Vec<StoredDelivery>>,
+}
+
+impl InMemoryDeliveryRepository {
+    fn with(delivery: WebhookDelivery) -> Self {
+        InMemoryDeliveryRepository {
+            deliveries: Mutex::new(vec![StoredDelivery {
+                delivery,
+                next_attempt_at: Some(DateTime::<Utc>::MIN_UTC),
+                delivered_status: None,
+            }]),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for InMemoryDeliveryRepository {
+    async fn enqueue(&self, _deliveries: &[NewWebhookDelivery]) -> Result<(), PersistenceError> {
+        unimplemented!()
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        _limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, PersistenceError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        Ok(deliveries
+            .iter_mut()
+            .filter(|d| d.next_attempt_at.is_some_and(|at| at <= now))
+            .map(|d| {
+                d.next_attempt_at = Some(lease_until);
+                WebhookDelivery {
+                    id: d.delivery.id,
+                    url: d.delivery.url.clone(),
+                    secret: d.delivery.secret.clone(),
+                    event_type: d.delivery.event_type.clone(),
+                    payload: d.delivery.payload.clone(),
+                    attempts: d.delivery.attempts,
+                }
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(
+        &self,
+        id: Uuid,
+        response_status: u16,
+        _delivered_at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        let stored = deliveries.iter_mut().find(|d| d.delivery.id == id).unwrap();
+        stored.next_attempt_at = None;
+        stored.delivered_status = Some(response_status);
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        attempts: u32,
+        _response_status: Option<u16>,
+        _error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), PersistenceError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        let stored = deliveries.iter_mut().find(|d| d.delivery.id == id).unwrap();
+        stored.delivery.attempts = attempts;
+        stored.next_attempt_at = next_attempt_at;
+        Ok(())
+    }
+
+    async fn purge_finished(&self, _before: DateTime<Utc>) -> Result<u64, PersistenceError> {
+        unimplemented!()
+    }
+}
+
+fn expected_signature(timestamp: &str, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[tokio::test]
+async fn delivers_signed_event_and_retries_on_non_success_status() {
+    let (url, mut received) = spawn_receiver(vec![500, 204]).await;
+
+    let delivery_id = Uuid::new_v4();
+    let payload = r#"{"type":"book.created","data":{"title":"Rust"}}"#.to_string();
+    let repository = Arc::new(InMemoryDeliveryRepository::with(WebhookDelivery {
+        id: delivery_id,
+        url,
+        secret: SECRET.into(),
+        event_type: "book.created".into(),
+        payload: payload.clone(),
+        attempts: 0,
+    }));
+    let service = DeliverWebhooksService::new(
+        Arc::new(SystemClock),
+        repository.clone(),
+        Arc::new(HttpWebhookSender::new().unwrap()),
+        RelayPolicy::new(
+            10,
+            3,
+            Duration::zero(),
+            Duration::zero(),
+            Duration::minutes(1),
+        ),
+    );
+
+    // The first attempt is rejected by the receiver and rescheduled
+    assert_eq!(service.execute().await.unwrap(), 1);
+    let first = received.recv().await.unwrap();
+    {
+        let deliveries = repository.deliveries.lock().unwrap();
+        assert_eq!(deliveries[0].delivery.attempts, 1);
+        assert!(deliveries[0].next_attempt_at.is_some());
+        assert_eq!(deliveries[0].delivered_status, None);
+    }
+
+    // The retry succeeds
+    assert_eq!(service.execute().await.unwrap(), 1);
+    let second = received.recv().await.unwrap();
+    {
+        let deliveries = repository.deliveries.lock().unwrap();
+        assert_eq!(deliveries[0].delivered_status, Some(204));
+        assert_eq!(deliveries[0].next_attempt_at, None);
+    }
+
+    for request in [&first, &second] {
+        assert_eq!(request.body, payload);
+        assert_eq!(request.headers["x-webhook-id"], delivery_id.to_string());
+        assert_eq!(request.headers["x-webhook-event"], "book.created");
+        assert_eq!(
+            request.headers["x-webhook-signature"],
+            expected_signature(&request.headers["x-webhook-timestamp"], &request.body)
+        );
+    }
+
+    // Nothing is left to deliver
+    assert_eq!(service.execute().await.unwrap(), 0);
+}
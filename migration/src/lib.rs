@@ -1,6 +1,22 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20261019_000001_create_outbox_table;
+mod m20261019_000002_create_webhook_tables;
+mod m20261019_000003_add_notification_tables;
+mod m20261019_000004_create_jobs_table;
+mod m20261019_000005_add_version_columns;
+mod m20261019_000006_add_active_checkout_index;
+mod m20261019_000007_create_idempotency_keys_table;
+mod m20261019_000008_create_book_imports_table;
+mod m20261019_000009_create_personal_access_tokens_table;
+mod m20261019_000010_create_role_permissions_table;
+mod m20261019_000011_add_checkout_actor_columns;
+mod m20261019_000012_create_book_co_owners_table;
+mod m20261019_000013_create_groups_tables;
+mod m20261019_000014_add_tenant_columns;
+mod m20261019_000015_add_book_visibility_columns;
+mod m20261019_000016_remove_manage_tags_permission;
 mod macros;
 
 pub struct Migrator;
@@ -8,6 +24,24 @@ pub struct Migrator;
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20261019_000001_create_outbox_table::Migration),
+            Box::new(m20261019_000002_create_webhook_tables::Migration),
+            Box::new(m20261019_000003_add_notification_tables::Migration),
+            Box::new(m20261019_000004_create_jobs_table::Migration),
+            Box::new(m20261019_000005_add_version_columns::Migration),
+            Box::new(m20261019_000006_add_active_checkout_index::Migration),
+            Box::new(m20261019_000007_create_idempotency_keys_table::Migration),
+            Box::new(m20261019_000008_create_book_imports_table::Migration),
+            Box::new(m20261019_000009_create_personal_access_tokens_table::Migration),
+            Box::new(m20261019_000010_create_role_permissions_table::Migration),
+            Box::new(m20261019_000011_add_checkout_actor_columns::Migration),
+            Box::new(m20261019_000012_create_book_co_owners_table::Migration),
+            Box::new(m20261019_000013_create_groups_tables::Migration),
+            Box::new(m20261019_000014_add_tenant_columns::Migration),
+            Box::new(m20261019_000015_add_book_visibility_columns::Migration),
+            Box::new(m20261019_000016_remove_manage_tags_permission::Migration),
+        ]
     }
 }
@@ -0,0 +1,134 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::NotifyDueSoon)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_column(
+                        ColumnDef::new(Users::NotifyOverdue)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_column(
+                        ColumnDef::new(Users::NotifyBookCheckedOut)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .add_column(
+                        ColumnDef::new(Users::NotifyBookReturned)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationLog::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationLog::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(NotificationLog::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(NotificationLog::Kind)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationLog::ReferenceId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationLog::SentAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_log_user_id")
+                            .from(NotificationLog::Table, NotificationLog::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Keeps a notification from being sent twice when an event or reminder is processed again
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_notification_log_user_id_kind_reference_id")
+                    .table(NotificationLog::Table)
+                    .col(NotificationLog::UserId)
+                    .col(NotificationLog::Kind)
+                    .col(NotificationLog::ReferenceId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(NotificationLog::Table).to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::NotifyDueSoon)
+                    .drop_column(Users::NotifyOverdue)
+                    .drop_column(Users::NotifyBookCheckedOut)
+                    .drop_column(Users::NotifyBookReturned)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+    NotifyDueSoon,
+    NotifyOverdue,
+    NotifyBookCheckedOut,
+    NotifyBookReturned,
+}
+
+#[derive(DeriveIden)]
+enum NotificationLog {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    ReferenceId,
+    SentAt,
+}
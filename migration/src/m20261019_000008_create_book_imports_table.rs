@@ -0,0 +1,116 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookImports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BookImports::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BookImports::ActorId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(BookImports::Status)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(BookImports::Rows).json_binary().not_null())
+                    .col(ColumnDef::new(BookImports::TotalRows).integer().not_null())
+                    .col(
+                        ColumnDef::new(BookImports::AcceptedRows)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BookImports::ProcessedRows)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(BookImports::CreatedCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(BookImports::Errors).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(BookImports::LockedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(BookImports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BookImports::FinishedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_imports_actor_id")
+                            .from(BookImports::Table, BookImports::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_imports_status_created_at")
+                    .table(BookImports::Table)
+                    .col(BookImports::Status)
+                    .col(BookImports::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookImports::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum BookImports {
+    Table,
+    Id,
+    ActorId,
+    Status,
+    Rows,
+    TotalRows,
+    AcceptedRows,
+    ProcessedRows,
+    CreatedCount,
+    Errors,
+    LockedUntil,
+    CreatedAt,
+    FinishedAt,
+}
@@ -0,0 +1,122 @@
+use sea_orm_migration::prelude::*;
+
+use crate::macros::with_audit_columns;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                with_audit_columns!(
+                    PersonalAccessTokens,
+                    Table::create()
+                        .table(PersonalAccessTokens::Table)
+                        .if_not_exists()
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::Version)
+                                .integer()
+                                .not_null()
+                                .default(1),
+                        )
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::UserId)
+                                .uuid()
+                                .not_null(),
+                        )
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::Name)
+                                .string_len(100)
+                                .not_null(),
+                        )
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::TokenHash)
+                                .string_len(64)
+                                .not_null(),
+                        )
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::Scopes)
+                                .json_binary()
+                                .not_null(),
+                        )
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::ExpiresAt)
+                                .timestamp_with_time_zone()
+                                .not_null(),
+                        )
+                        .col(
+                            ColumnDef::new(PersonalAccessTokens::LastUsedAt)
+                                .timestamp_with_time_zone()
+                                .null(),
+                        )
+                        .foreign_key(
+                            ForeignKey::create()
+                                .name("fk_personal_access_tokens_user_id")
+                                .from(PersonalAccessTokens::Table, PersonalAccessTokens::UserId)
+                                .to(Users::Table, Users::Id)
+                                .on_delete(ForeignKeyAction::Cascade)
+                                .on_update(ForeignKeyAction::Cascade),
+                        )
+                )
+                .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_personal_access_tokens_token_hash")
+                    .table(PersonalAccessTokens::Table)
+                    .col(PersonalAccessTokens::TokenHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_personal_access_tokens_user_id")
+                    .table(PersonalAccessTokens::Table)
+                    .col(PersonalAccessTokens::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PersonalAccessTokens::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum PersonalAccessTokens {
+    Table,
+    Id,
+    CreatedAt,
+    CreatedById,
+    CreatedByName,
+    UpdatedAt,
+    UpdatedById,
+    UpdatedByName,
+    Version,
+    UserId,
+    Name,
+    TokenHash,
+    Scopes,
+    ExpiresAt,
+    LastUsedAt,
+}
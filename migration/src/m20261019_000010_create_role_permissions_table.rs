@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+/// Named permissions granted to admins out of the box; regular users start with none.
+const ADMIN_PERMISSIONS: [&str; 4] = [
+    "manage-users",
+    "manage-tags",
+    "checkout-on-behalf",
+    "view-all-history",
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RolePermissions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RolePermissions::Role)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RolePermissions::Permission)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("pk_role_permissions")
+                            .col(RolePermissions::Role)
+                            .col(RolePermissions::Permission),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(RolePermissions::Table)
+            .columns([RolePermissions::Role, RolePermissions::Permission]);
+        for permission in ADMIN_PERMISSIONS {
+            insert.values_panic(["Admin".into(), permission.into()]);
+        }
+        manager.exec_stmt(insert).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RolePermissions::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RolePermissions {
+    Table,
+    Role,
+    Permission,
+}
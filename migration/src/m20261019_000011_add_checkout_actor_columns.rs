@@ -0,0 +1,152 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The existing columns hold the borrower; the acting user now gets columns of its own
+        rename_column(
+            manager,
+            BookCheckouts::CheckedOutById,
+            BookCheckouts::CheckedOutToId,
+        )
+        .await?;
+        rename_column(
+            manager,
+            BookCheckouts::CheckedOutByName,
+            BookCheckouts::CheckedOutToName,
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BookCheckouts::Table)
+                    .add_column(ColumnDef::new(BookCheckouts::CheckedOutById).uuid().null())
+                    .add_column(
+                        ColumnDef::new(BookCheckouts::CheckedOutByName)
+                            .string_len(100)
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(BookCheckouts::ReturnedById).uuid().null())
+                    .add_column(
+                        ColumnDef::new(BookCheckouts::ReturnedByName)
+                            .string_len(100)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Until now borrowers could only check books out to themselves
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE book_checkouts \
+                 SET checked_out_by_id = checked_out_to_id, checked_out_by_name = checked_out_to_name",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BookCheckouts::Table)
+                    .modify_column(
+                        ColumnDef::new(BookCheckouts::CheckedOutById)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(BookCheckouts::CheckedOutByName)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(RolePermissions::Table)
+            .columns([RolePermissions::Role, RolePermissions::Permission])
+            .values_panic(["Librarian".into(), "checkout-on-behalf".into()])
+            .values_panic(["Librarian".into(), "view-all-history".into()]);
+        manager.exec_stmt(insert).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(RolePermissions::Table)
+                    .and_where(Expr::col(RolePermissions::Role).eq("Librarian"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BookCheckouts::Table)
+                    .drop_column(BookCheckouts::CheckedOutById)
+                    .drop_column(BookCheckouts::CheckedOutByName)
+                    .drop_column(BookCheckouts::ReturnedById)
+                    .drop_column(BookCheckouts::ReturnedByName)
+                    .to_owned(),
+            )
+            .await?;
+
+        rename_column(
+            manager,
+            BookCheckouts::CheckedOutToId,
+            BookCheckouts::CheckedOutById,
+        )
+        .await?;
+        rename_column(
+            manager,
+            BookCheckouts::CheckedOutToName,
+            BookCheckouts::CheckedOutByName,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Postgres renames one column per `ALTER TABLE`.
+async fn rename_column(
+    manager: &SchemaManager<'_>,
+    from: BookCheckouts,
+    to: BookCheckouts,
+) -> Result<(), DbErr> {
+    manager
+        .alter_table(
+            Table::alter()
+                .table(BookCheckouts::Table)
+                .rename_column(from, to)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum BookCheckouts {
+    Table,
+    CheckedOutById,
+    CheckedOutByName,
+    CheckedOutToId,
+    CheckedOutToName,
+    ReturnedById,
+    ReturnedByName,
+}
+
+#[derive(DeriveIden)]
+enum RolePermissions {
+    Table,
+    Role,
+    Permission,
+}
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookCoOwners::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(BookCoOwners::BookId).uuid().not_null())
+                    .col(ColumnDef::new(BookCoOwners::UserId).uuid().not_null())
+                    .primary_key(
+                        Index::create()
+                            .name("pk_book_co_owners")
+                            .col(BookCoOwners::BookId)
+                            .col(BookCoOwners::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_co_owners_book_id")
+                            .from(BookCoOwners::Table, BookCoOwners::BookId)
+                            .to(Books::Table, Books::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_book_co_owners_user_id")
+                            .from(BookCoOwners::Table, BookCoOwners::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // "Managed by me" looks books up by co-owner
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_book_co_owners_user_id")
+                    .table(BookCoOwners::Table)
+                    .col(BookCoOwners::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookCoOwners::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Books {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum BookCoOwners {
+    Table,
+    BookId,
+    UserId,
+}
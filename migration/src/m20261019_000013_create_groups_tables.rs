@@ -0,0 +1,166 @@
+use sea_orm_migration::prelude::*;
+
+use crate::macros::with_audit_columns;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                with_audit_columns!(
+                    Groups,
+                    Table::create()
+                        .table(Groups::Table)
+                        .if_not_exists()
+                        .col(
+                            ColumnDef::new(Groups::Version)
+                                .integer()
+                                .not_null()
+                                .default(1),
+                        )
+                        .col(ColumnDef::new(Groups::Name).string_len(100).not_null())
+                        .col(
+                            ColumnDef::new(Groups::PrivateLibrary)
+                                .boolean()
+                                .not_null()
+                                .default(false),
+                        )
+                )
+                .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupMembers::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(GroupMembers::GroupId).uuid().not_null())
+                    .col(ColumnDef::new(GroupMembers::UserId).uuid().not_null())
+                    .col(ColumnDef::new(GroupMembers::Role).string_len(32).not_null())
+                    .primary_key(
+                        Index::create()
+                            .name("pk_group_members")
+                            .col(GroupMembers::GroupId)
+                            .col(GroupMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_members_group_id")
+                            .from(GroupMembers::Table, GroupMembers::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_members_user_id")
+                            .from(GroupMembers::Table, GroupMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Loading an actor looks up their memberships
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_group_members_user_id")
+                    .table(GroupMembers::Table)
+                    .col(GroupMembers::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Deleting a group hands its books back to their owners
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Books::Table)
+                    .add_column(ColumnDef::new(Books::GroupId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_books_group_id")
+                            .from_tbl(Books::Table)
+                            .from_col(Books::GroupId)
+                            .to_tbl(Groups::Table)
+                            .to_col(Groups::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_books_group_id")
+                    .table(Books::Table)
+                    .col(Books::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Books::Table)
+                    .drop_column(Books::GroupId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(GroupMembers::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Groups::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Books {
+    Table,
+    GroupId,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Groups {
+    Table,
+    Id,
+    CreatedAt,
+    CreatedById,
+    CreatedByName,
+    UpdatedAt,
+    UpdatedById,
+    UpdatedByName,
+    Version,
+    Name,
+    PrivateLibrary,
+}
+
+#[derive(DeriveIden)]
+enum GroupMembers {
+    Table,
+    GroupId,
+    UserId,
+    Role,
+}
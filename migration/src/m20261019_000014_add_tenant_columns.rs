@@ -0,0 +1,117 @@
+use sea_orm_migration::prelude::*;
+
+/// Tables whose rows belong to a tenant; the others hang off one of these.
+const TENANT_TABLES: [(TenantTables, &str); 5] = [
+    (TenantTables::Users, "idx_users_tenant_id"),
+    (TenantTables::Books, "idx_books_tenant_id"),
+    (TenantTables::Groups, "idx_groups_tenant_id"),
+    (TenantTables::Webhooks, "idx_webhooks_tenant_id"),
+    (
+        TenantTables::PersonalAccessTokens,
+        "idx_personal_access_tokens_tenant_id",
+    ),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Everything recorded so far belongs to the default tenant
+        for (table, index) in TENANT_TABLES {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            ColumnDef::new(Tenant::TenantId)
+                                .string_len(63)
+                                .not_null()
+                                .default("default"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index)
+                        .table(table)
+                        .col(Tenant::TenantId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // The same address may sign in to several tenants, so emails are unique per tenant
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE users DROP CONSTRAINT IF EXISTS users_email_key")
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_tenant_id_email")
+                    .table(Users::Table)
+                    .col(Tenant::TenantId)
+                    .col(Users::Email)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_users_tenant_id_email")
+                    .table(Users::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE users ADD CONSTRAINT users_email_key UNIQUE (email)")
+            .await?;
+
+        for (table, _) in TENANT_TABLES {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Tenant::TenantId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden, Clone, Copy)]
+enum TenantTables {
+    Users,
+    Books,
+    Groups,
+    Webhooks,
+    PersonalAccessTokens,
+}
+
+#[derive(DeriveIden)]
+enum Tenant {
+    TenantId,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Email,
+}
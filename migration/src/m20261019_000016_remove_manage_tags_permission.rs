@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The capability was never used by any route and no longer exists
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(RolePermissions::Table)
+                    .and_where(Expr::col(RolePermissions::Permission).eq("manage-tags"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(RolePermissions::Table)
+                    .columns([RolePermissions::Role, RolePermissions::Permission])
+                    .values_panic(["Admin".into(), "manage-tags".into()])
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RolePermissions {
+    Table,
+    Role,
+    Permission,
+}